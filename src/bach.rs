@@ -26,12 +26,16 @@
 //!   - **Musical Analysis**: Calculating tension, finding motifs, and measuring entropy.
 //! - **`BachComposer`**: A concrete implementation of the `Bach` trait that provides
 //!   a ready-to-use engine for musical tasks.
+//! - **JSON** (`serde` feature): The primitives and structures derive `Serialize` and
+//!   `Deserialize`, and `voice_to_json`/`voice_from_json` save and reload voices.
 
 use std::collections::HashMap;
 use rand::seq::SliceRandom;
+use rand::{Rng, SeedableRng};
 
 /// Represents the twelve standard musical notes in Western music theory.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum Note {
     /// C
     C,
@@ -61,6 +65,7 @@ pub enum Note {
 
 /// Represents the distance in semitones between two notes.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum Interval {
     /// 0 semitones
     Unison = 0,
@@ -92,6 +97,7 @@ pub enum Interval {
 
 /// Represents a collection of notes defined by a specific pattern of intervals.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum Scale {
     /// The major scale (W-W-H-W-W-W-H).
     Major,
@@ -117,10 +123,19 @@ pub enum Scale {
     WholeTone,
     /// A five-note scale.
     Pentatonic,
+    /// The six-note blues scale: minor pentatonic plus the flattened fifth.
+    Blues,
+    /// The eight-note diminished scale starting with a half step (H-W-H-W-H-W-H-W).
+    OctatonicHalfWhole,
+    /// The eight-note diminished scale starting with a whole step (W-H-W-H-W-H-W-H).
+    OctatonicWholeHalf,
+    /// The eight-note bebop dominant scale: Mixolydian with a passing major seventh.
+    BebopDominant,
 }
 
 /// Represents the quality and structure of a chord.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum ChordType {
     /// A three-note chord with a major third and a perfect fifth.
     Major,
@@ -148,6 +163,7 @@ pub enum ChordType {
 
 /// Represents a single melodic line in a musical composition.
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Voice {
     /// The sequence of notes and their durations (in beats).
     pub notes: Vec<(Note, f64)>,
@@ -159,6 +175,7 @@ pub struct Voice {
 
 /// Represents a set of notes played simultaneously.
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Chord {
     /// The root note of the chord.
     pub root: Note,
@@ -168,10 +185,13 @@ pub struct Chord {
     pub octave: i32,
     /// The duration of the chord in beats.
     pub duration: f64,
+    /// Which chord tone is in the bass: 0 for root position, 1 for first inversion, and so on.
+    pub inversion: u8,
 }
 
 /// Represents a sequence of chords.
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Progression {
     /// The sequence of chords in the progression.
     pub chords: Vec<Chord>,
@@ -181,6 +201,21 @@ pub struct Progression {
     pub scale: Scale,
 }
 
+/// The harmonic role of the final two chords of a progression.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CadenceType {
+    /// Dominant to tonic (V→I), the strongest close.
+    Authentic,
+    /// Subdominant to tonic (IV→I), the "amen" cadence.
+    Plagal,
+    /// Any chord to the dominant, leaving the phrase open.
+    Half,
+    /// Dominant to submediant (V→vi), evading the expected tonic.
+    Deceptive,
+    /// No recognized cadential motion, or fewer than two chords.
+    Inconclusive,
+}
+
 /// Defines the rules for generating and checking counterpoint.
 #[derive(Debug, Clone)]
 pub struct CounterpointRules {
@@ -197,7 +232,8 @@ pub struct CounterpointRules {
 /// A comprehensive trait for musical composition, analysis, and generation.
 pub trait Bach {
     // Basic musical operations
-    /// Converts a `Note` and octave to its corresponding frequency in Hertz.
+    /// Converts a `Note` and octave to its corresponding frequency in Hertz,
+    /// tuned according to the composer's temperament.
     fn note_to_frequency(&self, note: Note, octave: i32) -> f64;
     /// Converts a frequency in Hertz to the nearest `Note` and its octave.
     fn frequency_to_note(&self, frequency: f64) -> (Note, i32);
@@ -205,11 +241,20 @@ pub trait Bach {
     fn interval_between(&self, note1: Note, note2: Note) -> Interval;
     /// Transposes a note by a given interval.
     fn transpose_note(&self, note: Note, interval: Interval) -> Note;
+    /// Transposes a note up by a given interval, returning the octave it lands in.
+    /// Octaves turn over at C, so (B, 4) up a minor second is (C, 5).
+    fn transpose_note_octave(&self, note: Note, octave: i32, interval: Interval) -> (Note, i32);
     
     // Scale operations
     /// Generates the notes of a scale for a given root note.
     fn generate_scale(&self, root: Note, scale: Scale) -> Vec<Note>;
+    /// Generates a scale upward from a root in the given octave, pairing each
+    /// note with the octave it lands in (a B major scale from octave 4 reaches C#5).
+    fn generate_scale_octave(&self, root: Note, octave: i32, scale: Scale) -> Vec<(Note, i32)>;
     /// Determines the degree of a note within a given scale (e.g., 1 for tonic).
+    ///
+    /// Degrees run from 1 up to the scale's own size, so octatonic and bebop
+    /// scales have an eighth degree.
     fn scale_degree(&self, note: Note, root: Note, scale: Scale) -> Option<u8>;
     /// Checks if a note belongs to a given scale.
     fn is_in_scale(&self, note: Note, root: Note, scale: Scale) -> bool;
@@ -217,9 +262,22 @@ pub trait Bach {
     // Chord operations
     /// Builds the notes of a chord from a root note and chord type.
     fn build_chord(&self, root: Note, chord_type: ChordType) -> Vec<Note>;
+    /// Builds a chord upward from a root in the given octave, pairing each
+    /// chord tone with the octave it lands in (B major from octave 4 is B4, D#5, F#5).
+    fn build_chord_octave(&self, root: Note, octave: i32, chord_type: ChordType) -> Vec<(Note, i32)>;
+    /// Builds a chord and layers extensions (e.g. a ninth as `MajorSecond`) above it.
+    /// Extensions are intervals above the root; pitch classes already present are skipped.
+    fn build_extended_chord(&self, root: Note, base: ChordType, extensions: &[Interval]) -> Vec<Note>;
     /// Returns the standard symbol for a chord (e.g., "Cmaj7").
     fn chord_symbol(&self, chord: &Chord) -> String;
     /// Analyzes a set of notes to determine the most likely chord.
+    ///
+    /// The notes are reduced to a pitch-class set and compared with every
+    /// `ChordType` built on each of its notes, so inverted voicings resolve to
+    /// their root. An exact match wins; otherwise the template sharing the most
+    /// notes and missing the fewest is chosen. Ties prefer the bass (first
+    /// note) as root. The chord's `inversion` is the bass note's position among
+    /// the chord tones.
     fn analyze_chord(&self, notes: &[Note]) -> Option<Chord>;
     /// Generates all possible inversions of a chord.
     fn chord_inversions(&self, chord: &Chord) -> Vec<Vec<Note>>;
@@ -228,14 +286,38 @@ pub trait Bach {
     /// Generates a chord progression of a given length in a specific key and scale.
     fn generate_progression(&self, key: Note, scale: Scale, length: usize) -> Progression;
     /// Analyzes a chord progression and returns the Roman numeral analysis.
+    ///
+    /// Numerals are relative to the progression's key and scale: uppercase for
+    /// major-third chords, lowercase for minor-third ones, with `°`, `ø`, `+` and
+    /// `7` marking quality. Roots outside the scale are written as a flattened
+    /// degree of the major scale (e.g. `bVII`).
     fn analyze_progression(&self, progression: &Progression) -> Vec<String>;
+    /// Labels each chord with its scale degree in Roman numerals.
+    ///
+    /// The same analysis as `analyze_progression`, under the name harmony texts use.
+    fn roman_numeral_analysis(&self, progression: &Progression) -> Vec<String>;
     /// Generates smooth voice leading between two chords.
     fn voice_leading(&self, chord1: &Chord, chord2: &Chord) -> Vec<Voice>;
     
     // Counterpoint operations
     /// Generates a counterpoint melody for a given cantus firmus.
     fn generate_counterpoint(&self, cantus_firmus: &Voice, rules: &CounterpointRules) -> Voice;
+    /// Generates species counterpoint above a cantus firmus, an octave higher.
+    ///
+    /// Species 1 sets one note against each cantus note, 2 sets two, 3 sets four
+    /// and 4 syncopates, holding each downbeat note over into the next cantus note.
+    /// Species outside 1–4 are clamped. Downbeats are thirds and sixths, plus fifths
+    /// and unisons where `rules` allow them, and a perfect interval is never
+    /// repeated on consecutive downbeats. With `rules.voice_leading` each downbeat
+    /// is the candidate nearest the previous one.
+    fn generate_species_counterpoint(&self, cantus_firmus: &Voice, species: u8, rules: &CounterpointRules) -> Voice;
     /// Checks two voices for violations of counterpoint rules.
+    ///
+    /// Notes are paired by position and placed in their voice's octave. Parallel
+    /// fifths or octaves are a repeated perfect interval reached with both voices
+    /// moving in the same direction; direct fifths or octaves are a perfect
+    /// interval reached that way from a different interval at the final (cadence)
+    /// position. Melodic direction follows the shortest step between pitch classes.
     fn check_counterpoint_rules(&self, voice1: &Voice, voice2: &Voice, rules: &CounterpointRules) -> Vec<String>;
     /// Inverts a melody around a central note by a given interval.
     fn invert_melody(&self, voice: &Voice, interval: Interval) -> Voice;
@@ -245,17 +327,26 @@ pub trait Bach {
     // Algorithmic composition
     /// Generates a fugue subject in a given key and scale.
     fn generate_fugue_subject(&self, key: Note, scale: Scale) -> Voice;
-    /// Generates the answer to a fugue subject (typically transposed).
+    /// Generates the tonal answer to a fugue subject.
+    ///
+    /// The subject is transposed to the dominant, except that its opening run of
+    /// tonic and dominant notes is exchanged (tonic answered by dominant, dominant
+    /// answered by tonic) so the answer stays anchored in the home key.
     fn generate_fugue_answer(&self, subject: &Voice, key: Note) -> Voice;
     /// Generates a complete fugue exposition with a specified number of voices.
     fn generate_fugue_exposition(&self, subject: &Voice, key: Note, voices: usize) -> Vec<Voice>;
     /// Generates a canon from a subject with a given interval and delay.
     fn generate_canon(&self, subject: &Voice, interval: Interval, delay: f64) -> Vec<Voice>;
+    /// Generates a reproducible random melody of `length` notes drawn from the scale.
+    ///
+    /// Durations come from a small rhythmic alphabet (sixteenth to half notes).
+    /// The same seed always yields the same melody.
+    fn random_melody(&self, key: Note, scale: Scale, length: usize, seed: u64) -> Voice;
     
     // Mathematical music theory
     /// Calculates the harmonic series for a given fundamental frequency.
     fn calculate_harmonic_series(&self, fundamental: f64, partials: usize) -> Vec<f64>;
-    /// Calculates the frequency of a note using just intonation ratios.
+    /// Calculates the frequency of a note using just intonation ratios above C.
     fn calculate_just_intonation(&self, note: Note, octave: i32) -> f64;
     /// Calculates the frequency of a note using 12-tone equal temperament.
     fn calculate_equal_temperament(&self, note: Note, octave: i32) -> f64;
@@ -263,12 +354,21 @@ pub trait Bach {
     fn calculate_beat_frequency(&self, freq1: f64, freq2: f64) -> f64;
     
     // Rhythm and meter
-    /// Generates a rhythmic pattern based on a meter and complexity level.
+    /// Generates one bar of rhythm for a meter, in quarter-note beats.
+    ///
+    /// `complexity` in [0, 1] sets the subdivision: low values hold notes over
+    /// two beats, then single beats, eighths and finally sixteenths. Beats are
+    /// sized by the meter's `beat_value`, and the durations always sum to the bar.
     fn generate_rhythm_pattern(&self, meter: (u8, u8), complexity: f64) -> Vec<f64>;
     /// Applies syncopation to a rhythmic pattern.
     fn syncopate_rhythm(&self, rhythm: &[f64], syncopation_level: f64) -> Vec<f64>;
-    /// Combines two rhythms to create a polyrhythm.
-    fn polyrhythm(&self, rhythm1: &[f64], rhythm2: &[f64]) -> Vec<(f64, f64)>;
+    /// Combines two rhythms into one cycle of a polyrhythm.
+    ///
+    /// The cycle lasts the LCM of the two rhythms' total durations (resolved to
+    /// 1/480 of a beat), and each rhythm is stretched to fill it, so three beats
+    /// against two give a 3:2 pattern. Returns `(time, voice1_onset, voice2_onset)`
+    /// for every onset of either voice, in time order.
+    fn polyrhythm(&self, rhythm1: &[f64], rhythm2: &[f64]) -> Vec<(f64, bool, bool)>;
     
     // Musical analysis
     /// Performs a statistical analysis of a melody.
@@ -277,8 +377,20 @@ pub trait Bach {
     fn find_motifs(&self, voice: &Voice, min_length: usize) -> Vec<Vec<(Note, f64)>>;
     /// Calculates the harmonic tension at each point in a progression.
     fn calculate_tension(&self, progression: &Progression) -> Vec<f64>;
-    /// Calculates the strength of the final cadence in a progression.
+    /// Calculates the strength of the final cadence in a progression, derived from
+    /// `classify_cadence`: authentic cadences are strongest (1.0 from a dominant
+    /// seventh), followed by plagal, half and deceptive ones.
     fn calculate_cadence_strength(&self, progression: &Progression) -> f64;
+    /// Classifies the final cadence of a progression from the scale-degree motion
+    /// of its last two chord roots relative to the key. Deceptive cadences accept
+    /// both the major-key vi and the minor-key VI.
+    fn classify_cadence(&self, progression: &Progression) -> CadenceType;
+    /// Overlays several voices into a stream of simultaneously sounding notes.
+    ///
+    /// Each entry is the set of notes sounding during a time slice, in voice
+    /// order, together with the slice duration in beats. Slices are split at
+    /// every note onset in any voice.
+    fn overlay(&self, voices: &[Voice]) -> Vec<(Vec<Note>, f64)>;
     
     // Bach-specific algorithms
     /// Generates a four-part chorale in the style of J.S. Bach from a hymn tune.
@@ -292,6 +404,11 @@ pub trait Bach {
     
     // Mathematical transformations
     /// Applies a transformation matrix to the notes of a voice.
+    ///
+    /// Each note becomes the column vector (pitch class, duration, time index, octave)
+    /// and is multiplied by the matrix. The resulting pitch (plus 12 per octave of
+    /// octave shift) is snapped to the nearest chromatic note, the duration is taken
+    /// as-is, and the voice adopts the transformed octave of its first note.
     fn apply_transformation_matrix(&self, voice: &Voice, matrix: &[[f64; 4]; 4]) -> Voice;
     /// Generates a twelve-tone serial row.
     fn generate_serial_row(&self, length: usize) -> Vec<Note>;
@@ -309,14 +426,38 @@ pub trait Bach {
     fn add_tempo_variations(&self, voice: &Voice, tempo_profile: &[(f64, f64)]) -> Voice;
     /// Generates musical ornamentation in a specific style.
     fn generate_ornamentation(&self, voice: &Voice, style: &str) -> Voice;
+    
+    // MIDI export
+    /// Encodes a voice as a Format-0 Standard MIDI File.
+    ///
+    /// Each note becomes a note-on/note-off pair at the voice's velocity, with
+    /// octave 4 mapped so that C4 is MIDI note 60. Durations are in beats at
+    /// 480 ticks per beat, and `tempo_bpm` is written as a tempo event.
+    fn voice_to_midi(&self, voice: &Voice, tempo_bpm: u32) -> Vec<u8>;
+    /// Writes several voices to `path` as a Format-1 Standard MIDI File.
+    ///
+    /// The first track carries the tempo, followed by one track per voice on
+    /// its own channel (skipping the percussion channel 10).
+    fn write_midi(&self, path: &std::path::Path, voices: &[Voice], tempo_bpm: u32) -> std::io::Result<()>;
+    
+    // Audio export
+    /// Renders a voice as a 16-bit mono PCM WAV file.
+    ///
+    /// Each note is a sine tone at `note_to_frequency`, scaled by the voice's
+    /// velocity, with short linear attack and release ramps to avoid clicks.
+    /// Durations are in beats at `tempo_bpm`.
+    fn synthesize_wav(&self, voice: &Voice, sample_rate: u32, tempo_bpm: u32) -> Vec<u8>;
 }
 
 /// A concrete implementation of the `Bach` trait.
 pub struct BachComposer {
-    /// The musical temperament system to use (e.g., "equal").
+    /// The musical temperament system to use: "just" for just intonation,
+    /// anything else (e.g., "equal") for equal temperament.
     pub temperament: String,
     /// The tuning system to use (e.g., "12-tone").
     pub tuning_system: String,
+    /// The reference pitch of A4 in Hertz (e.g., 440.0 or 432.0).
+    pub reference_pitch: f64,
 }
 
 impl Default for BachComposer {
@@ -324,50 +465,30 @@ impl Default for BachComposer {
         Self {
             temperament: "equal".to_string(),
             tuning_system: "12-tone".to_string(),
+            reference_pitch: 440.0,
         }
     }
 }
 
 impl Bach for BachComposer {
     fn note_to_frequency(&self, note: Note, octave: i32) -> f64 {
-        let base_freq = match note {
-            Note::C => 261.63,
-            Note::CSharp => 277.18,
-            Note::D => 293.66,
-            Note::DSharp => 311.13,
-            Note::E => 329.63,
-            Note::F => 349.23,
-            Note::FSharp => 369.99,
-            Note::G => 392.00,
-            Note::GSharp => 415.30,
-            Note::A => 440.00,
-            Note::ASharp => 466.16,
-            Note::B => 493.88,
-        };
-        base_freq * 2.0_f64.powi(octave - 4)
+        match self.temperament.as_str() {
+            "just" => self.calculate_just_intonation(note, octave),
+            _ => self.calculate_equal_temperament(note, octave),
+        }
     }
     
     fn frequency_to_note(&self, frequency: f64) -> (Note, i32) {
-        let a4_freq = 440.0;
-        let semitones = (frequency / a4_freq).log2() * 12.0;
-        let octave = (semitones / 12.0).floor() as i32 + 4;
-        let note_index = (semitones % 12.0).round() as i32;
-        
-        let note = match note_index {
-            0 => Note::A,
-            1 => Note::ASharp,
-            2 => Note::B,
-            3 => Note::C,
-            4 => Note::CSharp,
-            5 => Note::D,
-            6 => Note::DSharp,
-            7 => Note::E,
-            8 => Note::F,
-            9 => Note::FSharp,
-            10 => Note::G,
-            11 => Note::GSharp,
-            _ => Note::A,
-        };
+        let note_values = [
+            Note::C, Note::CSharp, Note::D, Note::DSharp, Note::E, Note::F,
+            Note::FSharp, Note::G, Note::GSharp, Note::A, Note::ASharp, Note::B
+        ];
+        
+        // Round to the nearest semitone, then count from C4 (9 below A4) so octaves turn over at C
+        let semitones_from_a4 = ((frequency / self.reference_pitch).log2() * 12.0).round() as i32;
+        let semitones_from_c4 = semitones_from_a4 + 9;
+        let octave = semitones_from_c4.div_euclid(12) + 4;
+        let note = note_values[semitones_from_c4.rem_euclid(12) as usize];
         
         (note, octave)
     }
@@ -400,17 +521,25 @@ impl Bach for BachComposer {
     }
     
     fn transpose_note(&self, note: Note, interval: Interval) -> Note {
+        self.transpose_note_octave(note, 0, interval).0
+    }
+    
+    fn transpose_note_octave(&self, note: Note, octave: i32, interval: Interval) -> (Note, i32) {
         let note_values = [
             Note::C, Note::CSharp, Note::D, Note::DSharp, Note::E, Note::F,
             Note::FSharp, Note::G, Note::GSharp, Note::A, Note::ASharp, Note::B
         ];
         
         let current_index = note_values.iter().position(|&n| n == note).unwrap_or(0);
-        let new_index = (current_index + interval as usize) % 12;
-        note_values[new_index]
+        let new_index = current_index + interval as usize;
+        (note_values[new_index % 12], octave + (new_index / 12) as i32)
     }
     
     fn generate_scale(&self, root: Note, scale: Scale) -> Vec<Note> {
+        self.generate_scale_octave(root, 0, scale).into_iter().map(|(note, _)| note).collect()
+    }
+    
+    fn generate_scale_octave(&self, root: Note, octave: i32, scale: Scale) -> Vec<(Note, i32)> {
         let intervals = match scale {
             Scale::Major => vec![0, 2, 4, 5, 7, 9, 11],
             Scale::NaturalMinor => vec![0, 2, 3, 5, 7, 8, 10],
@@ -424,10 +553,14 @@ impl Bach for BachComposer {
             Scale::Chromatic => vec![0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11],
             Scale::WholeTone => vec![0, 2, 4, 6, 8, 10],
             Scale::Pentatonic => vec![0, 2, 4, 7, 9],
+            Scale::Blues => vec![0, 3, 5, 6, 7, 10],
+            Scale::OctatonicHalfWhole => vec![0, 1, 3, 4, 6, 7, 9, 10],
+            Scale::OctatonicWholeHalf => vec![0, 2, 3, 5, 6, 8, 9, 11],
+            Scale::BebopDominant => vec![0, 2, 4, 5, 7, 9, 10, 11],
         };
         
         intervals.iter().map(|&interval| {
-            self.transpose_note(root, match interval {
+            self.transpose_note_octave(root, octave, match interval {
                 0 => Interval::Unison,
                 1 => Interval::MinorSecond,
                 2 => Interval::MajorSecond,
@@ -456,6 +589,10 @@ impl Bach for BachComposer {
     }
     
     fn build_chord(&self, root: Note, chord_type: ChordType) -> Vec<Note> {
+        self.build_chord_octave(root, 0, chord_type).into_iter().map(|(note, _)| note).collect()
+    }
+    
+    fn build_chord_octave(&self, root: Note, octave: i32, chord_type: ChordType) -> Vec<(Note, i32)> {
         let intervals = match chord_type {
             ChordType::Major => vec![0, 4, 7],
             ChordType::Minor => vec![0, 3, 7],
@@ -471,7 +608,7 @@ impl Bach for BachComposer {
         };
         
         intervals.iter().map(|&interval| {
-            self.transpose_note(root, match interval {
+            self.transpose_note_octave(root, octave, match interval {
                 0 => Interval::Unison,
                 2 => Interval::MajorSecond,
                 3 => Interval::MinorThird,
@@ -488,6 +625,17 @@ impl Bach for BachComposer {
         }).collect()
     }
     
+    fn build_extended_chord(&self, root: Note, base: ChordType, extensions: &[Interval]) -> Vec<Note> {
+        let mut notes = self.build_chord(root, base);
+        for &extension in extensions {
+            let note = self.transpose_note(root, extension);
+            if !notes.contains(&note) {
+                notes.push(note);
+            }
+        }
+        notes
+    }
+    
     fn chord_symbol(&self, chord: &Chord) -> String {
         let root_symbol = match chord.root {
             Note::C => "C",
@@ -526,27 +674,36 @@ impl Bach for BachComposer {
             return None;
         }
         
-        let root = notes[0];
-        let intervals: Vec<Interval> = notes.iter().skip(1).map(|&note| {
-            self.interval_between(root, note)
-        }).collect();
+        let bass = notes[0];
+        let mut pitch_classes: Vec<Note> = Vec::new();
+        for &note in notes {
+            if !pitch_classes.contains(&note) {
+                pitch_classes.push(note);
+            }
+        }
         
-        // Simple chord type detection
-        let chord_type = if intervals.contains(&Interval::MajorThird) && intervals.contains(&Interval::PerfectFifth) {
-            ChordType::Major
-        } else if intervals.contains(&Interval::MinorThird) && intervals.contains(&Interval::PerfectFifth) {
-            ChordType::Minor
-        } else if intervals.contains(&Interval::MinorThird) && intervals.contains(&Interval::Tritone) {
-            ChordType::Diminished
-        } else {
-            ChordType::Major // Default
-        };
+        // Score = shared notes minus notes on either side left unmatched
+        let mut best: Option<(i32, Note, ChordType, Vec<Note>)> = None;
+        for &root in &pitch_classes {
+            for chord_type in CHORD_TYPES {
+                let template = self.build_chord(root, chord_type);
+                let shared = pitch_classes.iter().filter(|note| template.contains(note)).count() as i32;
+                let unmatched = (pitch_classes.len() + template.len()) as i32 - 2 * shared;
+                let score = shared - unmatched;
+                if best.as_ref().is_none_or(|(best_score, ..)| score > *best_score) {
+                    best = Some((score, root, chord_type, template));
+                }
+            }
+        }
         
+        let (_, root, chord_type, template) = best?;
+        let inversion = template.iter().position(|&note| note == bass).unwrap_or(0) as u8;
         Some(Chord {
             root,
             chord_type,
             octave: 4,
             duration: 1.0,
+            inversion,
         })
     }
     
@@ -591,6 +748,7 @@ impl Bach for BachComposer {
                 chord_type,
                 octave: 4,
                 duration: 1.0,
+                inversion: 0,
             });
         }
         
@@ -602,21 +760,41 @@ impl Bach for BachComposer {
     }
     
     fn analyze_progression(&self, progression: &Progression) -> Vec<String> {
+        let scale_notes = self.generate_scale(progression.key, progression.scale);
+        let diatonic = scale_notes.len() == 7;
+        
         progression.chords.iter().map(|chord| {
-            self.chord_symbol(chord)
+            match scale_notes.iter().position(|&n| n == chord.root) {
+                Some(degree) if diatonic => roman_numeral(degree, "", chord.chord_type),
+                _ => {
+                    // Fall back to the major scale, flattening degrees it lacks
+                    let offset = self.interval_between(progression.key, chord.root) as usize;
+                    match MAJOR_SCALE_OFFSETS.iter().position(|&o| o == offset) {
+                        Some(degree) => roman_numeral(degree, "", chord.chord_type),
+                        None => {
+                            let degree = MAJOR_SCALE_OFFSETS.iter().position(|&o| o == offset + 1).unwrap_or(0);
+                            roman_numeral(degree, "b", chord.chord_type)
+                        }
+                    }
+                }
+            }
         }).collect()
     }
     
+    fn roman_numeral_analysis(&self, progression: &Progression) -> Vec<String> {
+        self.analyze_progression(progression)
+    }
+    
     fn voice_leading(&self, chord1: &Chord, chord2: &Chord) -> Vec<Voice> {
-        let notes1 = self.build_chord(chord1.root, chord1.chord_type);
+        let notes1 = self.build_chord_octave(chord1.root, chord1.octave, chord1.chord_type);
         let notes2 = self.build_chord(chord2.root, chord2.chord_type);
         
         let mut voices = Vec::new();
-        for (i, &note) in notes1.iter().enumerate() {
+        for (i, &(note, octave)) in notes1.iter().enumerate() {
             let target_note = if i < notes2.len() { notes2[i] } else { note };
             voices.push(Voice {
                 notes: vec![(note, chord1.duration), (target_note, chord2.duration)],
-                octave: chord1.octave,
+                octave,
                 velocity: 80,
             });
         }
@@ -640,18 +818,100 @@ impl Bach for BachComposer {
         counterpoint
     }
     
+    fn generate_species_counterpoint(&self, cantus_firmus: &Voice, species: u8, rules: &CounterpointRules) -> Voice {
+        let imperfect = [Interval::MinorThird, Interval::MajorThird, Interval::MinorSixth, Interval::MajorSixth];
+        let mut consonances = imperfect.to_vec();
+        if rules.parallel_fifths_allowed {
+            consonances.push(Interval::PerfectFifth);
+        }
+        if rules.parallel_octaves_allowed {
+            consonances.push(Interval::Unison);
+        }
+        let distance = |a: Note, b: Note| {
+            let semitones = self.interval_between(a, b) as i32;
+            semitones.min(12 - semitones)
+        };
+        
+        // Pick one consonant downbeat interval per cantus note
+        let mut downbeats: Vec<Interval> = Vec::new();
+        let mut previous_counter: Option<Note> = None;
+        for (i, &(note, _)) in cantus_firmus.notes.iter().enumerate() {
+            let candidates: Vec<Interval> = consonances.iter().copied()
+                .filter(|&interval| {
+                    let perfect = matches!(interval, Interval::PerfectFifth | Interval::Unison);
+                    !perfect || downbeats.last() != Some(&interval)
+                })
+                .collect();
+            let interval = match previous_counter {
+                Some(previous) if rules.voice_leading => *candidates.iter()
+                    .min_by_key(|&&interval| distance(previous, self.transpose_note(note, interval)))
+                    .unwrap(),
+                _ => candidates[i % candidates.len()],
+            };
+            previous_counter = Some(self.transpose_note(note, interval));
+            downbeats.push(interval);
+        }
+        
+        let mut notes = Vec::new();
+        for (i, (&(note, duration), &interval)) in cantus_firmus.notes.iter().zip(&downbeats).enumerate() {
+            let counter_note = self.transpose_note(note, interval);
+            match species.clamp(1, 4) {
+                1 => notes.push((counter_note, duration)),
+                4 => {
+                    // The previous downbeat is held over as a suspension, then resolves
+                    let held = if i == 0 { counter_note } else { notes[notes.len() - 1].0 };
+                    notes.push((held, duration / 2.0));
+                    notes.push((counter_note, duration / 2.0));
+                }
+                subdivision_species => {
+                    // Weak beats move through the other imperfect consonances above the same note
+                    let subdivisions = if subdivision_species == 2 { 2 } else { 4 };
+                    let start = imperfect.iter().position(|&candidate| candidate == interval).unwrap_or(0);
+                    notes.push((counter_note, duration / subdivisions as f64));
+                    for beat in 1..subdivisions {
+                        let weak = imperfect[(start + beat) % imperfect.len()];
+                        notes.push((self.transpose_note(note, weak), duration / subdivisions as f64));
+                    }
+                }
+            }
+        }
+        
+        Voice {
+            notes,
+            octave: cantus_firmus.octave + 1,
+            velocity: cantus_firmus.velocity,
+        }
+    }
+    
     fn check_counterpoint_rules(&self, voice1: &Voice, voice2: &Voice, rules: &CounterpointRules) -> Vec<String> {
         let mut violations = Vec::new();
+        let height = |note: Note, octave: i32| octave * 12 + self.interval_between(Note::C, note) as i32;
+        let motion = |from: Note, to: Note| (self.interval_between(from, to) as i32 + 6).rem_euclid(12) - 6;
         
-        for (i, ((note1, _), (note2, _))) in voice1.notes.iter().zip(voice2.notes.iter()).enumerate() {
-            let interval = self.interval_between(*note1, *note2);
-            
-            if !rules.parallel_fifths_allowed && interval == Interval::PerfectFifth {
-                violations.push(format!("Parallel fifths at position {}", i));
+        // Interval above the lower voice at each position, reduced to within an octave
+        let intervals: Vec<i32> = voice1.notes.iter().zip(&voice2.notes)
+            .map(|(&(note1, _), &(note2, _))| (height(note1, voice1.octave) - height(note2, voice2.octave)).abs() % 12)
+            .collect();
+        let forbidden = |interval: i32| match interval {
+            7 => (!rules.parallel_fifths_allowed).then_some("fifths"),
+            0 => (!rules.parallel_octaves_allowed).then_some("octaves"),
+            _ => None,
+        };
+        
+        for i in 1..intervals.len() {
+            let step1 = motion(voice1.notes[i - 1].0, voice1.notes[i].0);
+            let step2 = motion(voice2.notes[i - 1].0, voice2.notes[i].0);
+            if step1 == 0 || step1.signum() != step2.signum() {
+                continue;
             }
+            let Some(kind) = forbidden(intervals[i]) else {
+                continue;
+            };
             
-            if !rules.parallel_octaves_allowed && interval == Interval::Unison {
-                violations.push(format!("Parallel octaves at position {}", i));
+            if intervals[i - 1] == intervals[i] {
+                violations.push(format!("Parallel {} at position {}", kind, i));
+            } else if i == intervals.len() - 1 {
+                violations.push(format!("Direct {} into cadence at position {}", kind, i));
             }
         }
         
@@ -701,8 +961,24 @@ impl Bach for BachComposer {
     }
     
     fn generate_fugue_answer(&self, subject: &Voice, key: Note) -> Voice {
-        // Transpose the subject up a perfect fifth
-        self.invert_melody(subject, Interval::PerfectFifth)
+        let dominant = self.transpose_note(key, Interval::PerfectFifth);
+        let mut in_head = true;
+
+        Voice {
+            notes: subject.notes.iter().map(|&(note, duration)| {
+                in_head &= note == key || note == dominant;
+                let answered = if in_head && note == dominant {
+                    // The dominant is answered by the tonic, a fourth above
+                    key
+                } else {
+                    // Everything else gets the real answer, a fifth above
+                    self.transpose_note(note, Interval::PerfectFifth)
+                };
+                (answered, duration)
+            }).collect(),
+            octave: subject.octave,
+            velocity: subject.velocity,
+        }
     }
     
     fn generate_fugue_exposition(&self, subject: &Voice, key: Note, voices: usize) -> Vec<Voice> {
@@ -738,30 +1014,54 @@ impl Bach for BachComposer {
         vec![voice1, delayed_voice2]
     }
     
+    fn random_melody(&self, key: Note, scale: Scale, length: usize, seed: u64) -> Voice {
+        const DURATIONS: [f64; 5] = [0.25, 0.5, 1.0, 1.5, 2.0];
+        
+        let scale_notes = self.generate_scale(key, scale);
+        let mut rng = rand::rngs::StdRng::seed_from_u64(seed);
+        let notes = (0..length)
+            .map(|_| {
+                let note = scale_notes[rng.gen_range(0..scale_notes.len())];
+                let duration = DURATIONS[rng.gen_range(0..DURATIONS.len())];
+                (note, duration)
+            })
+            .collect();
+        
+        Voice {
+            notes,
+            octave: 4,
+            velocity: 80,
+        }
+    }
+    
     fn calculate_harmonic_series(&self, fundamental: f64, partials: usize) -> Vec<f64> {
         (1..=partials).map(|n| fundamental * n as f64).collect()
     }
     
     fn calculate_just_intonation(&self, note: Note, octave: i32) -> f64 {
-        let base_freq = match note {
-            Note::C => 261.63,
-            Note::CSharp => 261.63 * 25.0 / 24.0,
-            Note::D => 261.63 * 9.0 / 8.0,
-            Note::DSharp => 261.63 * 6.0 / 5.0,
-            Note::E => 261.63 * 5.0 / 4.0,
-            Note::F => 261.63 * 4.0 / 3.0,
-            Note::FSharp => 261.63 * 45.0 / 32.0,
-            Note::G => 261.63 * 3.0 / 2.0,
-            Note::GSharp => 261.63 * 8.0 / 5.0,
-            Note::A => 261.63 * 5.0 / 3.0,
-            Note::ASharp => 261.63 * 9.0 / 5.0,
-            Note::B => 261.63 * 15.0 / 8.0,
+        // The tonic C is shared with equal temperament at the same reference pitch
+        let c4 = self.calculate_equal_temperament(Note::C, 4);
+        let ratio = match note {
+            Note::C => 1.0,
+            Note::CSharp => 25.0 / 24.0,
+            Note::D => 9.0 / 8.0,
+            Note::DSharp => 6.0 / 5.0,
+            Note::E => 5.0 / 4.0,
+            Note::F => 4.0 / 3.0,
+            Note::FSharp => 45.0 / 32.0,
+            Note::G => 3.0 / 2.0,
+            Note::GSharp => 8.0 / 5.0,
+            Note::A => 5.0 / 3.0,
+            Note::ASharp => 9.0 / 5.0,
+            Note::B => 15.0 / 8.0,
         };
-        base_freq * 2.0_f64.powi(octave - 4)
+        c4 * ratio * 2.0_f64.powi(octave - 4)
     }
     
     fn calculate_equal_temperament(&self, note: Note, octave: i32) -> f64 {
-        self.note_to_frequency(note, octave)
+        // A4 sits 9 semitones above C4
+        let semitones_from_a4 = self.interval_between(Note::C, note) as i32 - 9 + 12 * (octave - 4);
+        self.reference_pitch * 2.0_f64.powf(semitones_from_a4 as f64 / 12.0)
     }
     
     fn calculate_beat_frequency(&self, freq1: f64, freq2: f64) -> f64 {
@@ -770,11 +1070,21 @@ impl Bach for BachComposer {
     
     fn generate_rhythm_pattern(&self, meter: (u8, u8), complexity: f64) -> Vec<f64> {
         let (beats, beat_value) = meter;
+        let beat = 4.0 / beat_value.max(1) as f64;
+        // Notes per beat: 1/2, 1, 2 or 4
+        let level = (complexity.clamp(0.0, 1.0) * 4.0).floor().min(3.0) as i32;
         let mut pattern = Vec::new();
         
-        for i in 0..beats as usize {
-            let duration = if i == 0 { 1.0 } else { 0.5 };
-            pattern.push(duration);
+        if level == 0 {
+            for start in (0..beats).step_by(2) {
+                let span = (beats - start).min(2);
+                pattern.push(beat * span as f64);
+            }
+        } else {
+            let per_beat = 1usize << (level - 1);
+            for _ in 0..beats {
+                pattern.extend(std::iter::repeat_n(beat / per_beat as f64, per_beat));
+            }
         }
         
         pattern
@@ -790,17 +1100,30 @@ impl Bach for BachComposer {
         }).collect()
     }
     
-    fn polyrhythm(&self, rhythm1: &[f64], rhythm2: &[f64]) -> Vec<(f64, f64)> {
-        let lcm = rhythm1.len().lcm(rhythm2.len());
-        let mut result = Vec::new();
+    fn polyrhythm(&self, rhythm1: &[f64], rhythm2: &[f64]) -> Vec<(f64, bool, bool)> {
+        let ticks = |rhythm: &[f64]| (rhythm.iter().sum::<f64>() * POLYRHYTHM_TICKS_PER_BEAT).round() as usize;
+        let (ticks1, ticks2) = (ticks(rhythm1), ticks(rhythm2));
+        if ticks1 == 0 || ticks2 == 0 {
+            return Vec::new();
+        }
+        let cycle = ticks1.lcm(ticks2) as f64 / POLYRHYTHM_TICKS_PER_BEAT;
         
-        for i in 0..lcm {
-            let r1 = rhythm1[i % rhythm1.len()];
-            let r2 = rhythm2[i % rhythm2.len()];
-            result.push((r1, r2));
+        let mut events: Vec<(f64, bool, bool)> = Vec::new();
+        for (rhythm, first) in [(rhythm1, true), (rhythm2, false)] {
+            let stretch = cycle / rhythm.iter().sum::<f64>();
+            let mut time = 0.0;
+            for &duration in rhythm {
+                let onset = time * stretch;
+                match events.iter_mut().find(|(t, _, _)| (t - onset).abs() < 1e-9) {
+                    Some(event) => event.2 = true,
+                    None => events.push((onset, first, !first)),
+                }
+                time += duration;
+            }
         }
         
-        result
+        events.sort_by(|a, b| a.0.total_cmp(&b.0));
+        events
     }
     
     fn analyze_melody(&self, voice: &Voice) -> HashMap<String, f64> {
@@ -848,17 +1171,70 @@ impl Bach for BachComposer {
             return 0.0;
         }
         
-        let last_chord = &progression.chords[progression.chords.len() - 1];
         let second_last = &progression.chords[progression.chords.len() - 2];
         
-        match (second_last.chord_type, last_chord.chord_type) {
-            (ChordType::DominantSeventh, ChordType::Major) => 1.0,
-            (ChordType::DominantSeventh, ChordType::Minor) => 0.8,
-            (ChordType::Major, ChordType::Major) => 0.9,
-            _ => 0.3,
+        match self.classify_cadence(progression) {
+            CadenceType::Authentic if second_last.chord_type == ChordType::DominantSeventh => 1.0,
+            CadenceType::Authentic => 0.9,
+            CadenceType::Plagal => 0.7,
+            CadenceType::Half => 0.5,
+            CadenceType::Deceptive => 0.4,
+            CadenceType::Inconclusive => 0.3,
+        }
+    }
+    
+    fn classify_cadence(&self, progression: &Progression) -> CadenceType {
+        let [.., second_last, last] = progression.chords.as_slice() else {
+            return CadenceType::Inconclusive;
+        };
+        
+        let from = self.interval_between(progression.key, second_last.root);
+        let to = self.interval_between(progression.key, last.root);
+        
+        match (from, to) {
+            (Interval::PerfectFifth, Interval::Unison) => CadenceType::Authentic,
+            (Interval::PerfectFourth, Interval::Unison) => CadenceType::Plagal,
+            (Interval::PerfectFifth, Interval::MajorSixth | Interval::MinorSixth) => CadenceType::Deceptive,
+            (_, Interval::PerfectFifth) => CadenceType::Half,
+            _ => CadenceType::Inconclusive,
         }
     }
     
+    fn overlay(&self, voices: &[Voice]) -> Vec<(Vec<Note>, f64)> {
+        const EPSILON: f64 = 1e-9;
+        
+        // Lay each voice out on a shared timeline as (start, end, note) spans
+        let timelines: Vec<Vec<(f64, f64, Note)>> = voices.iter().map(|voice| {
+            let mut time = 0.0;
+            voice.notes.iter().map(|&(note, duration)| {
+                let span = (time, time + duration, note);
+                time += duration;
+                span
+            }).collect()
+        }).collect();
+        
+        let mut boundaries: Vec<f64> = timelines.iter()
+            .flat_map(|spans| spans.iter().flat_map(|&(start, end, _)| [start, end]))
+            .collect();
+        boundaries.sort_by(|a, b| a.partial_cmp(b).unwrap_or(std::cmp::Ordering::Equal));
+        boundaries.dedup_by(|a, b| (*a - *b).abs() < EPSILON);
+        
+        boundaries.windows(2).filter_map(|window| {
+            let (start, end) = (window[0], window[1]);
+            let sounding: Vec<Note> = timelines.iter().filter_map(|spans| {
+                spans.iter()
+                    .find(|&&(note_start, note_end, _)| note_start <= start + EPSILON && start + EPSILON < note_end)
+                    .map(|&(_, _, note)| note)
+            }).collect();
+            
+            if sounding.is_empty() {
+                None
+            } else {
+                Some((sounding, end - start))
+            }
+        }).collect()
+    }
+    
     fn generate_bach_style_chorale(&self, hymn_tune: &Voice) -> Vec<Voice> {
         let mut chorale = Vec::new();
         
@@ -925,29 +1301,33 @@ impl Bach for BachComposer {
     }
     
     fn apply_transformation_matrix(&self, voice: &Voice, matrix: &[[f64; 4]; 4]) -> Voice {
-        // Apply affine transformation to note positions
+        let note_values = [
+            Note::C, Note::CSharp, Note::D, Note::DSharp, Note::E, Note::F,
+            Note::FSharp, Note::G, Note::GSharp, Note::A, Note::ASharp, Note::B
+        ];
+        
         let mut transformed = voice.clone();
+        let mut first_octave = None;
         
-        for (note, duration) in &mut transformed.notes {
-            // Simple transformation: transpose based on matrix
-            let transposition = (matrix[0][0] * *duration) as i32 % 12;
-            *note = self.transpose_note(*note, match transposition {
-                0 => Interval::Unison,
-                1 => Interval::MinorSecond,
-                2 => Interval::MajorSecond,
-                3 => Interval::MinorThird,
-                4 => Interval::MajorThird,
-                5 => Interval::PerfectFourth,
-                6 => Interval::Tritone,
-                7 => Interval::PerfectFifth,
-                8 => Interval::MinorSixth,
-                9 => Interval::MajorSixth,
-                10 => Interval::MinorSeventh,
-                11 => Interval::MajorSeventh,
-                _ => Interval::Unison,
-            });
+        for (index, (note, duration)) in transformed.notes.iter_mut().enumerate() {
+            let pitch_class = note_values.iter().position(|n| n == note).unwrap_or(0);
+            let input = [pitch_class as f64, *duration, index as f64, voice.octave as f64];
+            let output: Vec<f64> = matrix.iter()
+                .map(|row| row.iter().zip(&input).map(|(m, x)| m * x).sum())
+                .collect();
+            
+            // Snap to the nearest chromatic pitch, carrying whole octaves of the
+            // octave shift into the absolute pitch
+            let octave = output[3].round() as i64;
+            let absolute = output[0].round() as i64 + 12 * (octave - voice.octave as i64);
+            *note = note_values[absolute.rem_euclid(12) as usize];
+            *duration = output[1];
+            first_octave.get_or_insert(octave + absolute.div_euclid(12));
         }
         
+        if let Some(octave) = first_octave {
+            transformed.octave = octave as i32;
+        }
         transformed
     }
     
@@ -1062,6 +1442,184 @@ impl Bach for BachComposer {
             _ => voice.clone(),
         }
     }
+    
+    fn voice_to_midi(&self, voice: &Voice, tempo_bpm: u32) -> Vec<u8> {
+        let mut track = midi_tempo_event(tempo_bpm);
+        track.extend(self.midi_note_events(voice, 0));
+        
+        let mut midi = midi_header(0, 1);
+        midi.extend(midi_track(track));
+        midi
+    }
+    
+    fn write_midi(&self, path: &std::path::Path, voices: &[Voice], tempo_bpm: u32) -> std::io::Result<()> {
+        let mut midi = midi_header(1, voices.len() as u16 + 1);
+        midi.extend(midi_track(midi_tempo_event(tempo_bpm)));
+        for (i, voice) in voices.iter().enumerate() {
+            let channel = (if i < 9 { i } else { i + 1 }) % 16;
+            midi.extend(midi_track(self.midi_note_events(voice, channel as u8)));
+        }
+        std::fs::write(path, midi)
+    }
+    
+    fn synthesize_wav(&self, voice: &Voice, sample_rate: u32, tempo_bpm: u32) -> Vec<u8> {
+        let samples_per_beat = 60.0 * sample_rate as f64 / tempo_bpm.max(1) as f64;
+        let ramp = (WAV_RAMP_SECONDS * sample_rate as f64) as usize;
+        let amplitude = voice.velocity.min(127) as f64 / 127.0 * WAV_PEAK;
+        
+        // Note boundaries are rounded from the running beat total, so the
+        // sample count always matches the summed durations
+        let mut samples = Vec::new();
+        let mut beats = 0.0;
+        for &(note, duration) in &voice.notes {
+            beats += duration.max(0.0);
+            let length = (beats * samples_per_beat).round() as usize - samples.len();
+            let frequency = self.note_to_frequency(note, voice.octave);
+            let ramp = ramp.min(length / 2).max(1);
+            for i in 0..length {
+                let envelope = (i.min(length - 1 - i) as f64 / ramp as f64).min(1.0);
+                let phase = 2.0 * std::f64::consts::PI * frequency * i as f64 / sample_rate as f64;
+                samples.push((amplitude * envelope * phase.sin()).round() as i16);
+            }
+        }
+        
+        let data_len = (samples.len() * 2) as u32;
+        let mut wav = b"RIFF".to_vec();
+        wav.extend((36 + data_len).to_le_bytes());
+        wav.extend(b"WAVEfmt ");
+        wav.extend(16u32.to_le_bytes());
+        wav.extend(1u16.to_le_bytes()); // PCM
+        wav.extend(1u16.to_le_bytes()); // mono
+        wav.extend(sample_rate.to_le_bytes());
+        wav.extend((sample_rate * 2).to_le_bytes());
+        wav.extend(2u16.to_le_bytes());
+        wav.extend(16u16.to_le_bytes());
+        wav.extend(b"data");
+        wav.extend(data_len.to_le_bytes());
+        for sample in samples {
+            wav.extend(sample.to_le_bytes());
+        }
+        wav
+    }
+}
+
+impl BachComposer {
+    /// Encodes the notes of a voice as MIDI note-on/note-off events on a channel.
+    fn midi_note_events(&self, voice: &Voice, channel: u8) -> Vec<u8> {
+        let mut events = Vec::new();
+        for &(note, duration) in &voice.notes {
+            let pitch = ((voice.octave + 1) * 12 + self.interval_between(Note::C, note) as i32).clamp(0, 127) as u8;
+            let ticks = (duration.max(0.0) * MIDI_TICKS_PER_BEAT as f64).round() as u32;
+            write_variable_length(&mut events, 0);
+            events.extend([0x90 | channel, pitch, voice.velocity.min(127)]);
+            write_variable_length(&mut events, ticks);
+            events.extend([0x80 | channel, pitch, 0]);
+        }
+        events
+    }
+}
+
+/// Encodes a voice as pretty-printed JSON, with notes written by name (e.g. `"CSharp"`).
+#[cfg(feature = "serde")]
+pub fn voice_to_json(voice: &Voice) -> String {
+    serde_json::to_string_pretty(voice).expect("a voice always serializes to JSON")
+}
+
+/// Decodes a voice written by `voice_to_json`.
+#[cfg(feature = "serde")]
+pub fn voice_from_json(json: &str) -> Result<Voice, serde_json::Error> {
+    serde_json::from_str(json)
+}
+
+/// Resolution at which polyrhythm cycle lengths are compared
+const POLYRHYTHM_TICKS_PER_BEAT: f64 = 480.0;
+
+/// Length of the WAV attack and release ramps, in seconds
+const WAV_RAMP_SECONDS: f64 = 0.005;
+
+/// Peak WAV sample value at full velocity, leaving some headroom
+const WAV_PEAK: f64 = 0.8 * i16::MAX as f64;
+
+/// MIDI time resolution, in ticks per quarter-note beat
+const MIDI_TICKS_PER_BEAT: u16 = 480;
+
+// Helper function for the MIDI header chunk
+fn midi_header(format: u16, tracks: u16) -> Vec<u8> {
+    let mut header = b"MThd".to_vec();
+    header.extend(6u32.to_be_bytes());
+    header.extend(format.to_be_bytes());
+    header.extend(tracks.to_be_bytes());
+    header.extend(MIDI_TICKS_PER_BEAT.to_be_bytes());
+    header
+}
+
+// Helper function wrapping events in a track chunk terminated by end-of-track
+fn midi_track(mut events: Vec<u8>) -> Vec<u8> {
+    events.extend([0x00, 0xFF, 0x2F, 0x00]);
+    let mut track = b"MTrk".to_vec();
+    track.extend((events.len() as u32).to_be_bytes());
+    track.extend(events);
+    track
+}
+
+// Helper function for the set-tempo meta event (microseconds per beat)
+fn midi_tempo_event(tempo_bpm: u32) -> Vec<u8> {
+    let micros_per_beat = (60_000_000 / tempo_bpm.max(1)).min(0xFF_FFFF);
+    let mut event = vec![0x00, 0xFF, 0x51, 0x03];
+    event.extend(&micros_per_beat.to_be_bytes()[1..]);
+    event
+}
+
+// Helper function for MIDI variable-length quantities
+fn write_variable_length(out: &mut Vec<u8>, value: u32) {
+    let mut groups = vec![(value & 0x7F) as u8];
+    let mut rest = value >> 7;
+    while rest > 0 {
+        groups.push((rest & 0x7F) as u8 | 0x80);
+        rest >>= 7;
+    }
+    out.extend(groups.iter().rev());
+}
+
+/// Every chord quality, in the order `analyze_chord` prefers them on a tie
+const CHORD_TYPES: [ChordType; 11] = [
+    ChordType::Major,
+    ChordType::Minor,
+    ChordType::DominantSeventh,
+    ChordType::MajorSeventh,
+    ChordType::MinorSeventh,
+    ChordType::Diminished,
+    ChordType::HalfDiminished,
+    ChordType::DiminishedSeventh,
+    ChordType::Augmented,
+    ChordType::SuspendedFourth,
+    ChordType::SuspendedSecond,
+];
+
+/// Semitone offsets of the major scale degrees from the tonic
+const MAJOR_SCALE_OFFSETS: [usize; 7] = [0, 2, 4, 5, 7, 9, 11];
+
+// Helper function for Roman numeral rendering (degree is zero-based)
+fn roman_numeral(degree: usize, accidental: &str, chord_type: ChordType) -> String {
+    const NUMERALS: [&str; 7] = ["I", "II", "III", "IV", "V", "VI", "VII"];
+    
+    let numeral = NUMERALS[degree % 7];
+    let (minor_third, suffix) = match chord_type {
+        ChordType::Major => (false, ""),
+        ChordType::Minor => (true, ""),
+        ChordType::Diminished => (true, "°"),
+        ChordType::Augmented => (false, "+"),
+        ChordType::MajorSeventh => (false, "maj7"),
+        ChordType::MinorSeventh => (true, "7"),
+        ChordType::DominantSeventh => (false, "7"),
+        ChordType::DiminishedSeventh => (true, "°7"),
+        ChordType::HalfDiminished => (true, "ø7"),
+        ChordType::SuspendedSecond => (false, "sus2"),
+        ChordType::SuspendedFourth => (false, "sus4"),
+    };
+    
+    let numeral = if minor_third { numeral.to_lowercase() } else { numeral.to_string() };
+    format!("{}{}{}", accidental, numeral, suffix)
 }
 
 // Helper function for LCM calculation
@@ -1091,3 +1649,588 @@ impl Gcd for usize {
         a
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn voice(notes: &[(Note, f64)]) -> Voice {
+        Voice {
+            notes: notes.to_vec(),
+            octave: 4,
+            velocity: 80,
+        }
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_voice_json_round_trip() {
+        let melody = Voice {
+            notes: vec![(Note::CSharp, 0.75), (Note::A, 1.5), (Note::FSharp, 0.1)],
+            octave: 3,
+            velocity: 97,
+        };
+        let json = voice_to_json(&melody);
+        assert!(json.contains("\"CSharp\""));
+
+        let decoded = voice_from_json(&json).unwrap();
+        assert_eq!(decoded.notes, melody.notes);
+        assert_eq!((decoded.octave, decoded.velocity), (3, 97));
+        assert!(voice_from_json("{\"notes\": [[\"H\", 1.0]], \"octave\": 4, \"velocity\": 80}").is_err());
+    }
+
+    #[test]
+    fn test_analyze_chord_sevenths_and_inversions() {
+        let composer = BachComposer::default();
+        let g7 = composer.build_chord(Note::G, ChordType::DominantSeventh);
+        for inversion in 0..g7.len() {
+            let mut voicing = g7.clone();
+            voicing.rotate_left(inversion);
+            let chord = composer.analyze_chord(&voicing).unwrap();
+            assert_eq!((chord.root, chord.chord_type, chord.inversion), (Note::G, ChordType::DominantSeventh, inversion as u8));
+        }
+
+        // Cmaj7 in third inversion, with a doubled root
+        let chord = composer.analyze_chord(&[Note::B, Note::C, Note::E, Note::G, Note::C]).unwrap();
+        assert_eq!((chord.root, chord.chord_type, chord.inversion), (Note::C, ChordType::MajorSeventh, 3));
+
+        let chord = composer.analyze_chord(&[Note::E, Note::GSharp, Note::C]).unwrap();
+        assert_eq!((chord.root, chord.chord_type), (Note::E, ChordType::Augmented));
+        let chord = composer.analyze_chord(&[Note::A, Note::C, Note::E]).unwrap();
+        assert_eq!((chord.root, chord.chord_type, chord.inversion), (Note::A, ChordType::Minor, 0));
+        assert!(composer.analyze_chord(&[]).is_none());
+    }
+
+    #[test]
+    fn test_blues_octatonic_and_bebop_scales() {
+        let composer = BachComposer::default();
+        assert_eq!(
+            composer.generate_scale(Note::C, Scale::Blues),
+            vec![Note::C, Note::DSharp, Note::F, Note::FSharp, Note::G, Note::ASharp]
+        );
+
+        let half_whole = composer.generate_scale(Note::C, Scale::OctatonicHalfWhole);
+        let whole_half = composer.generate_scale(Note::C, Scale::OctatonicWholeHalf);
+        assert_eq!(half_whole.len(), 8);
+        assert_eq!(whole_half.len(), 8);
+        assert_eq!(composer.scale_degree(Note::ASharp, Note::C, Scale::OctatonicHalfWhole), Some(8));
+        assert_eq!(composer.scale_degree(Note::B, Note::C, Scale::OctatonicWholeHalf), Some(8));
+        assert!(!composer.is_in_scale(Note::D, Note::C, Scale::OctatonicHalfWhole));
+        assert!(composer.is_in_scale(Note::D, Note::C, Scale::OctatonicWholeHalf));
+
+        // The bebop dominant keeps both sevenths
+        assert_eq!(composer.scale_degree(Note::ASharp, Note::G, Scale::BebopDominant), None);
+        assert_eq!(composer.scale_degree(Note::F, Note::G, Scale::BebopDominant), Some(7));
+        assert_eq!(composer.scale_degree(Note::FSharp, Note::G, Scale::BebopDominant), Some(8));
+        assert!(!composer.is_in_scale(Note::E, Note::C, Scale::Blues));
+    }
+
+    #[test]
+    fn test_polyrhythm_three_against_two() {
+        let composer = BachComposer::default();
+        let events = composer.polyrhythm(&[1.0, 1.0, 1.0], &[1.0, 1.0]);
+
+        // Six beats: triplets every two, duplets every three, meeting on the downbeat
+        assert_eq!(events, vec![
+            (0.0, true, true),
+            (2.0, true, false),
+            (3.0, false, true),
+            (4.0, true, false),
+        ]);
+
+        let four_three = composer.polyrhythm(&[0.5; 4], &[1.0; 3]);
+        assert_eq!(four_three.len(), 6);
+        assert_eq!(four_three.iter().filter(|(_, a, b)| *a && *b).count(), 1);
+        assert!(composer.polyrhythm(&[], &[1.0]).is_empty());
+    }
+
+    #[test]
+    fn test_rhythm_pattern_complexity() {
+        let composer = BachComposer::default();
+        let simple = composer.generate_rhythm_pattern((4, 4), 0.0);
+        let busy = composer.generate_rhythm_pattern((4, 4), 0.9);
+        assert_eq!(simple, vec![2.0, 2.0]);
+        assert_eq!(busy.len(), 16);
+        assert_eq!(simple.iter().sum::<f64>(), 4.0);
+        assert_eq!(busy.iter().sum::<f64>(), 4.0);
+
+        // Eighth-note beats: 6/8 spans three quarters, 3/4 has an odd beat out
+        let compound = composer.generate_rhythm_pattern((6, 8), 0.6);
+        assert_eq!(compound.len(), 12);
+        assert_eq!(compound.iter().sum::<f64>(), 3.0);
+        assert_eq!(composer.generate_rhythm_pattern((3, 4), 0.1), vec![2.0, 1.0]);
+    }
+
+    #[test]
+    fn test_fugue_answer_is_tonal() {
+        let composer = BachComposer::default();
+        // Subject in C opening on the dominant: G C D E
+        let subject = voice(&[(Note::G, 1.0), (Note::C, 0.5), (Note::D, 0.5), (Note::E, 1.0)]);
+
+        let answer = composer.generate_fugue_answer(&subject, Note::C);
+        let real = composer.invert_melody(&subject, Interval::PerfectFifth);
+
+        // The opening dominant is answered by the tonic, not a literal fifth (D)
+        assert_eq!(answer.notes[0].0, Note::C);
+        assert_ne!(answer.notes[0].0, real.notes[0].0);
+        // The tonic in the head is answered by the dominant
+        assert_eq!(answer.notes[1].0, Note::G);
+        // After the head the answer is a real transposition
+        assert_eq!(answer.notes[2..], real.notes[2..]);
+    }
+
+    #[test]
+    fn test_temperament_selects_tuning() {
+        let mut composer = BachComposer::default();
+        assert!((composer.note_to_frequency(Note::A, 4) - 440.0).abs() < 1e-9);
+        assert!((composer.note_to_frequency(Note::C, 4) - 261.63).abs() < 0.01);
+        let equal_third = composer.note_to_frequency(Note::E, 4) / composer.note_to_frequency(Note::C, 4);
+        assert!((equal_third - 2.0_f64.powf(4.0 / 12.0)).abs() < 1e-9);
+
+        composer.temperament = "just".to_string();
+        let just_third = composer.note_to_frequency(Note::E, 4) / composer.note_to_frequency(Note::C, 4);
+        assert!((just_third - 5.0 / 4.0).abs() < 1e-9);
+        assert!((composer.note_to_frequency(Note::E, 4) - composer.calculate_equal_temperament(Note::E, 4)).abs() > 1.0);
+    }
+
+    /// Reads a MIDI variable-length quantity, advancing the position
+    fn read_variable_length(bytes: &[u8], position: &mut usize) -> u32 {
+        let mut value = 0;
+        loop {
+            let byte = bytes[*position];
+            *position += 1;
+            value = (value << 7) | (byte & 0x7F) as u32;
+            if byte & 0x80 == 0 {
+                return value;
+            }
+        }
+    }
+
+    /// Counts (note-on, note-off) events in a track chunk starting at `start`,
+    /// returning the counts and the offset just past the chunk
+    fn count_midi_notes(bytes: &[u8], start: usize) -> ((usize, usize), usize) {
+        assert_eq!(&bytes[start..start + 4], b"MTrk");
+        let length = u32::from_be_bytes(bytes[start + 4..start + 8].try_into().unwrap()) as usize;
+        let end = start + 8 + length;
+        let (mut on, mut off) = (0, 0);
+        let mut position = start + 8;
+        while position < end {
+            read_variable_length(bytes, &mut position);
+            match bytes[position] {
+                0xFF => {
+                    position += 2;
+                    position += read_variable_length(bytes, &mut position) as usize;
+                }
+                status => {
+                    match status & 0xF0 {
+                        0x90 => on += 1,
+                        0x80 => off += 1,
+                        other => panic!("unexpected status {:#x}", other),
+                    }
+                    position += 3;
+                }
+            }
+        }
+        assert_eq!(position, end);
+        ((on, off), end)
+    }
+
+    #[test]
+    fn test_voice_to_midi() {
+        let composer = BachComposer::default();
+        let subject = composer.generate_fugue_subject(Note::C, Scale::Major);
+        let midi = composer.voice_to_midi(&subject, 90);
+
+        assert_eq!(&midi[0..4], b"MThd");
+        assert_eq!(&midi[4..14], &[0, 0, 0, 6, 0, 0, 0, 1, 0x01, 0xE0]);
+        let ((on, off), end) = count_midi_notes(&midi, 14);
+        assert_eq!((on, off), (subject.notes.len(), subject.notes.len()));
+        assert_eq!(end, midi.len());
+
+        // Middle C at velocity 80, then a quarter note (480 ticks) later its note-off
+        let first_note = 14 + 8 + 7;
+        assert_eq!(&midi[first_note..first_note + 10], &[0x00, 0x90, 60, 80, 0x83, 0x60, 0x80, 60, 0, 0x00]);
+
+        let path = std::env::temp_dir().join(format!("bach-exposition-{}.mid", std::process::id()));
+        let exposition = composer.generate_fugue_exposition(&subject, Note::C, 3);
+        composer.write_midi(&path, &exposition, 90).unwrap();
+        let file = std::fs::read(&path).unwrap();
+        std::fs::remove_file(&path).unwrap();
+
+        assert_eq!(&file[8..12], &[0, 1, 0, exposition.len() as u8 + 1]);
+        let ((on, _), mut position) = count_midi_notes(&file, 14);
+        assert_eq!(on, 0);
+        for voice in &exposition {
+            let (counts, next) = count_midi_notes(&file, position);
+            assert_eq!(counts, (voice.notes.len(), voice.notes.len()));
+            position = next;
+        }
+        assert_eq!(position, file.len());
+    }
+
+    #[test]
+    fn test_synthesize_wav() {
+        let composer = BachComposer::default();
+        let subject = composer.generate_fugue_subject(Note::C, Scale::Major);
+        let wav = composer.synthesize_wav(&subject, 8000, 120);
+
+        let word = |at: usize| u32::from_le_bytes([wav[at], wav[at + 1], wav[at + 2], wav[at + 3]]);
+        assert_eq!(&wav[0..4], b"RIFF");
+        assert_eq!(word(4) as usize, wav.len() - 8);
+        assert_eq!(&wav[8..16], b"WAVEfmt ");
+        assert_eq!(word(16), 16);
+        assert_eq!(&wav[20..24], &[1, 0, 1, 0]);
+        assert_eq!((word(24), word(28)), (8000, 16000));
+        assert_eq!(&wav[32..36], &[2, 0, 16, 0]);
+        assert_eq!(&wav[36..40], b"data");
+
+        // Half a second per beat at 120 bpm
+        let beats: f64 = subject.notes.iter().map(|&(_, duration)| duration).sum();
+        let samples = word(40) as usize / 2;
+        assert_eq!(samples, (beats * 4000.0).round() as usize);
+        assert_eq!(wav.len(), 44 + samples * 2);
+
+        // Notes start silent and reach the velocity-scaled peak
+        let pcm: Vec<i16> = wav[44..].chunks_exact(2).map(|b| i16::from_le_bytes([b[0], b[1]])).collect();
+        assert_eq!(pcm[0], 0);
+        let peak = pcm.iter().map(|s| s.unsigned_abs()).max().unwrap() as f64;
+        assert!(peak <= WAV_PEAK * 80.0 / 127.0 + 1.0 && peak > WAV_PEAK * 0.45);
+    }
+
+    #[test]
+    fn test_check_counterpoint_rules_detects_parallel_motion() {
+        let composer = BachComposer::default();
+        let rules = CounterpointRules {
+            parallel_fifths_allowed: false,
+            parallel_octaves_allowed: false,
+            dissonance_resolution: true,
+            voice_leading: true,
+        };
+        let voice = |notes: &[Note], octave: i32| Voice {
+            notes: notes.iter().map(|&note| (note, 1.0)).collect(),
+            octave,
+            velocity: 80,
+        };
+
+        // G–A over C–D: the upper voice comes first, which the old check read as fourths
+        let upper = voice(&[Note::G, Note::A], 5);
+        let lower = voice(&[Note::C, Note::D], 4);
+        assert_eq!(composer.check_counterpoint_rules(&upper, &lower, &rules), vec!["Parallel fifths at position 1"]);
+        assert_eq!(composer.check_counterpoint_rules(&lower, &upper, &rules), vec!["Parallel fifths at position 1"]);
+
+        // Parallel thirds are fine, as is a single fifth or a held unison
+        let thirds = voice(&[Note::E, Note::F, Note::G], 4);
+        let under = voice(&[Note::C, Note::D, Note::C], 4);
+        assert!(composer.check_counterpoint_rules(&thirds, &under, &rules).is_empty());
+        let oblique = voice(&[Note::C, Note::C], 4);
+        assert!(composer.check_counterpoint_rules(&oblique, &oblique, &rules).is_empty());
+
+        // Parallel octaves, and a direct octave approached in similar motion at the cadence
+        let octaves = voice(&[Note::D, Note::E, Note::F], 5);
+        let bass = voice(&[Note::D, Note::E, Note::F], 4);
+        assert_eq!(composer.check_counterpoint_rules(&octaves, &bass, &rules).len(), 2);
+        let soprano = voice(&[Note::E, Note::F, Note::C], 5);
+        let cadence_bass = voice(&[Note::C, Note::D, Note::C], 4);
+        assert_eq!(
+            composer.check_counterpoint_rules(&soprano, &cadence_bass, &rules),
+            vec!["Direct octaves into cadence at position 2"]
+        );
+
+        let lenient = CounterpointRules { parallel_fifths_allowed: true, parallel_octaves_allowed: true, ..rules };
+        assert!(composer.check_counterpoint_rules(&upper, &lower, &lenient).is_empty());
+    }
+
+    #[test]
+    fn test_species_counterpoint() {
+        let composer = BachComposer::default();
+        let cantus_firmus = Voice {
+            notes: [Note::C, Note::D, Note::F, Note::E, Note::D, Note::C].iter().map(|&note| (note, 1.0)).collect(),
+            octave: 3,
+            velocity: 80,
+        };
+        let strict = CounterpointRules {
+            parallel_fifths_allowed: false,
+            parallel_octaves_allowed: false,
+            dissonance_resolution: true,
+            voice_leading: true,
+        };
+
+        let first = composer.generate_species_counterpoint(&cantus_firmus, 1, &strict);
+        assert_eq!(first.notes.len(), cantus_firmus.notes.len());
+        assert_eq!(first.octave, 4);
+        assert!(composer.check_counterpoint_rules(&cantus_firmus, &first, &strict).is_empty());
+
+        for (species, per_note) in [(2, 2), (3, 4), (4, 2)] {
+            let line = composer.generate_species_counterpoint(&cantus_firmus, species, &strict);
+            assert_eq!(line.notes.len(), cantus_firmus.notes.len() * per_note);
+            let total: f64 = line.notes.iter().map(|&(_, duration)| duration).sum();
+            assert!((total - 6.0).abs() < 1e-12);
+        }
+
+        // Permitted perfect intervals still never move in parallel
+        let lenient = CounterpointRules { parallel_fifths_allowed: true, parallel_octaves_allowed: true, voice_leading: false, ..strict };
+        let line = composer.generate_species_counterpoint(&cantus_firmus, 1, &lenient);
+        let intervals: Vec<Interval> = cantus_firmus.notes.iter().zip(&line.notes)
+            .map(|(&(cantus, _), &(counter, _))| composer.interval_between(cantus, counter))
+            .collect();
+        assert!(intervals.contains(&Interval::PerfectFifth));
+        assert!(intervals.windows(2).all(|pair| {
+            pair[0] != pair[1] || !matches!(pair[0], Interval::PerfectFifth | Interval::Unison)
+        }));
+    }
+
+    #[test]
+    fn test_transpose_note_octave_crosses_octaves() {
+        let composer = BachComposer::default();
+
+        assert_eq!(composer.transpose_note_octave(Note::B, 4, Interval::MinorSecond), (Note::C, 5));
+        assert_eq!(composer.transpose_note_octave(Note::B, 4, Interval::MajorThird), (Note::DSharp, 5));
+        assert_eq!(composer.transpose_note_octave(Note::C, 4, Interval::MajorSeventh), (Note::B, 4));
+        assert_eq!(composer.transpose_note_octave(Note::A, 3, Interval::Octave), (Note::A, 4));
+        assert_eq!(composer.transpose_note(Note::B, Interval::MajorThird), Note::DSharp);
+
+        assert_eq!(
+            composer.build_chord_octave(Note::B, 4, ChordType::Major),
+            vec![(Note::B, 4), (Note::DSharp, 5), (Note::FSharp, 5)]
+        );
+        assert_eq!(
+            composer.build_chord_octave(Note::A, 3, ChordType::DominantSeventh),
+            vec![(Note::A, 3), (Note::CSharp, 4), (Note::E, 4), (Note::G, 4)]
+        );
+        assert_eq!(
+            composer.generate_scale_octave(Note::G, 4, Scale::Major),
+            vec![
+                (Note::G, 4), (Note::A, 4), (Note::B, 4), (Note::C, 5),
+                (Note::D, 5), (Note::E, 5), (Note::FSharp, 5),
+            ]
+        );
+        assert_eq!(
+            composer.generate_scale_octave(Note::C, 4, Scale::Major).iter().map(|&(_, octave)| octave).max(),
+            Some(4)
+        );
+        assert_eq!(
+            composer.build_chord(Note::B, ChordType::Major),
+            vec![Note::B, Note::DSharp, Note::FSharp]
+        );
+
+        let b_major = Chord { root: Note::B, chord_type: ChordType::Major, octave: 4, duration: 1.0, inversion: 0 };
+        let e_major = Chord { root: Note::E, chord_type: ChordType::Major, octave: 4, duration: 1.0, inversion: 0 };
+        let octaves: Vec<i32> = composer.voice_leading(&b_major, &e_major).iter().map(|voice| voice.octave).collect();
+        assert_eq!(octaves, vec![4, 5, 5]);
+    }
+
+    #[test]
+    fn test_random_melody_is_in_scale_and_reproducible() {
+        let composer = BachComposer::default();
+        for (key, scale) in [(Note::D, Scale::Major), (Note::A, Scale::HarmonicMinor), (Note::FSharp, Scale::Dorian)] {
+            let melody = composer.random_melody(key, scale, 64, 42);
+            assert_eq!(melody.notes.len(), 64);
+            for &(note, duration) in &melody.notes {
+                assert!(composer.is_in_scale(note, key, scale));
+                assert!([0.25, 0.5, 1.0, 1.5, 2.0].contains(&duration));
+            }
+        }
+
+        let first = composer.random_melody(Note::C, Scale::Major, 32, 7);
+        let again = composer.random_melody(Note::C, Scale::Major, 32, 7);
+        let other = composer.random_melody(Note::C, Scale::Major, 32, 8);
+        assert_eq!(first.notes, again.notes);
+        assert_ne!(first.notes, other.notes);
+    }
+
+    #[test]
+    fn test_frequency_to_note_round_trip() {
+        let composer = BachComposer::default();
+        let notes = [
+            Note::C, Note::CSharp, Note::D, Note::DSharp, Note::E, Note::F,
+            Note::FSharp, Note::G, Note::GSharp, Note::A, Note::ASharp, Note::B
+        ];
+
+        assert_eq!(composer.frequency_to_note(440.0), (Note::A, 4));
+        for octave in 2..=6 {
+            for note in notes {
+                let frequency = composer.note_to_frequency(note, octave);
+                assert_eq!(composer.frequency_to_note(frequency), (note, octave));
+                // Slightly flat or sharp pitches still round to the same note
+                assert_eq!(composer.frequency_to_note(frequency * 0.99), (note, octave));
+                assert_eq!(composer.frequency_to_note(frequency * 1.01), (note, octave));
+            }
+        }
+    }
+
+    #[test]
+    fn test_reference_pitch_scales_all_frequencies() {
+        for temperament in ["equal", "just"] {
+            let standard = BachComposer { temperament: temperament.to_string(), ..BachComposer::default() };
+            let baroque = BachComposer {
+                temperament: temperament.to_string(),
+                reference_pitch: 432.0,
+                ..BachComposer::default()
+            };
+            for (note, octave) in [(Note::C, 3), (Note::E, 4), (Note::A, 4), (Note::B, 5)] {
+                let ratio = baroque.note_to_frequency(note, octave) / standard.note_to_frequency(note, octave);
+                assert!((ratio - 432.0 / 440.0).abs() < 1e-12);
+            }
+        }
+    }
+
+    fn progression(key: Note, scale: Scale, chords: &[(Note, ChordType)]) -> Progression {
+        Progression {
+            chords: chords.iter().map(|&(root, chord_type)| Chord {
+                root,
+                chord_type,
+                octave: 4,
+                duration: 1.0,
+                inversion: 0,
+            }).collect(),
+            key,
+            scale,
+        }
+    }
+
+    #[test]
+    fn test_analyze_progression_roman_numerals() {
+        let composer = BachComposer::default();
+        let pop = progression(Note::C, Scale::Major, &[
+            (Note::C, ChordType::Major),
+            (Note::A, ChordType::Minor),
+            (Note::F, ChordType::Major),
+            (Note::G, ChordType::Major),
+        ]);
+
+        assert_eq!(composer.analyze_progression(&pop), vec!["I", "vi", "IV", "V"]);
+    }
+
+    #[test]
+    fn test_roman_numeral_analysis() {
+        let composer = BachComposer::default();
+        let axis = progression(Note::C, Scale::Major, &[
+            (Note::C, ChordType::Major),
+            (Note::G, ChordType::Major),
+            (Note::A, ChordType::Minor),
+            (Note::F, ChordType::Major),
+        ]);
+
+        assert_eq!(composer.roman_numeral_analysis(&axis), vec!["I", "V", "vi", "IV"]);
+    }
+
+    #[test]
+    fn test_analyze_progression_qualities_and_borrowed_chords() {
+        let composer = BachComposer::default();
+        let major = progression(Note::C, Scale::Major, &[
+            (Note::B, ChordType::Diminished),
+            (Note::G, ChordType::DominantSeventh),
+            (Note::ASharp, ChordType::Major),
+        ]);
+        let minor = progression(Note::A, Scale::NaturalMinor, &[
+            (Note::A, ChordType::Minor),
+            (Note::C, ChordType::Major),
+            (Note::E, ChordType::DominantSeventh),
+        ]);
+
+        assert_eq!(composer.analyze_progression(&major), vec!["vii°", "V7", "bVII"]);
+        assert_eq!(composer.analyze_progression(&minor), vec!["i", "III", "V7"]);
+    }
+
+    #[test]
+    fn test_classify_cadence() {
+        let composer = BachComposer::default();
+        let authentic = progression(Note::C, Scale::Major, &[
+            (Note::F, ChordType::Major),
+            (Note::G, ChordType::DominantSeventh),
+            (Note::C, ChordType::Major),
+        ]);
+        let plagal = progression(Note::C, Scale::Major, &[(Note::F, ChordType::Major), (Note::C, ChordType::Major)]);
+        let deceptive = progression(Note::C, Scale::Major, &[(Note::G, ChordType::Major), (Note::A, ChordType::Minor)]);
+        let half = progression(Note::C, Scale::Major, &[(Note::D, ChordType::Minor), (Note::G, ChordType::Major)]);
+        let minor_authentic = progression(Note::A, Scale::NaturalMinor, &[(Note::E, ChordType::Major), (Note::A, ChordType::Minor)]);
+
+        assert_eq!(composer.classify_cadence(&authentic), CadenceType::Authentic);
+        assert_eq!(composer.classify_cadence(&plagal), CadenceType::Plagal);
+        assert_eq!(composer.classify_cadence(&deceptive), CadenceType::Deceptive);
+        assert_eq!(composer.classify_cadence(&half), CadenceType::Half);
+        assert_eq!(composer.classify_cadence(&minor_authentic), CadenceType::Authentic);
+        assert_eq!(composer.classify_cadence(&progression(Note::C, Scale::Major, &[(Note::C, ChordType::Major)])), CadenceType::Inconclusive);
+
+        let strength = |p: &Progression| composer.calculate_cadence_strength(p);
+        assert_eq!(strength(&authentic), 1.0);
+        assert!(strength(&authentic) > strength(&plagal));
+        assert!(strength(&plagal) > strength(&half));
+        assert!(strength(&half) > strength(&deceptive));
+    }
+
+    #[test]
+    fn test_transformation_matrix_identity_and_shear() {
+        let composer = BachComposer::default();
+        let melody = voice(&[(Note::C, 1.0), (Note::D, 0.5), (Note::E, 2.0), (Note::A, 1.0)]);
+        let identity = [
+            [1.0, 0.0, 0.0, 0.0],
+            [0.0, 1.0, 0.0, 0.0],
+            [0.0, 0.0, 1.0, 0.0],
+            [0.0, 0.0, 0.0, 1.0],
+        ];
+
+        let unchanged = composer.apply_transformation_matrix(&melody, &identity);
+        assert_eq!(unchanged.notes, melody.notes);
+        assert_eq!(unchanged.octave, melody.octave);
+
+        // Shear pitch by time index: the i-th note rises by 2i semitones
+        let mut shear = identity;
+        shear[0][2] = 2.0;
+        let sheared = composer.apply_transformation_matrix(&melody, &shear);
+        let pitches: Vec<Note> = sheared.notes.iter().map(|&(note, _)| note).collect();
+        let durations: Vec<f64> = sheared.notes.iter().map(|&(_, d)| d).collect();
+
+        assert_eq!(pitches, vec![Note::C, Note::E, Note::GSharp, Note::DSharp]);
+        assert_eq!(durations, vec![1.0, 0.5, 2.0, 1.0]);
+    }
+
+    #[test]
+    fn test_build_extended_chord_dominant_ninth() {
+        let composer = BachComposer::default();
+
+        let ninth = composer.build_extended_chord(Note::G, ChordType::DominantSeventh, &[Interval::MajorSecond]);
+
+        assert_eq!(ninth, vec![Note::G, Note::B, Note::D, Note::F, Note::A]);
+    }
+
+    #[test]
+    fn test_build_extended_chord_skips_existing_tones() {
+        let composer = BachComposer::default();
+
+        let chord = composer.build_extended_chord(Note::C, ChordType::Major, &[Interval::PerfectFifth, Interval::Octave]);
+
+        assert_eq!(chord, composer.build_chord(Note::C, ChordType::Major));
+    }
+
+    #[test]
+    fn test_overlay_splits_at_onsets() {
+        let composer = BachComposer::default();
+        let soprano = voice(&[(Note::C, 2.0), (Note::D, 2.0)]);
+        let alto = voice(&[(Note::E, 1.0), (Note::F, 1.0), (Note::G, 2.0)]);
+        let bass = voice(&[(Note::G, 4.0)]);
+
+        let slices = composer.overlay(&[soprano, alto, bass]);
+
+        assert_eq!(slices, vec![
+            (vec![Note::C, Note::E, Note::G], 1.0),
+            (vec![Note::C, Note::F, Note::G], 1.0),
+            (vec![Note::D, Note::G, Note::G], 2.0),
+        ]);
+    }
+
+    #[test]
+    fn test_overlay_voices_of_different_lengths() {
+        let composer = BachComposer::default();
+        let short = voice(&[(Note::A, 1.0)]);
+        let long = voice(&[(Note::C, 0.5), (Note::E, 2.5)]);
+
+        let slices = composer.overlay(&[short, long]);
+
+        assert_eq!(slices, vec![
+            (vec![Note::A, Note::C], 0.5),
+            (vec![Note::A, Note::E], 0.5),
+            (vec![Note::E], 2.0),
+        ]);
+    }
+}
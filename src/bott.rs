@@ -19,6 +19,9 @@
 
 use crate::godel::Godel;
 
+/// Tolerance within which Chern classes are considered equal
+pub const HOMOTOPY_TOLERANCE: f64 = 1e-9;
+
 /// Bott trait representing an 8-dimensional quasi-fiber structure
 /// 
 /// This models complex, multi-dimensional relationships where each dimension
@@ -86,6 +89,21 @@ pub trait Bott {
     /// Compute the Chern classes (topological invariants)
     fn chern_classes(&self) -> Vec<f64>;
     
+    /// Check whether two structures lie in the same homotopy class
+    ///
+    /// Structures are homotopic when they share a Bott periodicity class and
+    /// their Chern classes agree within `HOMOTOPY_TOLERANCE`, regardless of
+    /// their coordinates.
+    fn is_homotopic(&self, other: &Self) -> bool
+    where
+        Self: Sized,
+    {
+        let (ours, theirs) = (self.chern_classes(), other.chern_classes());
+        self.bott_periodicity_class() == other.bott_periodicity_class()
+            && ours.len() == theirs.len()
+            && ours.iter().zip(&theirs).all(|(a, b)| (a - b).abs() < HOMOTOPY_TOLERANCE)
+    }
+    
     /// Get the Euler characteristic
     fn euler_characteristic(&self) -> i32;
     
@@ -258,16 +276,34 @@ pub struct Bott8D<B, F> {
     pub coordinates: [Option<F>; 8],
     /// The base space of the fiber structure.
     pub base: B,
+    /// The curvature 2-form over the 8 dimensions; `None` for a flat structure.
+    pub curvature: Option<[[f64; 8]; 8]>,
+    /// The metric tensor field the connection is derived from; `None` for a flat structure.
+    pub metric: Option<MetricField>,
     /// Phantom data to hold the fiber type `F`.
     pub _phantom: std::marker::PhantomData<F>,
 }
 
+/// A metric tensor field over the 8 dimensions, evaluated around a point
+#[derive(Debug, Clone, Copy)]
+pub struct MetricField {
+    /// The metric tensor g_ij as a function of position.
+    pub tensor: fn(&[f64; 8]) -> [[f64; 8]; 8],
+    /// The point at which the connection is evaluated.
+    pub point: [f64; 8],
+}
+
+/// Step used for the central differences of the metric
+const METRIC_STEP: f64 = 1e-5;
+
 impl<B, F: Copy> Bott8D<B, F> {
     /// Create a new 8D Bott structure
     pub fn new(base: B) -> Self {
         Self {
             coordinates: [None; 8],
             base,
+            curvature: None,
+            metric: None,
             _phantom: std::marker::PhantomData,
         }
     }
@@ -277,6 +313,8 @@ impl<B, F: Copy> Bott8D<B, F> {
         Self {
             coordinates: coords,
             base,
+            curvature: None,
+            metric: None,
             _phantom: std::marker::PhantomData,
         }
     }
@@ -314,6 +352,138 @@ impl<B, F: Copy> Bott8D<B, F> {
     pub fn filled_dimensions(&self) -> usize {
         self.coordinates.iter().filter(|c| c.is_some()).count()
     }
+    
+    /// Give the structure a curvature 2-form
+    pub fn with_curvature(mut self, curvature: [[f64; 8]; 8]) -> Self {
+        self.curvature = Some(curvature);
+        self
+    }
+    
+    /// Give the structure a metric tensor field, evaluated at `point`
+    pub fn with_metric(mut self, tensor: fn(&[f64; 8]) -> [[f64; 8]; 8], point: [f64; 8]) -> Self {
+        self.metric = Some(MetricField { tensor, point });
+        self
+    }
+    
+    /// Christoffel symbols of the metric, indexed `[k][i][j]` for Γ^k_ij
+    ///
+    /// Γ^k_ij = ½ g^kl (∂_i g_jl + ∂_j g_il - ∂_l g_ij), with the derivatives
+    /// taken by central differences at the metric's point. Returns `None`
+    /// without a metric or when the metric is singular there.
+    pub fn christoffel_symbols(&self) -> Option<[[[f64; 8]; 8]; 8]> {
+        let metric = self.metric?;
+        let inverse = invert_matrix((metric.tensor)(&metric.point))?;
+        
+        // derivatives[l][i][j] = ∂_l g_ij
+        let derivatives: [[[f64; 8]; 8]; 8] = std::array::from_fn(|l| {
+            let mut forward = metric.point;
+            let mut backward = metric.point;
+            forward[l] += METRIC_STEP;
+            backward[l] -= METRIC_STEP;
+            let (ahead, behind) = ((metric.tensor)(&forward), (metric.tensor)(&backward));
+            std::array::from_fn(|i| std::array::from_fn(|j| {
+                (ahead[i][j] - behind[i][j]) / (2.0 * METRIC_STEP)
+            }))
+        });
+        
+        Some(std::array::from_fn(|k| std::array::from_fn(|i| std::array::from_fn(|j| {
+            (0..8).map(|l| {
+                0.5 * inverse[k][l] * (derivatives[i][j][l] + derivatives[j][i][l] - derivatives[l][i][j])
+            }).sum()
+        }))))
+    }
+}
+
+impl<B: Clone, F: Copy> Bott8D<B, F> {
+    /// Direct sum with another structure
+    /// Filled coordinates of `self` come first, followed by those of `other`,
+    /// truncated once all 8 dimensions are occupied. The base of `self` is kept.
+    pub fn direct_sum(&self, other: &Bott8D<B, F>) -> Bott8D<B, F> {
+        let filled = self.coordinates.iter().chain(other.coordinates.iter()).flatten();
+        Self::packed(self.base.clone(), filled.copied())
+    }
+    
+    /// Tensor product with another structure
+    /// Each coordinate holds the product of a pair of filled fiber values,
+    /// in row-major order over (`self`, `other`) and truncated at 8 dimensions.
+    pub fn tensor(&self, other: &Bott8D<B, F>) -> Bott8D<B, F>
+    where
+        F: std::ops::Mul<Output = F>,
+    {
+        let products = self.coordinates.iter().flatten().flat_map(|&a| {
+            other.coordinates.iter().flatten().map(move |&b| a * b)
+        });
+        Self::packed(self.base.clone(), products)
+    }
+    
+    /// Packs fiber values into consecutive coordinates, dropping any beyond the 8th
+    fn packed(base: B, values: impl Iterator<Item = F>) -> Bott8D<B, F> {
+        let mut coords = [None; 8];
+        for (slot, value) in coords.iter_mut().zip(values) {
+            *slot = Some(value);
+        }
+        Self::from_coordinates(base, coords)
+    }
+}
+
+/// The primes whose exponents carry the 8 coordinates in a Gödel string
+const GODEL_PRIMES: [u64; 8] = [2, 3, 5, 7, 11, 13, 17, 19];
+
+impl<B: Default> Bott8D<B, f64> {
+    /// Encode the coordinates as a Gödel-factored string such as `"2^5 · 3^1 · 19^2"`
+    ///
+    /// Dimension `i` is carried by the exponent of the `i`-th prime. An unset
+    /// coordinate has exponent 0 and is omitted; a set coordinate `v` has exponent
+    /// `zigzag(v) + 1`, where zigzag maps 0, -1, 1, -2, ... to 0, 1, 2, 3, ...
+    /// Coordinates are rounded to integers and saturated to the `i64` range
+    /// (NaN encodes as 0), so only integral ones within that range round-trip
+    /// exactly. Exponents are computed in `u128`, so even `i64::MIN` encodes
+    /// without overflow. A structure with no coordinates encodes as `"1"`. The
+    /// base and curvature are not encoded.
+    pub fn to_godel_string(&self) -> String {
+        let factors: Vec<String> = GODEL_PRIMES.iter()
+            .zip(&self.coordinates)
+            .filter_map(|(prime, coord)| {
+                let value = (*coord)?.round() as i64 as i128;
+                let zigzag = if value >= 0 { 2 * value as u128 } else { 2 * value.unsigned_abs() - 1 };
+                Some(format!("{}^{}", prime, zigzag + 1))
+            })
+            .collect();
+
+        if factors.is_empty() { "1".to_string() } else { factors.join(" · ") }
+    }
+
+    /// Decode a string produced by `to_godel_string`, with a default base
+    ///
+    /// Factors may appear in any order and a bare prime means exponent 1.
+    /// Returns an error for primes beyond the 8th, repeated primes or malformed factors.
+    pub fn from_godel_string(encoded: &str) -> Result<Self, String> {
+        let mut bott = Self::new(B::default());
+        if encoded.trim() == "1" {
+            return Ok(bott);
+        }
+
+        for factor in encoded.split('·').map(str::trim) {
+            let (prime, exponent) = factor.split_once('^').unwrap_or((factor, "1"));
+            let prime: u64 = prime.trim().parse()
+                .map_err(|_| format!("invalid prime in factor '{}'", factor))?;
+            let exponent: u128 = exponent.trim().parse()
+                .map_err(|_| format!("invalid exponent in factor '{}'", factor))?;
+            let dim = GODEL_PRIMES.iter().position(|&p| p == prime)
+                .ok_or_else(|| format!("{} is not one of the first 8 primes", prime))?;
+            if bott.coordinates[dim].is_some() {
+                return Err(format!("prime {} appears more than once", prime));
+            }
+            if exponent == 0 {
+                continue;
+            }
+
+            let zigzag = exponent - 1;
+            let value = if zigzag & 1 == 0 { (zigzag / 2) as f64 } else { -(zigzag.div_ceil(2) as f64) };
+            bott.coordinates[dim] = Some(value);
+        }
+        Ok(bott)
+    }
 }
 
 impl<B: Clone + 'static, F: Clone + 'static + Copy> Bott for Bott8D<B, F> {
@@ -380,13 +550,16 @@ impl<B: Clone + 'static, F: Clone + 'static + Copy> Bott for Bott8D<B, F> {
     }
     
     fn bott_periodicity_class(&self) -> u8 {
-        // Simplified - in practice this would compute actual Bott periodicity
-        0
+        // Real K-theory repeats with period 8 in the dimension
+        (self.filled_dimensions() % 8) as u8
     }
     
     fn connection_form(&self) -> Vec<f64> {
-        // Simplified - in practice this would compute actual connection forms
-        vec![0.0; 8]
+        // The traced connection A_j = Γ^k_kj, i.e. ∂_j ln √|det g|
+        match self.christoffel_symbols() {
+            Some(symbols) => (0..8).map(|j| (0..8).map(|k| symbols[k][k][j]).sum()).collect(),
+            None => vec![0.0; 8],
+        }
     }
     
     fn parallel_transport(&self, _path: &[Self::Base]) -> Vec<Self::Fiber> {
@@ -395,8 +568,10 @@ impl<B: Clone + 'static, F: Clone + 'static + Copy> Bott for Bott8D<B, F> {
     }
     
     fn curvature_form(&self) -> Vec<Vec<f64>> {
-        // Simplified - in practice this would compute actual curvature
-        vec![vec![0.0; 8]; 8]
+        match &self.curvature {
+            Some(curvature) => curvature.iter().map(|row| row.to_vec()).collect(),
+            None => vec![vec![0.0; 8]; 8],
+        }
     }
     
     fn holonomy_group(&self) -> Vec<Vec<f64>> {
@@ -405,8 +580,17 @@ impl<B: Clone + 'static, F: Clone + 'static + Copy> Bott for Bott8D<B, F> {
     }
     
     fn chern_classes(&self) -> Vec<f64> {
-        // Simplified - in practice this would compute actual Chern classes
-        vec![0.0; 4]
+        // Simplified - the Chern character terms tr(F^k) / (2π)^k for k = 1..=4
+        let curvature = self.curvature_form();
+        let scale = 2.0 * std::f64::consts::PI;
+        let mut power = curvature.clone();
+        (1..=4).map(|k| {
+            let trace: f64 = (0..8).map(|i| power[i][i]).sum();
+            power = (0..8).map(|i| (0..8).map(|j| {
+                (0..8).map(|m| power[i][m] * curvature[m][j]).sum()
+            }).collect()).collect();
+            trace / scale.powi(k)
+        }).collect()
     }
     
     fn euler_characteristic(&self) -> i32 {
@@ -650,8 +834,170 @@ impl<B: Clone + 'static, F: Clone + 'static + Copy> Bott for Bott8D<B, F> {
     }
 }
 
+/// Inverts an 8x8 matrix by Gauss-Jordan elimination, or `None` if it is singular
+fn invert_matrix(mut matrix: [[f64; 8]; 8]) -> Option<[[f64; 8]; 8]> {
+    let mut inverse: [[f64; 8]; 8] = std::array::from_fn(|i| std::array::from_fn(|j| if i == j { 1.0 } else { 0.0 }));
+    for col in 0..8 {
+        let pivot = (col..8).max_by(|&a, &b| matrix[a][col].abs().total_cmp(&matrix[b][col].abs()))?;
+        if matrix[pivot][col].abs() < 1e-12 {
+            return None;
+        }
+        matrix.swap(col, pivot);
+        inverse.swap(col, pivot);
+        
+        let scale = matrix[col][col];
+        for j in 0..8 {
+            matrix[col][j] /= scale;
+            inverse[col][j] /= scale;
+        }
+        for row in 0..8 {
+            if row != col {
+                let factor = matrix[row][col];
+                for j in 0..8 {
+                    matrix[row][j] -= factor * matrix[col][j];
+                    inverse[row][j] -= factor * inverse[col][j];
+                }
+            }
+        }
+    }
+    Some(inverse)
+}
+
 impl<B: Default, F: Default + Copy> Default for Bott8D<B, F> {
     fn default() -> Self {
         Self::new(B::default())
     }
-}
\ No newline at end of file
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn structure(values: &[f64]) -> Bott8D<f64, f64> {
+        let mut bott = Bott8D::new(0.0);
+        for (dim, &value) in values.iter().enumerate() {
+            bott.set_coord(dim as u8, value);
+        }
+        bott
+    }
+
+    #[test]
+    fn test_godel_string_round_trip() {
+        let mut bott = structure(&[2.0, 0.0, -3.0, 7.0, -1.0, 12.0, 5.0, -40.0]);
+        bott.coordinates[1] = None;
+
+        let encoded = bott.to_godel_string();
+        assert_eq!(encoded, "2^5 · 5^6 · 7^15 · 11^2 · 13^25 · 17^11 · 19^80");
+
+        let decoded = Bott8D::<f64, f64>::from_godel_string(&encoded).unwrap();
+        assert_eq!(decoded.coordinates, bott.coordinates);
+
+        let empty = Bott8D::<f64, f64>::new(0.0);
+        assert_eq!(empty.to_godel_string(), "1");
+        assert_eq!(Bott8D::<f64, f64>::from_godel_string("1").unwrap().filled_dimensions(), 0);
+        // A zero coordinate is set, unlike an absent one
+        assert_eq!(structure(&[0.0]).to_godel_string(), "2^1");
+        assert_eq!(Bott8D::<f64, f64>::from_godel_string("3 · 2^3").unwrap().coordinates[..2], [Some(1.0), Some(0.0)]);
+
+        // Extreme and saturated coordinates encode without overflowing the exponent
+        let extremes = structure(&[i64::MIN as f64, i64::MAX as f64, f64::NEG_INFINITY, f64::INFINITY]);
+        let encoded = extremes.to_godel_string();
+        assert_eq!(encoded, format!("2^{0} · 3^{1} · 5^{0} · 7^{1}", 1u128 << 64, u64::MAX));
+        let decoded = Bott8D::<f64, f64>::from_godel_string(&encoded).unwrap();
+        assert_eq!(decoded.coordinates[..4], [Some(i64::MIN as f64), Some(i64::MAX as f64), Some(i64::MIN as f64), Some(i64::MAX as f64)]);
+
+        assert!(Bott8D::<f64, f64>::from_godel_string("23^2").is_err());
+        assert!(Bott8D::<f64, f64>::from_godel_string("2^1 · 2^3").is_err());
+        assert!(Bott8D::<f64, f64>::from_godel_string("2^x").is_err());
+    }
+
+    fn identity_metric(_point: &[f64; 8]) -> [[f64; 8]; 8] {
+        std::array::from_fn(|i| std::array::from_fn(|j| if i == j { 1.0 } else { 0.0 }))
+    }
+
+    /// g = diag(1, 1 + x0², 1, ..., 1)
+    fn stretched_metric(point: &[f64; 8]) -> [[f64; 8]; 8] {
+        let mut metric = identity_metric(point);
+        metric[1][1] = 1.0 + point[0] * point[0];
+        metric
+    }
+
+    #[test]
+    fn test_connection_form_from_metric() {
+        assert_eq!(structure(&[1.0]).connection_form(), vec![0.0; 8]);
+
+        let flat = structure(&[1.0]).with_metric(identity_metric, [0.5; 8]);
+        assert!(flat.connection_form().iter().all(|a| a.abs() < 1e-9));
+
+        // At x0 = 1: Γ^0_11 = -x0 = -1 and Γ^1_01 = Γ^1_10 = x0 / (1 + x0²) = 1/2
+        let mut point = [0.0; 8];
+        point[0] = 1.0;
+        let curved = structure(&[1.0]).with_metric(stretched_metric, point);
+        let symbols = curved.christoffel_symbols().unwrap();
+        assert!((symbols[0][1][1] + 1.0).abs() < 1e-6);
+        assert!((symbols[1][0][1] - 0.5).abs() < 1e-6);
+        assert!((symbols[1][1][0] - 0.5).abs() < 1e-6);
+        assert!(symbols[0][0][0].abs() < 1e-6);
+
+        let connection = curved.connection_form();
+        assert!((connection[0] - 0.5).abs() < 1e-6);
+        assert!(connection[1..].iter().all(|a| a.abs() < 1e-6));
+    }
+
+    #[test]
+    fn test_flat_structures_of_same_dimension_are_homotopic() {
+        let a = structure(&[1.0, 2.0, 3.0]);
+        let b = structure(&[-4.0, 0.5, 9.0]);
+
+        assert!(a.is_homotopic(&b));
+        assert!(!a.is_homotopic(&structure(&[1.0, 2.0])));
+    }
+
+    #[test]
+    fn test_curved_structure_is_not_homotopic_to_flat() {
+        let mut curvature = [[0.0; 8]; 8];
+        curvature[0][1] = 1.0;
+        curvature[1][0] = -1.0;
+        let curved = structure(&[1.0, 2.0, 3.0]).with_curvature(curvature);
+        let flat = structure(&[1.0, 2.0, 3.0]);
+
+        assert!(!curved.is_flat());
+        assert!(!curved.is_homotopic(&flat));
+        assert!(curved.is_homotopic(&curved.clone()));
+    }
+
+    #[test]
+    fn test_direct_sum_fills_from_both_operands() {
+        let sum = structure(&[1.0, 2.0, 3.0]).direct_sum(&structure(&[4.0, 5.0]));
+
+        assert_eq!(sum.filled_dimensions(), 5);
+        assert_eq!(sum.coord(2), Some(&3.0));
+        assert_eq!(sum.coord(3), Some(&4.0));
+        assert_eq!(sum.coord(4), Some(&5.0));
+    }
+
+    #[test]
+    fn test_direct_sum_caps_at_max_dimension() {
+        let sum = structure(&[1.0; 6]).direct_sum(&structure(&[2.0; 6]));
+
+        assert!(sum.is_complete());
+        assert_eq!(sum.coord(5), Some(&1.0));
+        assert_eq!(sum.coord(7), Some(&2.0));
+    }
+
+    #[test]
+    fn test_tensor_of_single_coordinates_is_product() {
+        let product = structure(&[3.0]).tensor(&structure(&[7.0]));
+
+        assert_eq!(product.filled_dimensions(), 1);
+        assert_eq!(product.coord(0), Some(&21.0));
+    }
+
+    #[test]
+    fn test_tensor_pairs_all_fibers() {
+        let product = structure(&[1.0, 2.0]).tensor(&structure(&[3.0, 5.0]));
+        let values: Vec<f64> = product.all_coords().iter().flatten().copied().collect();
+
+        assert_eq!(values, vec![3.0, 5.0, 6.0, 10.0]);
+    }
+}
@@ -9,5 +9,401 @@
 //!
 //! - **`SolMultivector`**: A struct representing a multivector from `solfunmeme_clifford`.
 //! - **`BertCliffordEncoder`**: Encoder to map BERT embeddings to Clifford algebra representation.
+//! - **`CliffordDyn` Trait**: The `dyn`-safe core of a multivector (dimension, norm, coefficients).
+//! - **`Clifford` Trait**: Extends `CliffordDyn` with the algebra itself (geometric product,
+//!   reverse, inverse).
+//! - **`CliffordMultivector`**: A dense multivector of the Euclidean algebra Cl(n, 0),
+//!   storing one coefficient per basis blade. Blades are indexed by bitmask, so
+//!   index `0b101` is `e1∧e3`.
 
 pub use solfunmeme_clifford::{SolMultivector, BertCliffordEncoder, SolCl, BertConfig as CliffordBertConfig};
+
+use std::fmt;
+use std::sync::OnceLock;
+
+/// Tolerance used when deciding whether a multivector component vanishes.
+const EPSILON: f64 = 1e-12;
+
+/// Largest dimension whose blade multiplication table is cached (`4^8` signs).
+/// Products in larger algebras compute each sign on the fly.
+const MAX_CACHED_DIMENSION: usize = 8;
+
+/// Cayley tables of Cl(n, 0), built on first use and shared across threads.
+/// Entry `a * 2^n + b` holds the sign of `e_a * e_b`; the product blade is always `a ^ b`.
+static CAYLEY_TABLES: [OnceLock<Vec<f64>>; MAX_CACHED_DIMENSION + 1] =
+    [const { OnceLock::new() }; MAX_CACHED_DIMENSION + 1];
+
+/// Returns the cached Cayley table for `dimension`, if it is small enough to cache
+fn cayley_table(dimension: usize) -> Option<&'static [f64]> {
+    let table = CAYLEY_TABLES.get(dimension)?.get_or_init(|| {
+        let blades = 1 << dimension;
+        (0..blades * blades)
+            .map(|i| CliffordMultivector::reordering_sign(i / blades, i % blades))
+            .collect()
+    });
+    Some(table)
+}
+
+/// Core operations of a Clifford algebra element
+pub trait Clifford: CliffordDyn + Sized {
+    /// Get the grade-0 (scalar) part
+    fn scalar_part(&self) -> Self::Scalar;
+
+    /// Compute the geometric product `self * other`
+    fn geometric_product(&self, other: &Self) -> Self;
+
+    /// Compute the reverse, flipping the sign of grades 2 and 3 (mod 4)
+    fn reverse(&self) -> Self;
+
+    /// Compute the multiplicative inverse, if one exists
+    fn inverse(&self) -> Option<Self>;
+}
+
+/// Dyn-compatible interface for Clifford operations
+/// This trait provides a simplified interface that can be used with trait objects
+pub trait CliffordDyn {
+    /// The scalar type of the algebra
+    type Scalar;
+
+    /// Get the dimension of the generating vector space
+    fn dimension(&self) -> usize;
+
+    /// Get the Euclidean norm of the coefficient vector
+    fn norm(&self) -> f64;
+
+    /// Get the coefficients, one per basis blade
+    fn coefficients(&self) -> Vec<f64>;
+}
+
+/// A dense multivector of the Euclidean Clifford algebra Cl(n, 0)
+#[derive(Debug, Clone, PartialEq)]
+pub struct CliffordMultivector {
+    dimension: usize,
+    coefficients: Vec<f64>,
+}
+
+impl CliffordMultivector {
+    /// Create the zero multivector over an `dimension`-dimensional space
+    pub fn new(dimension: usize) -> Self {
+        Self {
+            dimension,
+            coefficients: vec![0.0; 1 << dimension],
+        }
+    }
+
+    /// Create from blade coefficients, padding with zeros or truncating to `2^dimension`
+    pub fn from_coefficients(dimension: usize, coefficients: &[f64]) -> Self {
+        let mut multivector = Self::new(dimension);
+        for (slot, &value) in multivector.coefficients.iter_mut().zip(coefficients) {
+            *slot = value;
+        }
+        multivector
+    }
+
+    /// Create from a flat blade list, using the smallest algebra that holds every coefficient
+    pub fn from_blades(coefficients: &[f64]) -> Self {
+        let dimension = coefficients.len().next_power_of_two().trailing_zeros() as usize;
+        Self::from_coefficients(dimension, coefficients)
+    }
+
+    /// Create a scalar multivector
+    pub fn scalar(dimension: usize, value: f64) -> Self {
+        let mut multivector = Self::new(dimension);
+        multivector.coefficients[0] = value;
+        multivector
+    }
+
+    /// Create a grade-1 vector with one component per basis vector
+    pub fn vector(components: &[f64]) -> Self {
+        let mut multivector = Self::new(components.len());
+        for (i, &value) in components.iter().enumerate() {
+            multivector.coefficients[1 << i] = value;
+        }
+        multivector
+    }
+
+    /// Get the coefficient of the blade with the given bitmask
+    pub fn coefficient(&self, blade: usize) -> f64 {
+        self.coefficients.get(blade).copied().unwrap_or(0.0)
+    }
+
+    /// Set the coefficient of the blade with the given bitmask
+    pub fn set_coefficient(&mut self, blade: usize, value: f64) -> bool {
+        match self.coefficients.get_mut(blade) {
+            Some(slot) => {
+                *slot = value;
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Multiply every coefficient by a scalar
+    pub fn scale(&self, factor: f64) -> Self {
+        Self {
+            dimension: self.dimension,
+            coefficients: self.coefficients.iter().map(|c| c * factor).collect(),
+        }
+    }
+
+    /// Check whether every component above grade 0 vanishes
+    pub fn is_scalar(&self) -> bool {
+        self.coefficients[1..].iter().all(|c| c.abs() < EPSILON)
+    }
+
+    /// Check whether every blade coefficient is within `eps` of the other's
+    ///
+    /// Blades missing from the smaller algebra count as zero.
+    pub fn approx_eq(&self, other: &Self, eps: f64) -> bool {
+        let blades = self.coefficients.len().max(other.coefficients.len());
+        (0..blades).all(|blade| (self.coefficient(blade) - other.coefficient(blade)).abs() <= eps)
+    }
+
+    /// Versor conjugation (sandwich product) `versor * self * versor⁻¹`
+    /// Returns `None` when the versor is not invertible.
+    pub fn conjugate_by(&self, versor: &Self) -> Option<Self> {
+        let inverse = versor.inverse()?;
+        Some(versor.geometric_product(self).geometric_product(&inverse))
+    }
+
+    /// Sign picked up when reordering the basis vectors of `a * b` into canonical order
+    fn reordering_sign(a: usize, b: usize) -> f64 {
+        let mut a = a >> 1;
+        let mut swaps = 0;
+        while a != 0 {
+            swaps += (a & b).count_ones();
+            a >>= 1;
+        }
+        if swaps % 2 == 0 { 1.0 } else { -1.0 }
+    }
+
+    /// Geometric product using `sign(a, b)` for the sign of each blade product
+    fn product_with(&self, other: &Self, sign: impl Fn(usize, usize) -> f64) -> Self {
+        let mut product = Self::new(self.dimension.max(other.dimension));
+        for (a, &x) in self.coefficients.iter().enumerate() {
+            if x == 0.0 {
+                continue;
+            }
+            for (b, &y) in other.coefficients.iter().enumerate() {
+                if y == 0.0 {
+                    continue;
+                }
+                // Euclidean signature: every basis vector squares to +1
+                product.coefficients[a ^ b] += sign(a, b) * x * y;
+            }
+        }
+        product
+    }
+}
+
+impl CliffordDyn for CliffordMultivector {
+    type Scalar = f64;
+
+    fn dimension(&self) -> usize {
+        self.dimension
+    }
+
+    fn norm(&self) -> f64 {
+        self.coefficients.iter().map(|c| c * c).sum::<f64>().sqrt()
+    }
+
+    fn coefficients(&self) -> Vec<f64> {
+        self.coefficients.clone()
+    }
+}
+
+impl Clifford for CliffordMultivector {
+    fn scalar_part(&self) -> f64 {
+        self.coefficients[0]
+    }
+
+    /// Operands of different dimensions are multiplied in the larger algebra.
+    /// Blade signs come from the shared Cayley table when the algebra is small
+    /// enough to cache one.
+    fn geometric_product(&self, other: &Self) -> Self {
+        let dimension = self.dimension.max(other.dimension);
+        match cayley_table(dimension) {
+            Some(table) => self.product_with(other, |a, b| table[(a << dimension) | b]),
+            None => self.product_with(other, Self::reordering_sign),
+        }
+    }
+
+    fn reverse(&self) -> Self {
+        let coefficients = self.coefficients.iter().enumerate()
+            .map(|(blade, &c)| {
+                let grade = blade.count_ones();
+                if (grade * grade.saturating_sub(1) / 2) % 2 == 0 { c } else { -c }
+            })
+            .collect();
+        Self {
+            dimension: self.dimension,
+            coefficients,
+        }
+    }
+
+    /// Only versors (and blades) are inverted: elements whose product with their
+    /// reverse is a non-zero scalar. Anything else returns `None`.
+    fn inverse(&self) -> Option<Self> {
+        let reverse = self.reverse();
+        let magnitude = self.geometric_product(&reverse);
+        let scalar = magnitude.scalar_part();
+        if !magnitude.is_scalar() || scalar.abs() < EPSILON {
+            return None;
+        }
+        Some(reverse.scale(1.0 / scalar))
+    }
+}
+
+/// Prints the non-zero components in basis-blade notation, e.g. `3.0 + 2.0·e1 + 1.5·e1e2`.
+/// The zero multivector prints as `0`.
+impl fmt::Display for CliffordMultivector {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut terms = self.coefficients.iter().enumerate().filter(|(_, c)| c.abs() >= EPSILON);
+        let Some((blade, &first)) = terms.next() else {
+            return write!(f, "0");
+        };
+
+        write_term(f, blade, first)?;
+        for (blade, &c) in terms {
+            write!(f, " {} ", if c < 0.0 { '-' } else { '+' })?;
+            write_term(f, blade, c.abs())?;
+        }
+        Ok(())
+    }
+}
+
+/// Writes one component, labelling non-scalar blades by their basis vectors
+fn write_term(f: &mut fmt::Formatter<'_>, blade: usize, coefficient: f64) -> fmt::Result {
+    write!(f, "{:?}", coefficient)?;
+    if blade != 0 {
+        write!(f, "·")?;
+        for i in (0..usize::BITS as usize).filter(|i| blade & (1 << i) != 0) {
+            write!(f, "e{}", i + 1)?;
+        }
+    }
+    Ok(())
+}
+
+impl Default for CliffordMultivector {
+    fn default() -> Self {
+        Self::new(3)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const E1: usize = 0b01;
+    const E2: usize = 0b10;
+    const E12: usize = 0b11;
+
+    #[test]
+    fn test_geometric_product_of_basis_vectors() {
+        let e1 = CliffordMultivector::vector(&[1.0, 0.0]);
+        let e2 = CliffordMultivector::vector(&[0.0, 1.0]);
+
+        assert_eq!(e1.geometric_product(&e1).scalar_part(), 1.0);
+        assert_eq!(e1.geometric_product(&e2).coefficient(E12), 1.0);
+        assert_eq!(e2.geometric_product(&e1).coefficient(E12), -1.0);
+    }
+
+    #[test]
+    fn test_conjugate_by_unit_rotor_rotates_vector() {
+        // R = cos(θ/2) - sin(θ/2) e12 rotates e1 towards e2 by θ
+        let half_angle = std::f64::consts::FRAC_PI_4;
+        let mut rotor = CliffordMultivector::scalar(2, half_angle.cos());
+        rotor.set_coefficient(E12, -half_angle.sin());
+
+        let rotated = CliffordMultivector::vector(&[1.0, 0.0]).conjugate_by(&rotor).unwrap();
+
+        assert!(rotated.approx_eq(&CliffordMultivector::vector(&[0.0, 1.0]), 1e-12));
+        assert!((rotated.norm() - 1.0).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_approx_eq_respects_epsilon() {
+        let a = CliffordMultivector::from_coefficients(2, &[1.0, 2.0, 3.0, 4.0]);
+        let mut b = a.clone();
+        b.set_coefficient(E2, 3.0 + 1e-12);
+
+        assert_ne!(a, b);
+        assert!(a.approx_eq(&b, 1e-9));
+        assert!(!a.approx_eq(&b, 1e-15));
+
+        // Missing blades of a smaller algebra compare as zero
+        assert!(CliffordMultivector::vector(&[1.0]).approx_eq(&CliffordMultivector::vector(&[1.0, 1e-12]), 1e-9));
+    }
+
+    fn random_multivector(dimension: usize, state: &mut u32) -> CliffordMultivector {
+        let coefficients: Vec<f64> = (0..1 << dimension)
+            .map(|_| {
+                *state = state.wrapping_mul(1103515245).wrapping_add(12345);
+                ((*state >> 16) % 200) as f64 / 10.0 - 10.0
+            })
+            .collect();
+        CliffordMultivector::from_coefficients(dimension, &coefficients)
+    }
+
+    #[test]
+    fn test_cached_product_matches_uncached() {
+        let mut state = 42;
+        for dimension in [0, 1, 3, 5, MAX_CACHED_DIMENSION] {
+            let a = random_multivector(dimension, &mut state);
+            let b = random_multivector(dimension, &mut state);
+
+            let uncached = a.product_with(&b, CliffordMultivector::reordering_sign);
+            assert_eq!(a.geometric_product(&b), uncached);
+        }
+
+        // Mixed dimensions use the larger algebra's table
+        let small = random_multivector(2, &mut state);
+        let large = random_multivector(4, &mut state);
+        assert_eq!(
+            small.geometric_product(&large),
+            small.product_with(&large, CliffordMultivector::reordering_sign)
+        );
+    }
+
+    #[test]
+    fn test_cayley_table_cached_once() {
+        // The first product in Cl(4) builds the table; later lookups hit the same one
+        let mut state = 7;
+        let a = random_multivector(4, &mut state);
+        let b = random_multivector(4, &mut state);
+        a.geometric_product(&b);
+        let table = cayley_table(4).unwrap();
+        assert!(std::ptr::eq(table, cayley_table(4).unwrap()));
+        assert_eq!(table.len(), 16 * 16);
+
+        // Algebras past the cached range miss and compute signs on the fly
+        assert!(cayley_table(MAX_CACHED_DIMENSION + 1).is_none());
+        let large = random_multivector(MAX_CACHED_DIMENSION + 1, &mut state);
+        assert_eq!(
+            large.geometric_product(&large),
+            large.product_with(&large, CliffordMultivector::reordering_sign)
+        );
+    }
+
+    #[test]
+    fn test_display_basis_blade_notation() {
+        let mut multivector = CliffordMultivector::scalar(3, 3.0);
+        multivector.set_coefficient(E1, 2.0);
+        multivector.set_coefficient(E12, 1.5);
+        multivector.set_coefficient(0b110, -0.5);
+
+        assert_eq!(multivector.to_string(), "3.0 + 2.0·e1 + 1.5·e1e2 - 0.5·e2e3");
+        assert_eq!(CliffordMultivector::vector(&[0.0, -1.0]).to_string(), "-1.0·e2");
+        assert_eq!(CliffordMultivector::new(3).to_string(), "0");
+    }
+
+    #[test]
+    fn test_conjugate_by_non_invertible_is_none() {
+        // 1 + e1 is a zero divisor: (1 + e1)(1 - e1) = 0
+        let mut null = CliffordMultivector::scalar(2, 1.0);
+        null.set_coefficient(E1, 1.0);
+
+        assert!(null.inverse().is_none());
+        assert!(CliffordMultivector::vector(&[0.0, 1.0]).conjugate_by(&null).is_none());
+    }
+}
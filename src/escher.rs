@@ -10,6 +10,57 @@
 //! - **`Escher` Trait**: Defines a set of methods for generating and transforming
 //!   various forms of mathematical art.
 //! - **`EscherArtist` Struct**: A concrete implementation of the `Escher` trait.
+//! - **`WallpaperGroup` Enum**: The symmetry group of a periodic pattern.
+
+use std::fmt;
+use crate::penrose::Edge;
+
+/// The wallpaper (plane crystallographic) group of a periodic pattern.
+///
+/// Only the most common groups are distinguished; patterns whose symmetries
+/// belong to one of the remaining groups are reported as the closest listed
+/// group with the same rotational order.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum WallpaperGroup {
+    /// Translations only.
+    P1,
+    /// Half-turn rotations.
+    P2,
+    /// Parallel mirror lines.
+    Pm,
+    /// Perpendicular mirror lines with half-turns at their intersections.
+    Pmm,
+    /// Quarter-turn rotations.
+    P4,
+    /// Quarter-turn rotations with mirror lines.
+    P4m,
+}
+
+impl WallpaperGroup {
+    /// Returns the order of the group's point group, a measure of symmetry richness.
+    pub fn point_group_order(&self) -> usize {
+        match self {
+            WallpaperGroup::P1 => 1,
+            WallpaperGroup::P2 | WallpaperGroup::Pm => 2,
+            WallpaperGroup::Pmm | WallpaperGroup::P4 => 4,
+            WallpaperGroup::P4m => 8,
+        }
+    }
+}
+
+impl fmt::Display for WallpaperGroup {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let name = match self {
+            WallpaperGroup::P1 => "p1",
+            WallpaperGroup::P2 => "p2",
+            WallpaperGroup::Pm => "pm",
+            WallpaperGroup::Pmm => "pmm",
+            WallpaperGroup::P4 => "p4",
+            WallpaperGroup::P4m => "p4m",
+        };
+        write!(f, "{}", name)
+    }
+}
 
 /// A trait for generating and manipulating visual mathematical art and geometry.
 pub trait Escher {
@@ -18,6 +69,11 @@ pub trait Escher {
     fn generate_tessellation(&self, pattern: &str, width: usize, height: usize) -> Vec<Vec<u8>>;
     /// Analyzes the symmetry groups of a tessellation.
     fn analyze_symmetry(&self, tessellation: &[Vec<u8>]) -> String;
+    /// Classifies a periodic pattern into its wallpaper group.
+    ///
+    /// The pattern is treated as one period of an infinite tiling, so
+    /// symmetries may be centered anywhere on the torus it wraps onto.
+    fn wallpaper_group(&self, pattern: &[Vec<u8>]) -> WallpaperGroup;
     /// Applies a transformation (e.g., rotation, reflection) to a tessellation.
     fn transform_tessellation(&self, tessellation: &[Vec<u8>], transformation: &str) -> Vec<Vec<u8>>;
 
@@ -46,6 +102,15 @@ pub trait Escher {
     fn render_ascii(&self, grid: &[Vec<u8>]) -> String;
     /// Exports a pattern as an SVG image string.
     fn export_svg(&self, pattern: &[Vec<u8>]) -> String;
+    /// Encodes a grid as a binary PGM (P5) grayscale image, one byte per pixel.
+    /// Ragged rows are padded with black to the width of the longest row.
+    fn to_pgm(&self, grid: &[Vec<u8>]) -> Vec<u8>;
+    /// Draws an edge list, such as a Penrose tiling, into a `height` × `width` grid.
+    ///
+    /// The edges' bounding box is scaled uniformly to fit the grid, with `y`
+    /// pointing up, and each edge is drawn as a Bresenham line of 255s on a zero
+    /// background, ready for `to_pgm`.
+    fn rasterize_tiling(&self, edges: &[Edge], width: usize, height: usize) -> Vec<Vec<u8>>;
 }
 
 /// A concrete implementation of the `Escher` trait for creating mathematical art.
@@ -62,6 +127,42 @@ impl Escher for EscherArtist {
     fn analyze_symmetry(&self, _tessellation: &[Vec<u8>]) -> String {
         "Symmetry analysis not implemented".to_string()
     }
+    fn wallpaper_group(&self, pattern: &[Vec<u8>]) -> WallpaperGroup {
+        let height = pattern.len();
+        let width = pattern.first().map_or(0, |row| row.len());
+        if height == 0 || width == 0 || pattern.iter().any(|row| row.len() != width) {
+            return WallpaperGroup::P1;
+        }
+        let (h, w) = (height as i64, width as i64);
+
+        let half_turn = has_periodic_symmetry(pattern, |y, x, a, b| (a - y, b - x));
+        let vertical_mirror = has_periodic_symmetry(pattern, |y, x, _, b| (y, b - x));
+        let horizontal_mirror = has_periodic_symmetry(pattern, |y, x, a, _| (a - y, x));
+        let (quarter_turn, diagonal_mirror) = if h == w {
+            (
+                has_periodic_symmetry(pattern, |y, x, a, b| (a + x, b - y)),
+                has_periodic_symmetry(pattern, |y, x, a, b| (a + x, b + y)),
+            )
+        } else {
+            (false, false)
+        };
+
+        if quarter_turn {
+            if vertical_mirror || horizontal_mirror || diagonal_mirror {
+                WallpaperGroup::P4m
+            } else {
+                WallpaperGroup::P4
+            }
+        } else if vertical_mirror && horizontal_mirror {
+            WallpaperGroup::Pmm
+        } else if vertical_mirror || horizontal_mirror {
+            WallpaperGroup::Pm
+        } else if half_turn {
+            WallpaperGroup::P2
+        } else {
+            WallpaperGroup::P1
+        }
+    }
     fn transform_tessellation(&self, tessellation: &[Vec<u8>], _transformation: &str) -> Vec<Vec<u8>> {
         tessellation.to_vec()
     }
@@ -92,4 +193,194 @@ impl Escher for EscherArtist {
     fn export_svg(&self, _pattern: &[Vec<u8>]) -> String {
         "<svg><!-- SVG output --></svg>".to_string()
     }
+
+    fn to_pgm(&self, grid: &[Vec<u8>]) -> Vec<u8> {
+        let width = grid.iter().map(Vec::len).max().unwrap_or(0);
+        let mut image = format!("P5\n{} {}\n255\n", width, grid.len()).into_bytes();
+        for row in grid {
+            image.extend_from_slice(row);
+            image.resize(image.len() + width - row.len(), 0);
+        }
+        image
+    }
+
+    fn rasterize_tiling(&self, edges: &[Edge], width: usize, height: usize) -> Vec<Vec<u8>> {
+        let mut grid = vec![vec![0; width]; height];
+        if edges.is_empty() || width == 0 || height == 0 {
+            return grid;
+        }
+
+        let points = edges.iter().flat_map(|&(a, b)| [a, b]);
+        let (min_x, max_x) = points.clone().fold((f64::INFINITY, f64::NEG_INFINITY), |(lo, hi), (x, _)| (lo.min(x), hi.max(x)));
+        let (min_y, max_y) = points.fold((f64::INFINITY, f64::NEG_INFINITY), |(lo, hi), (_, y)| (lo.min(y), hi.max(y)));
+        // Fit the longer side of the bounding box; a degenerate axis does not constrain the scale
+        let fit = |span: f64, cells: usize| if span > 0.0 { (cells - 1) as f64 / span } else { f64::INFINITY };
+        let scale = fit(max_x - min_x, width).min(fit(max_y - min_y, height));
+        let scale = if scale.is_finite() { scale } else { 0.0 };
+        let to_cell = |(x, y): (f64, f64)| {
+            let column = ((x - min_x) * scale).round() as i64;
+            let row = (height - 1) as i64 - ((y - min_y) * scale).round() as i64;
+            (column, row)
+        };
+
+        for &(start, end) in edges {
+            let (mut x, mut y) = to_cell(start);
+            let (x1, y1) = to_cell(end);
+            let (dx, dy) = ((x1 - x).abs(), -(y1 - y).abs());
+            let (step_x, step_y) = ((x1 - x).signum(), (y1 - y).signum());
+            let mut error = dx + dy;
+            loop {
+                if let Some(cell) = grid.get_mut(y as usize).and_then(|row| row.get_mut(x as usize)) {
+                    *cell = 255;
+                }
+                if x == x1 && y == y1 {
+                    break;
+                }
+                let doubled = 2 * error;
+                if doubled >= dy {
+                    error += dy;
+                    x += step_x;
+                }
+                if doubled <= dx {
+                    error += dx;
+                    y += step_y;
+                }
+            }
+        }
+        grid
+    }
+}
+
+/// Checks whether a rectangular pattern, wrapped onto a torus, is invariant
+/// under `map` for some choice of offset `(a, b)`.
+///
+/// `map` receives `(y, x, a, b)` and returns the image cell, which is reduced
+/// modulo the pattern dimensions.
+fn has_periodic_symmetry<F>(pattern: &[Vec<u8>], map: F) -> bool
+where
+    F: Fn(i64, i64, i64, i64) -> (i64, i64),
+{
+    let h = pattern.len() as i64;
+    let w = pattern[0].len() as i64;
+
+    (0..h).any(|a| {
+        (0..w).any(|b| {
+            (0..h).all(|y| {
+                (0..w).all(|x| {
+                    let (my, mx) = map(y, x, a, b);
+                    pattern[my.rem_euclid(h) as usize][mx.rem_euclid(w) as usize]
+                        == pattern[y as usize][x as usize]
+                })
+            })
+        })
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn tile(motif: &[Vec<u8>], repeats: usize) -> Vec<Vec<u8>> {
+        (0..motif.len() * repeats)
+            .map(|y| {
+                let row = &motif[y % motif.len()];
+                (0..row.len() * repeats).map(|x| row[x % row.len()]).collect()
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_translated_motif_is_p1() {
+        let artist = EscherArtist;
+        let motif = vec![vec![1, 2, 3], vec![4, 5, 6], vec![7, 8, 9]];
+
+        assert_eq!(artist.wallpaper_group(&motif), WallpaperGroup::P1);
+        assert_eq!(artist.wallpaper_group(&tile(&motif, 3)), WallpaperGroup::P1);
+    }
+
+    #[test]
+    fn test_checkerboard_is_p4m() {
+        let artist = EscherArtist;
+        let checkerboard: Vec<Vec<u8>> = (0..8)
+            .map(|y| (0..8).map(|x| ((x + y) % 2) as u8).collect())
+            .collect();
+
+        let group = artist.wallpaper_group(&checkerboard);
+        assert_eq!(group, WallpaperGroup::P4m);
+        assert_eq!(group.to_string(), "p4m");
+    }
+
+    #[test]
+    fn test_mirror_and_rotation_groups() {
+        let artist = EscherArtist;
+
+        // Vertical stripes of differing widths mirror in both axes
+        let stripes = vec![vec![1, 1, 0, 2, 0, 1]; 4];
+        assert_eq!(artist.wallpaper_group(&stripes), WallpaperGroup::Pmm);
+
+        // A pinwheel has quarter-turn symmetry but no mirrors
+        let pinwheel = vec![
+            vec![0, 2, 0, 0],
+            vec![0, 1, 1, 2],
+            vec![2, 1, 1, 0],
+            vec![0, 0, 2, 0],
+        ];
+        assert_eq!(artist.wallpaper_group(&pinwheel), WallpaperGroup::P4);
+    }
+
+    #[test]
+    fn test_arbitrary_pattern_is_p1() {
+        let artist = EscherArtist;
+        let mut state: u32 = 42;
+        let pattern: Vec<Vec<u8>> = (0..7)
+            .map(|_| {
+                (0..9)
+                    .map(|_| {
+                        state = state.wrapping_mul(1103515245).wrapping_add(12345);
+                        (state >> 16) as u8 % 4
+                    })
+                    .collect()
+            })
+            .collect();
+
+        assert_eq!(artist.wallpaper_group(&pattern), WallpaperGroup::P1);
+    }
+
+    #[test]
+    fn test_to_pgm_header_matches_grid() {
+        let artist = EscherArtist;
+        let grid = vec![vec![0, 128, 255], vec![7, 7], vec![1, 2, 3]];
+        let image = artist.to_pgm(&grid);
+
+        // Header: magic, width, height, maxval, each followed by one whitespace byte
+        let header: Vec<&[u8]> = image.splitn(5, |b| b.is_ascii_whitespace()).collect();
+        assert_eq!(header[0], b"P5");
+        let width: usize = std::str::from_utf8(header[1]).unwrap().parse().unwrap();
+        let height: usize = std::str::from_utf8(header[2]).unwrap().parse().unwrap();
+        assert_eq!((width, height), (3, 3));
+        assert_eq!(header[3], b"255");
+
+        let pixels = header[4];
+        assert_eq!(pixels, [0, 128, 255, 7, 7, 0, 1, 2, 3]);
+    }
+
+    #[test]
+    fn test_rasterize_tiling_draws_edges() {
+        let artist = EscherArtist;
+        assert_eq!(artist.rasterize_tiling(&[], 3, 2), vec![vec![0; 3]; 2]);
+
+        // A rising diagonal fills the grid from the bottom-left corner
+        let grid = artist.rasterize_tiling(&[((0.0, 0.0), (1.0, 1.0))], 4, 4);
+        let lit: Vec<(usize, usize)> = (0..4)
+            .flat_map(|row| (0..4).map(move |column| (row, column)))
+            .filter(|&(row, column)| grid[row][column] == 255)
+            .collect();
+        assert_eq!(lit, vec![(0, 3), (1, 2), (2, 1), (3, 0)]);
+
+        // A horizontal edge spans the full width of the bottom row
+        let grid = artist.rasterize_tiling(&[((-1.0, 2.0), (1.0, 2.0))], 5, 3);
+        assert_eq!(grid[2], vec![255; 5]);
+        assert!(grid[..2].iter().all(|row| row.iter().all(|&v| v == 0)));
+        assert_eq!(artist.to_pgm(&grid).len(), "P5\n5 3\n255\n".len() + 15);
+    }
 }
@@ -11,6 +11,8 @@
 //!   and transformations for rigid body dynamics.
 //! - **`Eulerian` Struct**: A concrete implementation of the `Euler` trait.
 
+use crate::vectos::Complex;
+
 /// A trait for calculations related to number theory, topology, and mechanics,
 /// inspired by the work of Leonhard Euler.
 pub trait Euler {
@@ -28,12 +30,22 @@ pub trait Euler {
     fn euler_characteristic(&self, vertices: usize, edges: usize, faces: usize) -> isize;
     /// Calculates the greatest common divisor of two numbers.
     fn gcd(&self, a: u64, b: u64) -> u64;
+    /// Returns the first `terms` partial quotients of the simple continued fraction
+    /// of e, `[2; 1, 2, 1, 1, 4, 1, 1, 6, ...]`.
+    fn e_continued_fraction(&self, terms: usize) -> Vec<i64>;
+    /// Returns the convergent `(numerator, denominator)` of e built from the first
+    /// `terms` partial quotients (at least the integer part). If the next convergent
+    /// would overflow `i64`, the last representable one is returned.
+    fn approximate_e(&self, terms: usize) -> (i64, i64);
 
     // Mechanics
     /// Calculates the kinetic energy of a rotating rigid body.
     fn rigid_body_rotation(&self, inertia: f64, angular_velocity: f64) -> f64;
     /// Converts Euler angles (phi, theta, psi) to a 3x3 rotation matrix.
     fn euler_angles_to_matrix(&self, angles: (f64, f64, f64)) -> [[f64; 3]; 3];
+    /// Evaluates Euler's formula `e^{iθ} = cos θ + i sin θ`, the unit complex
+    /// number at angle `theta` (in radians), i.e. a phase rotation by `theta`.
+    fn euler_formula(&self, theta: f64) -> Complex<f64>;
 
     // Topology
     /// Calculates the Euler class of a vector bundle, related to the genus of a surface.
@@ -70,6 +82,34 @@ impl Euler for Eulerian {
         // Placeholder: identity matrix
         [[1.0, 0.0, 0.0], [0.0, 1.0, 0.0], [0.0, 0.0, 1.0]]
     }
+    fn euler_formula(&self, theta: f64) -> Complex<f64> {
+        let (sin, cos) = theta.sin_cos();
+        Complex::new(cos, sin)
+    }
+    fn e_continued_fraction(&self, terms: usize) -> Vec<i64> {
+        (0..terms as i64).map(|k| match k {
+            0 => 2,
+            k if k % 3 == 2 => 2 * (k + 1) / 3,
+            _ => 1,
+        }).collect()
+    }
+    fn approximate_e(&self, terms: usize) -> (i64, i64) {
+        // Standard recurrence h_n = a_n·h_(n-1) + h_(n-2), likewise for k_n
+        let (mut h, mut h_prev) = (1i64, 0i64);
+        let (mut k, mut k_prev) = (0i64, 1i64);
+        for a in self.e_continued_fraction(terms.max(1)) {
+            let next_h = a.checked_mul(h).and_then(|x| x.checked_add(h_prev));
+            let next_k = a.checked_mul(k).and_then(|x| x.checked_add(k_prev));
+            match (next_h, next_k) {
+                (Some(nh), Some(nk)) => {
+                    (h_prev, h) = (h, nh);
+                    (k_prev, k) = (k, nk);
+                }
+                _ => break,
+            }
+        }
+        (h, k)
+    }
     fn gcd(&self, mut a: u64, mut b: u64) -> u64 {
         while b != 0 {
             let temp = b;
@@ -82,4 +122,52 @@ impl Euler for Eulerian {
     fn euler_class(&self, genus: usize) -> isize {
         2 - 2 * genus as isize
     }
-}
\ No newline at end of file
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_e_continued_fraction_terms() {
+        let euler = Eulerian;
+        assert_eq!(euler.e_continued_fraction(12), vec![2, 1, 2, 1, 1, 4, 1, 1, 6, 1, 1, 8]);
+        assert!(euler.e_continued_fraction(0).is_empty());
+    }
+
+    #[test]
+    fn test_approximate_e_convergents() {
+        let euler = Eulerian;
+        assert_eq!(euler.approximate_e(1), (2, 1));
+        assert_eq!(euler.approximate_e(3), (8, 3));
+        assert_eq!(euler.approximate_e(5), (19, 7));
+
+        let errors: Vec<f64> = (1..=18).map(|terms| {
+            let (p, q) = euler.approximate_e(terms);
+            (p as f64 / q as f64 - std::f64::consts::E).abs()
+        }).collect();
+        assert!(errors.windows(2).all(|w| w[1] <= w[0]));
+        assert!(errors[errors.len() - 1] < 1e-13);
+    }
+
+    #[test]
+    fn test_euler_identity() {
+        let euler = Eulerian;
+        let minus_one = euler.euler_formula(std::f64::consts::PI);
+        assert!((minus_one.real + 1.0).abs() < 1e-12);
+        assert!(minus_one.imag.abs() < 1e-12);
+
+        let i = euler.euler_formula(std::f64::consts::FRAC_PI_2);
+        assert!(i.real.abs() < 1e-12 && (i.imag - 1.0).abs() < 1e-12);
+        let z = euler.euler_formula(0.7);
+        assert!((z.real.hypot(z.imag) - 1.0).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_approximate_e_stops_before_overflow() {
+        let euler = Eulerian;
+        let (p, q) = euler.approximate_e(200);
+        assert!(q > 0);
+        assert!((p as f64 / q as f64 - std::f64::consts::E).abs() < 1e-15);
+    }
+}
@@ -227,6 +227,17 @@ impl FunctionRegistry {
         1.0 / (1.0 + diff)
     }
 
+    /// Calculates the phase resonance between two function numbers: the overlap
+    /// (Jaccard index) of their distinct prime factors. Numbers built from the same
+    /// primes, like 6, 12 and 18, resonate fully; coprime numbers not at all.
+    /// A number always resonates fully with itself.
+    pub fn phase_resonance(&self, num1: u64, num2: u64) -> f64 {
+        if num1 == num2 {
+            return 1.0;
+        }
+        factor_overlap(&distinct_prime_factors(num1), &distinct_prime_factors(num2))
+    }
+
     /// Finds functions in the registry that resonate with a target number.
     pub fn find_resonant_functions(&self, target: u64, threshold: f64) -> Vec<u64> {
         self.functions.keys()
@@ -324,6 +335,42 @@ impl FunctionNumberLanguage {
         Ok(linked)
     }
 
+    /// Groups the defined function numbers into clusters of resonant kin.
+    ///
+    /// Two functions are linked when their `phase_resonance` exceeds `threshold`,
+    /// and each cluster is a connected component of those links, so kinship is
+    /// transitive. Clusters are sorted, and ordered by their smallest number.
+    pub fn cluster_by_resonance(&self, threshold: f64) -> Vec<Vec<u64>> {
+        let mut numbers = self.registry.get_all_function_numbers();
+        numbers.sort_unstable();
+        // Factor each number once up front; the walk below compares every pair.
+        let factors: Vec<Vec<u64>> = numbers.iter().map(|&n| distinct_prime_factors(n)).collect();
+
+        let mut clusters: Vec<Vec<u64>> = Vec::new();
+        let mut assigned = vec![false; numbers.len()];
+        for start in 0..numbers.len() {
+            if assigned[start] {
+                continue;
+            }
+            assigned[start] = true;
+            let mut cluster = vec![numbers[start]];
+            let mut frontier = vec![start];
+            while let Some(index) = frontier.pop() {
+                for (other, seen) in assigned.iter_mut().enumerate() {
+                    // Numbers are distinct, so the self-resonance shortcut never applies here.
+                    if !*seen && factor_overlap(&factors[index], &factors[other]) > threshold {
+                        *seen = true;
+                        cluster.push(numbers[other]);
+                        frontier.push(other);
+                    }
+                }
+            }
+            cluster.sort_unstable();
+            clusters.push(cluster);
+        }
+        clusters
+    }
+
     /// Analyzes the mathematical structure of the function numbers in the registry.
     pub fn analyze_mathematical_structure(&self) -> MathematicalAnalysis {
         let numbers = self.registry.get_all_function_numbers();
@@ -363,16 +410,92 @@ pub struct MathematicalAnalysis {
 }
 
 // Helper functions
+const SMALL_PRIMES: [u64; 12] = [2, 3, 5, 7, 11, 13, 17, 19, 23, 29, 31, 37];
+
+fn mul_mod(a: u64, b: u64, m: u64) -> u64 {
+    ((a as u128 * b as u128) % m as u128) as u64
+}
+
+fn pow_mod(mut base: u64, mut exp: u64, m: u64) -> u64 {
+    let mut result = 1 % m;
+    base %= m;
+    while exp > 0 {
+        if exp & 1 == 1 { result = mul_mod(result, base, m); }
+        base = mul_mod(base, base, m);
+        exp >>= 1;
+    }
+    result
+}
+
+/// Miller–Rabin; the first twelve primes as witnesses make it exact for every `u64`.
 fn is_prime(n: u64) -> bool {
     if n < 2 { return false; }
-    if n == 2 { return true; }
-    if n % 2 == 0 { return false; }
-    
-    let sqrt_n = (n as f64).sqrt() as u64;
-    for i in (3..=sqrt_n).step_by(2) {
-        if n % i == 0 { return false; }
+    for p in SMALL_PRIMES {
+        if n.is_multiple_of(p) { return n == p; }
+    }
+    let d = (n - 1) >> (n - 1).trailing_zeros();
+    SMALL_PRIMES.iter().all(|&a| {
+        let mut x = pow_mod(a, d, n);
+        if x == 1 || x == n - 1 { return true; }
+        let mut e = d;
+        while e < n - 1 {
+            x = mul_mod(x, x, n);
+            e <<= 1;
+            if x == n - 1 { return true; }
+        }
+        false
+    })
+}
+
+/// Pollard's rho: finds a nontrivial divisor of an odd composite `n`.
+fn pollard_rho(n: u64) -> u64 {
+    let gcd = |mut a: u64, mut b: u64| {
+        while b != 0 { (a, b) = (b, a % b); }
+        a
+    };
+    for c in 1.. {
+        let step = |x: u64| (mul_mod(x, x, n) + c) % n;
+        let (mut x, mut y, mut d) = (2, 2, 1);
+        while d == 1 {
+            x = step(x);
+            y = step(step(y));
+            d = gcd(x.abs_diff(y), n);
+        }
+        if d != n { return d; }
     }
-    true
+    unreachable!("every odd composite has a rho cycle for some constant")
+}
+
+/// The distinct prime factors of `n`, ascending. Small primes are divided out
+/// directly and whatever remains is split with Pollard's rho, so even 64-bit
+/// semiprimes factor quickly.
+fn distinct_prime_factors(mut n: u64) -> Vec<u64> {
+    let mut factors = Vec::new();
+    for p in SMALL_PRIMES {
+        if n.is_multiple_of(p) {
+            factors.push(p);
+            while n.is_multiple_of(p) { n /= p; }
+        }
+    }
+    let mut pending = if n > 1 { vec![n] } else { Vec::new() };
+    while let Some(m) = pending.pop() {
+        if is_prime(m) {
+            factors.push(m);
+        } else {
+            let d = pollard_rho(m);
+            pending.extend([d, m / d]);
+        }
+    }
+    factors.sort_unstable();
+    factors.dedup();
+    factors
+}
+
+/// Jaccard index of two sets of distinct prime factors.
+fn factor_overlap(factors1: &[u64], factors2: &[u64]) -> f64 {
+    let shared = factors1.iter().filter(|p| factors2.contains(p)).count();
+    let union = factors1.len() + factors2.len() - shared;
+    if union == 0 { 0.0 } else { shared as f64 / union as f64 }
 }
 
 fn is_fibonacci(n: u64) -> bool {
@@ -403,4 +526,45 @@ fn calculate_average_resonance(numbers: &[u64]) -> f64 {
     }
     
     if count > 0 { total_resonance / count as f64 } else { 0.0 }
-}
\ No newline at end of file
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_cluster_by_resonance() {
+        let mut language = FunctionNumberLanguage::new();
+        for (number, name) in [(6, "hexad"), (12, "dozen"), (18, "octodecad"), (7, "heptad")] {
+            language.define_function(number, name, "clustering test").unwrap();
+        }
+
+        let registry = language.get_registry();
+        assert_eq!(registry.phase_resonance(6, 12), 1.0);
+        assert_eq!(registry.phase_resonance(6, 7), 0.0);
+        assert_eq!(registry.phase_resonance(10, 6), 1.0 / 3.0);
+
+        assert_eq!(language.cluster_by_resonance(0.9), vec![vec![6, 12, 18], vec![7]]);
+        // Nothing exceeds full resonance, so every function stands alone
+        assert_eq!(language.cluster_by_resonance(1.0), vec![vec![6], vec![7], vec![12], vec![18]]);
+    }
+
+    #[test]
+    fn test_factorization_handles_large_numbers() {
+        // Two primes near 2^32: trial division would need billions of steps
+        let (p, q) = (4_294_967_291u64, 4_294_967_279u64);
+        assert!(is_prime(p) && is_prime(q));
+        assert!(!is_prime(p * q));
+        assert!(is_prime(u64::MAX - 58)); // the largest 64-bit prime
+        assert_eq!(distinct_prime_factors(p * q), vec![q, p]);
+        assert_eq!(distinct_prime_factors(2 * 2 * 3 * 1_000_000_007), vec![2, 3, 1_000_000_007]);
+        assert_eq!(distinct_prime_factors(1), Vec::<u64>::new());
+
+        let mut language = FunctionNumberLanguage::new();
+        for (number, name) in [(p * q, "semiprime"), (p * 2, "doubled"), (q * 3, "tripled")] {
+            language.define_function(number, name, "factorization test").unwrap();
+        }
+        assert_eq!(language.get_registry().phase_resonance(p * q, p * 2), 1.0 / 3.0);
+        assert_eq!(language.cluster_by_resonance(0.3), vec![vec![p * 2, q * 3, p * q]]);
+    }
+}
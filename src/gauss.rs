@@ -11,6 +11,8 @@
 //!   curvature, and basic statistical measures.
 //! - **`Gaussian` Struct**: A concrete implementation of the `Gauss` trait.
 
+use crate::kernel::splitmix64;
+
 /// A trait for calculations related to analysis, probability, and geometry,
 /// inspired by the work of Carl Friedrich Gauss.
 pub trait Gauss {
@@ -37,8 +39,23 @@ pub trait Gauss {
     fn variance(&self, data: &[f64]) -> f64;
     /// Calculates the standard deviation of a slice of data.
     fn stddev(&self, data: &[f64]) -> f64;
+    /// Draws `n` samples from the normal distribution N(`mu`, `sigma`²) by the
+    /// Box–Muller transform. Uniforms come from a SplitMix64 stream started at
+    /// `seed`, so the same seed always reproduces the same samples.
+    fn sample_normal(&self, mu: f64, sigma: f64, n: usize, seed: u64) -> Vec<f64>;
+
+    // Regression
+    /// Fits a polynomial of the given degree to `(xs, ys)` by least squares, solving
+    /// the normal equations with Gaussian elimination. Coefficients are returned in
+    /// ascending order, so `c[0] + c[1]·x + c[2]·x²` for degree 2. Returns an error
+    /// when the inputs differ in length, there are fewer points than coefficients,
+    /// or the system is too ill-conditioned to solve (e.g. repeated `xs`).
+    fn polyfit(&self, xs: &[f64], ys: &[f64], degree: usize) -> Result<Vec<f64>, String>;
 }
 
+/// Relative pivot size below which the normal equations are treated as singular.
+const POLYFIT_PIVOT_TOLERANCE: f64 = 1e-12;
+
 /// A concrete implementation of the `Gauss` trait.
 pub struct Gaussian;
 
@@ -77,4 +94,118 @@ impl Gauss for Gaussian {
     fn stddev(&self, data: &[f64]) -> f64 {
         self.variance(data).sqrt()
     }
+    fn sample_normal(&self, mu: f64, sigma: f64, n: usize, seed: u64) -> Vec<f64> {
+        let mut state = seed;
+        let mut samples = Vec::with_capacity(n + 1);
+        while samples.len() < n {
+            // u1 lies in (0, 1] so its logarithm stays finite
+            let u1 = ((splitmix64(&mut state) >> 11) + 1) as f64 / (1u64 << 53) as f64;
+            let u2 = (splitmix64(&mut state) >> 11) as f64 / (1u64 << 53) as f64;
+            let radius = (-2.0 * u1.ln()).sqrt();
+            let angle = 2.0 * std::f64::consts::PI * u2;
+            samples.push(mu + sigma * radius * angle.cos());
+            samples.push(mu + sigma * radius * angle.sin());
+        }
+        samples.truncate(n);
+        samples
+    }
+    fn polyfit(&self, xs: &[f64], ys: &[f64], degree: usize) -> Result<Vec<f64>, String> {
+        if xs.len() != ys.len() {
+            return Err(format!("polyfit: {} x values but {} y values", xs.len(), ys.len()));
+        }
+        let size = degree + 1;
+        if xs.len() < size {
+            return Err(format!("polyfit: degree {} needs at least {} points, got {}", degree, size, xs.len()));
+        }
+
+        // Normal equations (VᵀV)c = Vᵀy as an augmented matrix
+        let mut system = vec![vec![0.0; size + 1]; size];
+        for (&x, &y) in xs.iter().zip(ys) {
+            let powers: Vec<f64> = (0..size).map(|k| x.powi(k as i32)).collect();
+            for row in 0..size {
+                for col in 0..size {
+                    system[row][col] += powers[row] * powers[col];
+                }
+                system[row][size] += powers[row] * y;
+            }
+        }
+
+        let scale = (0..size).map(|k| system[k][k].abs()).fold(0.0, f64::max);
+        for pivot in 0..size {
+            let best = (pivot..size)
+                .max_by(|&a, &b| system[a][pivot].abs().total_cmp(&system[b][pivot].abs()))
+                .unwrap_or(pivot);
+            if system[best][pivot].abs() <= POLYFIT_PIVOT_TOLERANCE * scale {
+                return Err(format!("polyfit: normal equations are ill-conditioned for degree {}", degree));
+            }
+            system.swap(pivot, best);
+            let (upper, lower) = system.split_at_mut(pivot + 1);
+            let pivot_row = &upper[pivot];
+            for row in lower.iter_mut() {
+                let factor = row[pivot] / pivot_row[pivot];
+                for (cell, &p) in row[pivot..].iter_mut().zip(&pivot_row[pivot..]) {
+                    *cell -= factor * p;
+                }
+            }
+        }
+
+        let mut coefficients = vec![0.0; size];
+        for row in (0..size).rev() {
+            let known: f64 = (row + 1..size).map(|col| system[row][col] * coefficients[col]).sum();
+            coefficients[row] = (system[row][size] - known) / system[row][row];
+        }
+        Ok(coefficients)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn assert_close(actual: &[f64], expected: &[f64]) {
+        assert_eq!(actual.len(), expected.len());
+        for (a, e) in actual.iter().zip(expected) {
+            assert!((a - e).abs() < 1e-8, "{:?} != {:?}", actual, expected);
+        }
+    }
+
+    #[test]
+    fn test_polyfit_recovers_quadratic() {
+        let gauss = Gaussian;
+        let xs: Vec<f64> = (0..10).map(|x| x as f64 - 3.0).collect();
+        let ys: Vec<f64> = xs.iter().map(|x| 3.0 - 2.0 * x + 0.5 * x * x).collect();
+
+        assert_close(&gauss.polyfit(&xs, &ys, 2).unwrap(), &[3.0, -2.0, 0.5]);
+    }
+
+    #[test]
+    fn test_polyfit_triangular_numbers() {
+        let gauss = Gaussian;
+        let xs: Vec<f64> = (0..12).map(|n| n as f64).collect();
+        let ys: Vec<f64> = xs.iter().map(|n| n * (n + 1.0) / 2.0).collect();
+
+        assert_close(&gauss.polyfit(&xs, &ys, 2).unwrap(), &[0.0, 0.5, 0.5]);
+    }
+
+    #[test]
+    fn test_polyfit_rejects_degenerate_input() {
+        let gauss = Gaussian;
+
+        assert!(gauss.polyfit(&[2.0; 5], &[1.0, 2.0, 3.0, 4.0, 5.0], 2).is_err());
+        assert!(gauss.polyfit(&[0.0, 1.0], &[0.0, 1.0], 2).is_err());
+        assert!(gauss.polyfit(&[0.0, 1.0, 2.0], &[0.0, 1.0], 1).is_err());
+    }
+
+    #[test]
+    fn test_sample_normal_moments_and_reproducibility() {
+        let gauss = Gaussian;
+        let samples = gauss.sample_normal(3.0, 2.0, 20_000, 42);
+
+        assert_eq!(samples.len(), 20_000);
+        assert!((gauss.mean(&samples) - 3.0).abs() < 0.05);
+        assert!((gauss.variance(&samples) - 4.0).abs() < 0.15);
+        assert_eq!(gauss.sample_normal(3.0, 2.0, 5, 42), samples[..5]);
+        assert_ne!(gauss.sample_normal(3.0, 2.0, 5, 43), samples[..5]);
+        assert_eq!(gauss.sample_normal(0.0, 1.0, 3, 7).len(), 3);
+    }
 }
\ No newline at end of file
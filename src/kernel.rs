@@ -4,9 +4,13 @@
 ///
 /// The kernel maintains a 42-step cycle that advances with each
 /// operation, providing a sense of system evolution and renewal.
+/// Alongside the cycle it keeps a running digest of all content recorded
+/// through it, so two kernels can be compared by what they have seen.
 pub struct Kernel {
     /// Current step in the 42-step cycle (0-41)
     pub step: u64,
+    /// Running digest of all content recorded by the kernel (0 when empty)
+    pub content_digest: u64,
 }
 
 impl Kernel {
@@ -14,6 +18,7 @@ impl Kernel {
     pub fn new() -> Self {
         Self {
             step: 0,
+            content_digest: 0,
         }
     }
 
@@ -31,6 +36,42 @@ impl Kernel {
     pub fn set_step(&mut self, step: u64) {
         self.step = step % 42;
     }
+
+    /// Folds content into the running content digest and advances the cycle
+    ///
+    /// The digest is an FNV-1a fold over the previous digest and the new
+    /// content, so it depends on both what was recorded and in which order.
+    pub fn record_content(&mut self, content: &[u8]) {
+        const FNV_OFFSET: u64 = 0xcbf29ce484222325;
+        const FNV_PRIME: u64 = 0x100000001b3;
+
+        let seed = FNV_OFFSET ^ self.content_digest;
+        self.content_digest = content.iter().fold(seed, |acc, &byte| {
+            (acc ^ byte as u64).wrapping_mul(FNV_PRIME)
+        });
+        self.advance_cycle();
+    }
+
+    /// Returns a deterministic pseudo-random stream seeded by the kernel state
+    ///
+    /// The seed mixes the cycle step and content digest, so kernels in the same
+    /// state yield identical streams while any advance or recorded content
+    /// changes it. Values come from SplitMix64; they are reproducible, not secure.
+    pub fn rng_stream(&self) -> impl Iterator<Item = u64> {
+        let mut state = self.content_digest ^ self.step.wrapping_add(1).wrapping_mul(GOLDEN_GAMMA);
+        std::iter::repeat_with(move || splitmix64(&mut state))
+    }
+}
+
+const GOLDEN_GAMMA: u64 = 0x9e3779b97f4a7c15;
+
+/// Advances a SplitMix64 state and returns the next output.
+pub(crate) fn splitmix64(state: &mut u64) -> u64 {
+    *state = state.wrapping_add(GOLDEN_GAMMA);
+    let mut z = *state;
+    z = (z ^ (z >> 30)).wrapping_mul(0xbf58476d1ce4e5b9);
+    z = (z ^ (z >> 27)).wrapping_mul(0x94d049bb133111eb);
+    z ^ (z >> 31)
 }
 
 impl Default for Kernel {
@@ -86,4 +127,38 @@ mod tests {
         kernel.set_step(50);
         assert_eq!(kernel.cycle_step(), 8); // 50 % 42 = 8
     }
+
+    #[test]
+    fn test_record_content() {
+        let mut kernel = Kernel::new();
+        assert_eq!(kernel.content_digest, 0);
+
+        kernel.record_content(b"first");
+        assert_eq!(kernel.cycle_step(), 1);
+        assert_ne!(kernel.content_digest, 0);
+
+        // Order of recorded content matters
+        let mut forward = Kernel::new();
+        forward.record_content(b"a");
+        forward.record_content(b"b");
+        let mut backward = Kernel::new();
+        backward.record_content(b"b");
+        backward.record_content(b"a");
+        assert_ne!(forward.content_digest, backward.content_digest);
+    }
+
+    #[test]
+    fn test_rng_stream_reproducible() {
+        let mut a = Kernel::new();
+        let mut b = Kernel::new();
+        a.record_content(b"seed");
+        b.record_content(b"seed");
+
+        let first: Vec<u64> = a.rng_stream().take(8).collect();
+        assert_eq!(first, b.rng_stream().take(8).collect::<Vec<u64>>());
+        assert_eq!(first, a.rng_stream().take(8).collect::<Vec<u64>>());
+
+        a.advance_cycle();
+        assert_ne!(first, a.rng_stream().take(8).collect::<Vec<u64>>());
+    }
 } 
\ No newline at end of file
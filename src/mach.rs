@@ -32,6 +32,16 @@ pub trait Mach {
     fn time_dilation(&self, proper_time: f64, velocity: f64, c: f64) -> f64;
     /// Calculates the length contraction experienced by a moving object.
     fn length_contraction(&self, proper_length: f64, velocity: f64, c: f64) -> f64;
+    /// Calculates a toy Lense–Thirring precession rate `Ω = 2J / r³` (geometric units,
+    /// G = c = 1) for a test particle at `radius` from a body with `angular_momentum`.
+    /// The radius is clamped to at least 1, so the rate stays finite at `radius == 0`
+    /// and is constant inside the unit sphere instead of diverging.
+    fn frame_drag(&self, angular_momentum: f64, radius: f64) -> f64;
+    /// Calculates the invariant (rest) mass `sqrt(E²/c⁴ − p²/c²)` of a system with
+    /// total `energy` and `momentum`. Spacelike inputs (`|p|c > E`) have no real
+    /// mass and return `NaN`; a mass-shell deficit within rounding error of zero,
+    /// as for a photon with `E = pc`, is treated as exactly massless.
+    fn invariant_mass(&self, energy: f64, momentum: f64, c: f64) -> f64;
 
     // Cosmology
     /// Calculates the effect of the universe's mass distribution on local physics.
@@ -73,6 +83,19 @@ impl Mach for Machian {
     fn length_contraction(&self, proper_length: f64, velocity: f64, c: f64) -> f64 {
         proper_length / self.lorentz_factor(velocity, c)
     }
+    fn frame_drag(&self, angular_momentum: f64, radius: f64) -> f64 {
+        2.0 * angular_momentum / radius.abs().max(1.0).powi(3)
+    }
+    fn invariant_mass(&self, energy: f64, momentum: f64, c: f64) -> f64 {
+        let momentum_energy = momentum.abs() * c;
+        // (E − pc)(E + pc) avoids cancelling two large squares
+        let mass_squared = (energy - momentum_energy) * (energy + momentum_energy) / c.powi(4);
+        let tolerance = 4.0 * f64::EPSILON * energy * energy / c.powi(4);
+        if mass_squared < -tolerance {
+            return f64::NAN;
+        }
+        mass_squared.max(0.0).sqrt()
+    }
     fn mach_principle_effect(&self, local_mass: f64, universe_mass: f64, distance: f64) -> f64 {
         // Placeholder: proportional to inertial mass
         self.inertial_mass(local_mass, universe_mass, distance)
@@ -81,4 +104,47 @@ impl Mach for Machian {
         // Placeholder: identity tensor
         [[1.0, 0.0, 0.0], [0.0, 1.0, 0.0], [0.0, 0.0, 1.0]]
     }
-}
\ No newline at end of file
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_frame_drag_grows_with_angular_momentum() {
+        let mach = Machian;
+        assert!(mach.frame_drag(2.0, 3.0) > mach.frame_drag(1.0, 3.0));
+    }
+
+    #[test]
+    fn test_frame_drag_falls_off_with_radius_cubed() {
+        let mach = Machian;
+        let near = mach.frame_drag(5.0, 2.0);
+        let far = mach.frame_drag(5.0, 4.0);
+        assert!((near / far - 8.0).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_invariant_mass() {
+        let mach = Machian;
+        let c = 299_792_458.0;
+
+        // A photon carries momentum but no rest mass
+        assert_eq!(mach.invariant_mass(3.0e-19, 3.0e-19 / c, c), 0.0);
+        // At rest all the energy is mass: m = E/c²
+        let energy = 8.987_551_787e16;
+        assert!((mach.invariant_mass(energy, 0.0, c) - energy / (c * c)).abs() < 1e-12);
+        // A moving particle keeps its rest mass: E = γmc², p = γmv
+        let (mass, velocity) = (2.0, 0.6 * c);
+        let gamma = mach.lorentz_factor(velocity, c);
+        assert!((mach.invariant_mass(gamma * mass * c * c, gamma * mass * velocity, c) - mass).abs() < 1e-9);
+        assert!(mach.invariant_mass(1.0, 1.0, c).is_nan());
+    }
+
+    #[test]
+    fn test_frame_drag_regularized_at_origin() {
+        let mach = Machian;
+        assert!(mach.frame_drag(5.0, 0.0).is_finite());
+        assert_eq!(mach.frame_drag(5.0, 0.0), mach.frame_drag(5.0, 1.0));
+    }
+}
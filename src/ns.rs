@@ -31,6 +31,14 @@ pub trait Ns {
     fn divergence(&self, velocity_field: &[[f64; 2]]) -> f64;
     /// Calculates the curl (vorticity) of the velocity field.
     fn curl(&self, velocity_field: &[[f64; 2]]) -> f64;
+    /// Calculates the divergence at every cell of a periodic `N`×`N` grid, where
+    /// `field[i][j]` holds the `[x, y]` velocity and the grid spacing is 1.
+    /// Uses forward differences, the staggered counterpart of `project_incompressible`.
+    fn divergence_field<const N: usize>(&self, field: &[[[f64; 2]; N]; N]) -> [[f64; N]; N] where Self: Sized;
+    /// Enforces ∇·u = 0 by pressure projection: solves ∇²p = ∇·u with `iterations`
+    /// Jacobi sweeps, then subtracts ∇p from the velocity. More iterations remove
+    /// more divergence; smooth, large-scale divergence converges slowest.
+    fn project_incompressible<const N: usize>(&self, field: &mut [[[f64; 2]; N]; N], iterations: usize) where Self: Sized;
 
     // Boundary and initial conditions
     /// Sets the boundary conditions for the simulation domain.
@@ -73,8 +81,84 @@ impl Ns for NavierStokesSolver {
     }
     fn divergence(&self, _velocity_field: &[[f64; 2]]) -> f64 { 0.0 }
     fn curl(&self, _velocity_field: &[[f64; 2]]) -> f64 { 0.0 }
+    fn divergence_field<const N: usize>(&self, field: &[[[f64; 2]; N]; N]) -> [[f64; N]; N] {
+        let mut divergence = [[0.0; N]; N];
+        for i in 0..N {
+            for j in 0..N {
+                let du = field[(i + 1) % N][j][0] - field[i][j][0];
+                let dv = field[i][(j + 1) % N][1] - field[i][j][1];
+                divergence[i][j] = du + dv;
+            }
+        }
+        divergence
+    }
+    fn project_incompressible<const N: usize>(&self, field: &mut [[[f64; 2]; N]; N], iterations: usize) {
+        if N == 0 {
+            return;
+        }
+        let divergence = self.divergence_field(field);
+        let mut pressure = [[0.0; N]; N];
+        for _ in 0..iterations {
+            let previous = pressure;
+            for i in 0..N {
+                for j in 0..N {
+                    let neighbors = previous[(i + 1) % N][j] + previous[(i + N - 1) % N][j]
+                        + previous[i][(j + 1) % N] + previous[i][(j + N - 1) % N];
+                    pressure[i][j] = (neighbors - divergence[i][j]) / 4.0;
+                }
+            }
+        }
+        // Backward-difference gradient pairs with the forward-difference divergence
+        for i in 0..N {
+            for j in 0..N {
+                field[i][j][0] -= pressure[i][j] - pressure[(i + N - 1) % N][j];
+                field[i][j][1] -= pressure[i][j] - pressure[i][(j + N - 1) % N];
+            }
+        }
+    }
     fn set_boundary_conditions(&self, _field: &mut [[f64; 2]]) {}
     fn set_initial_conditions(&self, _field: &mut [[f64; 2]]) {}
     fn visualize_velocity(&self, _field: &[[f64; 2]]) -> String { "velocity field".to_string() }
     fn visualize_pressure(&self, _field: &[f64]) -> String { "pressure field".to_string() }
-}
\ No newline at end of file
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const N: usize = 16;
+
+    fn max_divergence(solver: &NavierStokesSolver, field: &[[[f64; 2]; N]; N]) -> f64 {
+        solver.divergence_field(field).iter().flatten().fold(0.0, |max: f64, d| max.max(d.abs()))
+    }
+
+    #[test]
+    fn test_project_incompressible_reduces_divergence() {
+        let solver = NavierStokesSolver::default();
+        let mut field = [[[0.0; 2]; N]; N];
+        for (i, row) in field.iter_mut().enumerate() {
+            for (j, cell) in row.iter_mut().enumerate() {
+                let x = 2.0 * std::f64::consts::PI * i as f64 / N as f64;
+                let y = 2.0 * std::f64::consts::PI * j as f64 / N as f64;
+                *cell = [x.sin() + 0.5 * (2.0 * y).cos(), (y + x).sin()];
+            }
+        }
+
+        let before = max_divergence(&solver, &field);
+        solver.project_incompressible(&mut field, 60);
+        let after = max_divergence(&solver, &field);
+
+        assert!(before > 0.1);
+        assert!(after < before / 10.0, "divergence {} -> {}", before, after);
+    }
+
+    #[test]
+    fn test_project_incompressible_keeps_uniform_flow() {
+        let solver = NavierStokesSolver::default();
+        let mut field = [[[1.0, -2.0]; N]; N];
+
+        solver.project_incompressible(&mut field, 10);
+
+        assert!(field.iter().flatten().all(|cell| *cell == [1.0, -2.0]));
+    }
+}
@@ -27,12 +27,12 @@ use crate::clifford::{Clifford, CliffordDyn};
 use crate::bach::{Bach, Note, Voice};
 use crate::escher::Escher;
 use crate::ns::Ns;
-use crate::euler::Euler;
+use crate::euler::{Euler, Eulerian};
 use crate::gauss::Gauss;
 use crate::mach::Mach;
 use crate::penrose::Penrose;
 use crate::oeis::OEIS;
-use crate::vectos::{Vectos, MathematicalUniverse, StageVibes, LatticeState};
+use crate::vectos::{Vectos, MathematicalUniverse, StageVibes, LatticeState, LatticeNode};
 use crate::phase2::Phase2;
 use std::collections::HashMap;
 
@@ -83,6 +83,13 @@ pub trait EmbeddedNumber {
     fn evolve_self(&self, iterations: usize) -> Vec<Self> where Self: Sized;
     /// Creates a new `HarmonicNumber` representing the harmonic relationship with another number.
     fn harmonize_with(&self, other: &Self) -> HarmonicNumber where Self: Sized;
+    /// Checks whether the two values differ by an integer power of the golden ratio,
+    /// within a relative tolerance `tol`.
+    ///
+    /// `evolve_self` scales by φ each step, so a number and its evolved forms are
+    /// φ-related; it rounds φ to 1.618, which a `tol` around 1e-4 per step absorbs.
+    /// Zero values and values of opposite sign are never related.
+    fn is_phi_related(&self, other: &Self, tol: f64) -> bool where Self: Sized;
     /// Creates a `ResonantNumber` by resonating with a given frequency.
     fn resonate_with(&self, frequency: f64) -> ResonantNumber;
     
@@ -144,6 +151,44 @@ pub struct NumberReflection {
     pub self_modification_potential: f64,
 }
 
+impl NumberReflection {
+    /// Returns the reflection's coordinates across every reflection dimension,
+    /// excluding the raw number value.
+    pub fn dimensions(&self) -> [f64; 15] {
+        [
+            self.godel_significance,
+            self.bott_periodicity,
+            self.clifford_richness,
+            self.bach_harmony,
+            self.escher_beauty,
+            self.ns_physics,
+            self.euler_elegance,
+            self.gauss_analysis,
+            self.mach_relativity,
+            self.penrose_geometry,
+            self.oeis_sequence,
+            self.vectos_integration,
+            self.phase2_consciousness,
+            self.overall_coherence,
+            self.self_modification_potential,
+        ]
+    }
+
+    /// Averages the thirteen domain scores into a single coherence value.
+    pub fn coherence(&self) -> f64 {
+        self.dimensions()[..13].iter().sum::<f64>() / 13.0
+    }
+
+    /// Computes the Euclidean distance to another reflection across the reflection dimensions.
+    pub fn distance(&self, other: &NumberReflection) -> f64 {
+        self.dimensions().iter()
+            .zip(other.dimensions().iter())
+            .map(|(a, b)| (a - b).powi(2))
+            .sum::<f64>()
+            .sqrt()
+    }
+}
+
 /// Represents a new number created from the harmonic relationship between two other numbers.
 #[derive(Debug, Clone)]
 pub struct HarmonicNumber {
@@ -260,7 +305,7 @@ impl EmbeddedNumber for SelfAwareNumber {
     }
     
     fn embedded_clifford(&self) -> Box<dyn CliffordDyn<Scalar = f64>> {
-        Box::new(crate::clifford::CliffordMultivector::new(self.clifford_vector.len()))
+        Box::new(crate::clifford::CliffordMultivector::from_blades(&self.clifford_vector))
     }
     
     fn embedded_bach(&self) -> Box<dyn Bach> {
@@ -316,9 +361,10 @@ impl EmbeddedNumber for SelfAwareNumber {
         let penrose = self.embedded_penrose();
         let oeis = self.embedded_oeis();
         let vectos = self.embedded_vectos();
-        let phase2 = self.embedded_phase2();
+        // The shared engine keeps its reflection cache across calls
+        let phase2 = crate::phase2::Phase2Engine::shared();
         
-        NumberReflection {
+        let mut reflection = NumberReflection {
             number_value: self.value,
             godel_significance: self.godel_number as f64 / 100.0,
             bott_periodicity: bott.calculate_curvature(self.value, 1.0),
@@ -333,9 +379,11 @@ impl EmbeddedNumber for SelfAwareNumber {
             oeis_sequence: *oeis.fibonacci_sequence(10).last().unwrap_or(&0) as f64 / 100.0,
             vectos_integration: vectos.mathematical_resonance(&[self.value]),
             phase2_consciousness: phase2.reflect_on_system(&self.synthesize_mathematical_universe()).mathematical_beauty,
-            overall_coherence: self.calculate_coherence(),
+            overall_coherence: 0.0,
             self_modification_potential: self.consciousness_level,
-        }
+        };
+        reflection.overall_coherence = reflection.coherence();
+        reflection
     }
     
     fn evolve_self(&self, iterations: usize) -> Vec<Self> {
@@ -372,6 +420,17 @@ impl EmbeddedNumber for SelfAwareNumber {
         }
     }
     
+    fn is_phi_related(&self, other: &Self, tol: f64) -> bool {
+        let ratio = other.value / self.value;
+        if !ratio.is_finite() || ratio <= 0.0 {
+            return false;
+        }
+        
+        let phi = (1.0 + 5f64.sqrt()) / 2.0;
+        let power = (ratio.ln() / phi.ln()).round();
+        (ratio / phi.powf(power) - 1.0).abs() <= tol
+    }
+    
     fn resonate_with(&self, frequency: f64) -> ResonantNumber {
         let resonant_frequency = frequency;
         let amplitude = self.value / frequency;
@@ -458,14 +517,7 @@ impl EmbeddedNumber for SelfAwareNumber {
     
     // Helper method
     fn calculate_coherence(&self) -> f64 {
-        let reflection = self.reflect_on_self();
-        (reflection.godel_significance + reflection.bott_periodicity + 
-         reflection.clifford_richness + reflection.bach_harmony + 
-         reflection.escher_beauty + reflection.ns_physics + 
-         reflection.euler_elegance + reflection.gauss_analysis + 
-         reflection.mach_relativity + reflection.penrose_geometry + 
-         reflection.oeis_sequence + reflection.vectos_integration + 
-         reflection.phase2_consciousness) / 13.0
+        self.reflect_on_self().overall_coherence
     }
 }
 
@@ -516,6 +568,18 @@ impl NumberEmbeddingSystem {
         self.numbers.get(&(value as u64)).unwrap()
     }
     
+    /// Removes the number stored under a Gödel key and refreshes the global metrics.
+    pub fn remove_number(&mut self, key: u64) -> Option<SelfAwareNumber> {
+        let removed = self.numbers.remove(&key)?;
+        self.update_global_metrics();
+        Some(removed)
+    }
+    
+    /// Returns the smallest positive Gödel key not yet used by any number.
+    pub fn allocate_id(&self) -> u64 {
+        (1..).find(|key| !self.numbers.contains_key(key)).unwrap()
+    }
+    
     /// Evolves all numbers in the system for a given number of iterations.
     pub fn evolve_system(&mut self, iterations: usize) {
         self.evolution_generation += 1;
@@ -527,7 +591,18 @@ impl NumberEmbeddingSystem {
             }
         }
         
-        // Update global properties
+        self.update_global_metrics();
+    }
+    
+    /// Recomputes the global consciousness and coherence as averages over all numbers.
+    /// An empty system has zero for both.
+    fn update_global_metrics(&mut self) {
+        if self.numbers.is_empty() {
+            self.global_consciousness = 0.0;
+            self.mathematical_coherence = 0.0;
+            return;
+        }
+        
         self.global_consciousness = self.numbers.values()
             .map(|n| n.consciousness_level)
             .sum::<f64>() / self.numbers.len() as f64;
@@ -574,6 +649,54 @@ impl NumberEmbeddingSystem {
         harmonics
     }
     
+    /// Finds the number whose own reflection lies closest to `target`.
+    ///
+    /// Returns the Gödel key of the best match, preferring the smaller key on ties,
+    /// or `None` if the system holds no numbers.
+    pub fn closest_to_profile(&self, target: &NumberReflection) -> Option<u64> {
+        self.numbers.iter()
+            .map(|(&key, number)| (key, number.reflect_on_self().distance(target)))
+            .min_by(|(key_a, dist_a), (key_b, dist_b)| {
+                dist_a.total_cmp(dist_b).then(key_a.cmp(key_b))
+            })
+            .map(|(key, _)| key)
+    }
+    
+    /// Places every number of the system into one shared lattice.
+    ///
+    /// Nodes are ordered by Gödel key. Each node sits at
+    /// `(value, consciousness, value × consciousness)` and is connected to every
+    /// other node whose integer value shares a prime factor with its own.
+    pub fn build_shared_lattice(&self) -> LatticeState {
+        let euler = Eulerian;
+        let mut keys: Vec<&u64> = self.numbers.keys().collect();
+        keys.sort();
+        let numbers: Vec<&SelfAwareNumber> = keys.iter().map(|key| &self.numbers[*key]).collect();
+        let integers: Vec<u64> = numbers.iter().map(|n| n.value.abs().round() as u64).collect();
+
+        let nodes = numbers.iter().enumerate().map(|(i, number)| LatticeNode {
+            position: (
+                number.value,
+                number.consciousness_level,
+                number.value * number.consciousness_level,
+            ),
+            value: number.value,
+            connections: (0..integers.len())
+                .filter(|&j| j != i && euler.gcd(integers[i], integers[j]) > 1)
+                .collect(),
+            phase: number.value * std::f64::consts::PI,
+        }).collect();
+
+        LatticeState {
+            iteration: self.evolution_generation,
+            energy: numbers.iter().map(|n| n.value).sum(),
+            entropy: (numbers.len().max(1) as f64).ln(),
+            coherence: self.mathematical_coherence,
+            entanglement: self.global_consciousness,
+            nodes,
+        }
+    }
+    
     /// Synthesizes a complete mathematical universe from all numbers in the system.
     pub fn synthesize_universe(&self) -> MathematicalUniverse {
         let mut universe = MathematicalUniverse {
@@ -627,4 +750,106 @@ pub struct SystemAnalysis {
     pub evolution_generation: usize,
     /// An overall health score for the system.
     pub system_health: f64,
-}
\ No newline at end of file
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_phi_related_numbers() {
+        let number = SelfAwareNumber { value: 3.0, ..SelfAwareNumber::default() };
+        let evolved = number.evolve_self(1).remove(0);
+
+        assert!(number.is_phi_related(&evolved, 1e-4));
+        assert!(evolved.is_phi_related(&number, 1e-4));
+        assert!(number.is_phi_related(&number, 1e-12));
+
+        let unrelated = SelfAwareNumber { value: 4.5, ..SelfAwareNumber::default() };
+        assert!(!number.is_phi_related(&unrelated, 1e-4));
+        let zero = SelfAwareNumber { value: 0.0, ..SelfAwareNumber::default() };
+        assert!(!number.is_phi_related(&zero, 1e-4));
+    }
+
+    #[test]
+    fn test_reflect_on_self_reuses_shared_reflections() {
+        let number = SelfAwareNumber { value: 31.0, godel_number: 987_654_321, ..SelfAwareNumber::default() };
+        let engine = crate::phase2::Phase2Engine::shared();
+
+        let first = number.reflect_on_self();
+        let hits = engine.reflection_cache_hits();
+        let second = number.reflect_on_self();
+
+        assert!(engine.reflection_cache_hits() > hits);
+        assert_eq!(first.phase2_consciousness, second.phase2_consciousness);
+    }
+
+    #[test]
+    fn test_closest_to_profile_finds_exact_match() {
+        let system = NumberEmbeddingSystem::default();
+        let target = system.numbers[&13].reflect_on_self();
+
+        assert_eq!(system.closest_to_profile(&target), Some(13));
+    }
+
+    #[test]
+    fn test_shared_lattice_connects_prime_kin() {
+        let system = NumberEmbeddingSystem::default();
+        let lattice = system.build_shared_lattice();
+
+        assert_eq!(lattice.nodes.len(), 42);
+        // Node i holds the number i + 1
+        let connected = |a: usize, b: usize| lattice.nodes[a - 1].connections.contains(&(b - 1));
+        assert!(connected(6, 15));
+        assert!(connected(15, 6));
+        assert!(connected(7, 42));
+        assert!(!connected(8, 15));
+        assert!(!connected(12, 12));
+        assert!(lattice.nodes[0].connections.is_empty());
+        // A prime's kin are exactly its multiples
+        let kin: Vec<usize> = lattice.nodes[12].connections.iter().map(|i| i + 1).collect();
+        assert_eq!(kin, vec![26, 39]);
+    }
+
+    #[test]
+    fn test_remove_number_updates_global_metrics() {
+        let mut system = NumberEmbeddingSystem::default();
+        assert!(system.remove_number(99).is_none());
+        assert_eq!(system.global_consciousness, 0.5);
+
+        let removed = system.remove_number(42).unwrap();
+        assert_eq!(removed.godel_number, 42);
+        assert_eq!(system.numbers.len(), 41);
+
+        // Consciousness levels 0.1 + 0.02·i for i in 1..=41 average to 0.52
+        assert!((system.global_consciousness - 0.52).abs() < 1e-12);
+        let coherence = system.numbers.values().map(|n| n.calculate_coherence()).sum::<f64>() / 41.0;
+        assert!((system.mathematical_coherence - coherence).abs() < 1e-12);
+
+        for key in 1..42 {
+            system.remove_number(key);
+        }
+        assert_eq!(system.global_consciousness, 0.0);
+        assert_eq!(system.mathematical_coherence, 0.0);
+    }
+
+    #[test]
+    fn test_allocate_id_is_unused() {
+        let mut system = NumberEmbeddingSystem::default();
+        assert_eq!(system.allocate_id(), 43);
+
+        system.remove_number(7);
+        let id = system.allocate_id();
+        assert_eq!(id, 7);
+        assert!(!system.numbers.contains_key(&id));
+    }
+
+    #[test]
+    fn test_closest_to_profile_empty_system() {
+        let mut system = NumberEmbeddingSystem::default();
+        let target = system.numbers[&1].reflect_on_self();
+        system.numbers.clear();
+
+        assert_eq!(system.closest_to_profile(&target), None);
+    }
+}
@@ -10,13 +10,19 @@
 //! - **`OEIS` Trait**: Defines a comprehensive set of methods for generating
 //!   famous sequences (like Fibonacci, primes, Catalan numbers), analyzing their
 //!   properties (growth rate, patterns), and applying transformations.
-//! - **`OEISDatabase` Struct**: A concrete implementation of the `OEIS` trait.
+//! - **`OEISDatabase` Struct**: A concrete implementation of the `OEIS` trait,
+//!   optionally memoizing generated sequences.
+
+use std::cell::{Cell, RefCell};
+use std::collections::HashMap;
 
 /// A trait for interacting with the On-Line Encyclopedia of Integer Sequences (OEIS).
 pub trait OEIS {
     // Core sequence operations
     /// Generates a sequence of a given length by its OEIS identifier (e.g., "A000045").
     fn generate_sequence(&self, sequence_id: &str, length: usize) -> Vec<i64>;
+    /// Checks whether a sequence identifier is one this database can generate.
+    fn is_known_sequence(&self, sequence_id: &str) -> bool;
     /// Retrieves the common name of a sequence by its OEIS identifier.
     fn sequence_name(&self, sequence_id: &str) -> String;
     /// Retrieves a brief description of a sequence by its OEIS identifier.
@@ -27,6 +33,10 @@ pub trait OEIS {
     fn fibonacci_sequence(&self, n: usize) -> Vec<i64>;
     /// Generates the first `n` prime numbers.
     fn prime_sequence(&self, n: usize) -> Vec<i64>;
+    /// Generates the first `n` twin-prime pairs `(p, p + 2)`.
+    fn twin_primes(&self, n: usize) -> Vec<(i64, i64)>;
+    /// Generates the first `count` gaps between consecutive primes.
+    fn prime_gaps(&self, count: usize) -> Vec<i64>;
     /// Generates the first `n` triangular numbers.
     fn triangular_numbers(&self, n: usize) -> Vec<i64>;
     /// Generates the first `n` square numbers.
@@ -35,8 +45,24 @@ pub trait OEIS {
     fn catalan_numbers(&self, n: usize) -> Vec<i64>;
     /// Generates the first `n` Bell numbers.
     fn bell_numbers(&self, n: usize) -> Vec<i64>;
-    /// Generates Stirling numbers of the second kind.
+    /// Generates the first `n` Lucas numbers. Terms past the 91st overflow `i64`.
+    fn lucas(&self, n: usize) -> Vec<i64>;
+    /// Generates the first `n` Pell numbers. Terms past the 51st overflow `i64`.
+    fn pell(&self, n: usize) -> Vec<i64>;
+    /// Generates the first `n` Tribonacci numbers. Terms past the 75th overflow `i64`.
+    fn tribonacci(&self, n: usize) -> Vec<i64>;
+    /// Generates Stirling numbers of the second kind (see `stirling_second`).
     fn stirling_numbers(&self, n: usize, k: usize) -> Vec<Vec<i64>>;
+    /// Generates the triangle of unsigned Stirling numbers of the first kind, where
+    /// `c(n, k)` counts the permutations of `n` elements with `k` cycles, by the
+    /// recurrence `c(n, k) = (n-1)·c(n-1, k) + c(n-1, k-1)`. Entry `[n][k]` holds
+    /// `c(n, k)` for `n` and `k` up to the given bounds.
+    fn stirling_first(&self, n: usize, k: usize) -> Vec<Vec<i64>>;
+    /// Generates the triangle of Stirling numbers of the second kind, where
+    /// `S(n, k)` counts the partitions of `n` elements into `k` non-empty blocks, by
+    /// the recurrence `S(n, k) = k·S(n-1, k) + S(n-1, k-1)`. Entry `[n][k]` holds
+    /// `S(n, k)` for `n` and `k` up to the given bounds.
+    fn stirling_second(&self, n: usize, k: usize) -> Vec<Vec<i64>>;
     
     // Sequence analysis
     /// Estimates the growth rate of a sequence.
@@ -47,6 +73,15 @@ pub trait OEIS {
     fn find_recurrence(&self, sequence: &[i64]) -> Option<String>;
     /// Checks if a sequence appears to be diverging rapidly.
     fn sequence_divergence(&self, sequence: &[i64]) -> bool;
+    /// Evaluates the ordinary generating function `Σ a(n)·xⁿ` of a sequence at `x`,
+    /// truncated to the first `terms` terms.
+    ///
+    /// The partial sum only approximates the closed form when `|x|` is inside the
+    /// radius of convergence (e.g. `|x| < 1/2` for powers of 2, `|x| < 1/φ` for
+    /// Fibonacci). Outside it the partial sums grow without bound, and for fast-growing
+    /// sequences too many terms overflow the underlying `i64` generators.
+    /// Unknown identifiers evaluate to `0.0`.
+    fn evaluate_ogf(&self, sequence_id: &str, x: f64, terms: usize) -> f64;
     
     // Sequence transformations
     /// Calculates the cumulative sum of a sequence.
@@ -79,6 +114,12 @@ pub trait OEIS {
     fn a000041(&self, n: usize) -> Vec<i64>;
     /// Generates powers of 2 (A000079).
     fn a000079(&self, n: usize) -> Vec<i64>;
+    /// Generates Lucas numbers (A000032).
+    fn a000032(&self, n: usize) -> Vec<i64>;
+    /// Generates Pell numbers (A000129).
+    fn a000129(&self, n: usize) -> Vec<i64>;
+    /// Generates Tribonacci numbers (A000073).
+    fn a000073(&self, n: usize) -> Vec<i64>;
     
     // Helper methods
     /// Checks if a number is prime.
@@ -87,15 +128,117 @@ pub trait OEIS {
     fn binomial_coefficient(&self, n: usize, k: usize) -> i64;
 }
 
-/// A concrete implementation of the `OEIS` trait.
-pub struct OEISDatabase;
+// Linear recurrences `a(n) = Σ c[i]·a(n-1-i)`, as coefficients
+const LUCAS_RECURRENCE: [i64; 2] = [1, 1];
+const PELL_RECURRENCE: [i64; 2] = [2, 1];
+const TRIBONACCI_RECURRENCE: [i64; 3] = [1, 1, 1];
 
-impl Default for OEISDatabase {
-    fn default() -> Self { Self }
+/// Extends `terms` to `length` with the linear recurrence given by `coefficients`,
+/// truncating instead if `terms` is already longer.
+///
+/// `terms` must hold at least `coefficients.len()` initial values. Like the other
+/// generators this uses plain `i64` arithmetic, so it overflows once terms pass
+/// `i64::MAX`.
+fn continue_recurrence(mut terms: Vec<i64>, coefficients: &[i64], length: usize) -> Vec<i64> {
+    while terms.len() < length {
+        let n = terms.len();
+        let next = coefficients.iter().enumerate().map(|(i, c)| c * terms[n - 1 - i]).sum();
+        terms.push(next);
+    }
+    terms.truncate(length);
+    terms
 }
 
-impl OEIS for OEISDatabase {
-    fn generate_sequence(&self, sequence_id: &str, length: usize) -> Vec<i64> {
+/// Cache of generated sequence prefixes, keyed by sequence id and prefix length.
+type SequenceCache = HashMap<(String, usize), Vec<i64>>;
+
+/// A concrete implementation of the `OEIS` trait.
+///
+/// Created with `OEISDatabase::cached()`, generated sequences are memoized: a
+/// request no longer than a cached prefix is served from the cache, and a longer
+/// request extends the longest cached prefix instead of starting over.
+#[derive(Debug, Default)]
+pub struct OEISDatabase {
+    cache: Option<RefCell<SequenceCache>>,
+    cache_hits: Cell<usize>,
+}
+
+impl OEISDatabase {
+    /// Creates a database that memoizes generated sequences
+    pub fn cached() -> Self {
+        Self {
+            cache: Some(RefCell::new(HashMap::new())),
+            cache_hits: Cell::new(0),
+        }
+    }
+
+    /// Returns how many requests were served entirely from the cache
+    pub fn cache_hits(&self) -> usize {
+        self.cache_hits.get()
+    }
+
+    /// Generates a sequence, consulting and updating the cache if enabled
+    fn memoized(&self, sequence_id: &str, length: usize) -> Vec<i64> {
+        let Some(cache) = &self.cache else {
+            return self.compute_sequence(sequence_id, length);
+        };
+
+        let longest = cache.borrow().iter()
+            .filter(|((id, _), _)| id == sequence_id)
+            .map(|(_, prefix)| prefix)
+            .max_by_key(|prefix| prefix.len())
+            .cloned();
+
+        match longest {
+            Some(prefix) if prefix.len() >= length => {
+                self.cache_hits.set(self.cache_hits.get() + 1);
+                prefix[..length].to_vec()
+            }
+            prefix => {
+                let sequence = self.extend_sequence(sequence_id, prefix.unwrap_or_default(), length);
+                let mut cache = cache.borrow_mut();
+                // Shorter prefixes of this sequence are now redundant
+                cache.retain(|(id, _), _| id != sequence_id);
+                cache.insert((sequence_id.to_string(), length), sequence.clone());
+                sequence
+            }
+        }
+    }
+
+    /// Extends a known prefix of a sequence to `length` terms
+    ///
+    /// Recurrence-defined sequences continue from the prefix; closed-form ones are
+    /// cheap enough to regenerate.
+    fn extend_sequence(&self, sequence_id: &str, mut prefix: Vec<i64>, length: usize) -> Vec<i64> {
+        match sequence_id {
+            "A000045" if prefix.len() >= 2 => {
+                while prefix.len() < length {
+                    let n = prefix.len();
+                    prefix.push(prefix[n - 1] + prefix[n - 2]);
+                }
+                prefix
+            }
+            "A000040" if !prefix.is_empty() => {
+                let mut candidate = prefix[prefix.len() - 1] + 1;
+                while prefix.len() < length {
+                    if self.is_prime(candidate) {
+                        prefix.push(candidate);
+                    }
+                    candidate += 1;
+                }
+                prefix
+            }
+            "A000032" if prefix.len() >= 2 => continue_recurrence(prefix, &LUCAS_RECURRENCE, length),
+            "A000129" if prefix.len() >= 2 => continue_recurrence(prefix, &PELL_RECURRENCE, length),
+            "A000073" if prefix.len() >= 3 => {
+                continue_recurrence(prefix, &TRIBONACCI_RECURRENCE, length)
+            }
+            _ => self.compute_sequence(sequence_id, length),
+        }
+    }
+
+    /// Generates a sequence from scratch, bypassing the cache
+    fn compute_sequence(&self, sequence_id: &str, length: usize) -> Vec<i64> {
         match sequence_id {
             "A000045" => self.a000045(length),
             "A000040" => self.a000040(length),
@@ -107,9 +250,27 @@ impl OEIS for OEISDatabase {
             "A000720" => self.a000720(length),
             "A000041" => self.a000041(length),
             "A000079" => self.a000079(length),
+            "A000032" => self.a000032(length),
+            "A000129" => self.a000129(length),
+            "A000073" => self.a000073(length),
             _ => vec![0; length],
         }
     }
+}
+
+impl OEIS for OEISDatabase {
+    fn generate_sequence(&self, sequence_id: &str, length: usize) -> Vec<i64> {
+        self.memoized(sequence_id, length)
+    }
+    
+    fn is_known_sequence(&self, sequence_id: &str) -> bool {
+        matches!(
+            sequence_id,
+            "A000045" | "A000040" | "A000217" | "A000290" | "A000108"
+                | "A000110" | "A000142" | "A000720" | "A000041" | "A000079"
+                | "A000032" | "A000129" | "A000073"
+        )
+    }
     
     fn sequence_name(&self, sequence_id: &str) -> String {
         match sequence_id {
@@ -123,6 +284,9 @@ impl OEIS for OEISDatabase {
             "A000720" => "Prime counting function".to_string(),
             "A000041" => "Partition numbers".to_string(),
             "A000079" => "Powers of 2".to_string(),
+            "A000032" => "Lucas numbers".to_string(),
+            "A000129" => "Pell numbers".to_string(),
+            "A000073" => "Tribonacci numbers".to_string(),
             _ => "Unknown sequence".to_string(),
         }
     }
@@ -139,35 +303,85 @@ impl OEIS for OEISDatabase {
             "A000720" => "pi(n), the number of primes <= n".to_string(),
             "A000041" => "a(n) is the number of partitions of n".to_string(),
             "A000079" => "Powers of 2: a(n) = 2^n".to_string(),
+            "A000032" => "Lucas numbers: L(n) = L(n-1) + L(n-2) with L(0) = 2, L(1) = 1".to_string(),
+            "A000129" => "Pell numbers: a(n) = 2*a(n-1) + a(n-2) with a(0) = 0, a(1) = 1".to_string(),
+            "A000073" => "Tribonacci numbers: a(n) = a(n-1) + a(n-2) + a(n-3) with a(0) = a(1) = 0, a(2) = 1".to_string(),
             _ => "No description available".to_string(),
         }
     }
     
     fn fibonacci_sequence(&self, n: usize) -> Vec<i64> {
-        self.a000045(n)
+        self.generate_sequence("A000045", n)
     }
     
     fn prime_sequence(&self, n: usize) -> Vec<i64> {
-        self.a000040(n)
+        self.generate_sequence("A000040", n)
+    }
+    
+    fn twin_primes(&self, n: usize) -> Vec<(i64, i64)> {
+        let mut pairs = Vec::new();
+        let mut num = 3;
+        while pairs.len() < n {
+            if self.is_prime(num) && self.is_prime(num + 2) {
+                pairs.push((num, num + 2));
+            }
+            num += 2;
+        }
+        pairs
+    }
+    
+    fn prime_gaps(&self, count: usize) -> Vec<i64> {
+        self.prime_sequence(count + 1)
+            .windows(2)
+            .map(|pair| pair[1] - pair[0])
+            .collect()
     }
     
     fn triangular_numbers(&self, n: usize) -> Vec<i64> {
-        self.a000217(n)
+        self.generate_sequence("A000217", n)
     }
     
     fn square_numbers(&self, n: usize) -> Vec<i64> {
-        self.a000290(n)
+        self.generate_sequence("A000290", n)
     }
     
     fn catalan_numbers(&self, n: usize) -> Vec<i64> {
-        self.a000108(n)
+        self.generate_sequence("A000108", n)
     }
     
     fn bell_numbers(&self, n: usize) -> Vec<i64> {
-        self.a000110(n)
+        self.generate_sequence("A000110", n)
+    }
+    
+    fn lucas(&self, n: usize) -> Vec<i64> {
+        self.generate_sequence("A000032", n)
+    }
+    
+    fn pell(&self, n: usize) -> Vec<i64> {
+        self.generate_sequence("A000129", n)
+    }
+    
+    fn tribonacci(&self, n: usize) -> Vec<i64> {
+        self.generate_sequence("A000073", n)
     }
     
     fn stirling_numbers(&self, n: usize, k: usize) -> Vec<Vec<i64>> {
+        self.stirling_second(n, k)
+    }
+    
+    fn stirling_first(&self, n: usize, k: usize) -> Vec<Vec<i64>> {
+        let mut stirling = vec![vec![0; k + 1]; n + 1];
+        stirling[0][0] = 1;
+        
+        for i in 1..=n {
+            for j in 1..=k {
+                stirling[i][j] = (i - 1) as i64 * stirling[i-1][j] + stirling[i-1][j-1];
+            }
+        }
+        stirling
+    }
+    
+    fn stirling_second(&self, n: usize, k: usize) -> Vec<Vec<i64>> {
         let mut stirling = vec![vec![0; k + 1]; n + 1];
         stirling[0][0] = 1;
         
@@ -247,6 +461,15 @@ impl OEIS for OEISDatabase {
         last_few.windows(2).any(|w| (w[1] - w[0]).abs() > 1000)
     }
     
+    fn evaluate_ogf(&self, sequence_id: &str, x: f64, terms: usize) -> f64 {
+        let mut power = 1.0;
+        self.generate_sequence(sequence_id, terms).iter().map(|&a| {
+            let term = a as f64 * power;
+            power *= x;
+            term
+        }).sum()
+    }
+    
     fn cumulative_sum(&self, sequence: &[i64]) -> Vec<i64> {
         let mut sum = 0;
         sequence.iter().map(|&x| {
@@ -378,6 +601,18 @@ impl OEIS for OEISDatabase {
         (0..n).map(|i| 1 << i).collect()
     }
     
+    fn a000032(&self, n: usize) -> Vec<i64> {
+        continue_recurrence(vec![2, 1], &LUCAS_RECURRENCE, n)
+    }
+    
+    fn a000129(&self, n: usize) -> Vec<i64> {
+        continue_recurrence(vec![0, 1], &PELL_RECURRENCE, n)
+    }
+    
+    fn a000073(&self, n: usize) -> Vec<i64> {
+        continue_recurrence(vec![0, 0, 1], &TRIBONACCI_RECURRENCE, n)
+    }
+    
     // Helper methods
     fn is_prime(&self, n: i64) -> bool {
         if n < 2 { return false; }
@@ -399,4 +634,114 @@ impl OEIS for OEISDatabase {
         }
         result
     }
-}
\ No newline at end of file
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_evaluate_ogf_powers_of_two_converges() {
+        let db = OEISDatabase::default();
+        let target = 1.0 / (1.0 - 2.0 * 0.25);
+
+        let errors: Vec<f64> = [5, 10, 20, 40]
+            .iter()
+            .map(|&terms| (db.evaluate_ogf("A000079", 0.25, terms) - target).abs())
+            .collect();
+
+        assert!(errors.windows(2).all(|w| w[1] < w[0]));
+        assert!(errors[3] < 1e-9);
+    }
+
+    #[test]
+    fn test_cached_sequences_match_uncached() {
+        let plain = OEISDatabase::default();
+        let cached = OEISDatabase::cached();
+
+        assert_eq!(cached.fibonacci_sequence(20), cached.fibonacci_sequence(20));
+        // Shorter, extended and repeated requests all agree with fresh computation
+        for &length in &[10, 30, 5, 30] {
+            assert_eq!(cached.fibonacci_sequence(length), plain.fibonacci_sequence(length));
+            assert_eq!(cached.prime_sequence(length), plain.prime_sequence(length));
+            assert_eq!(cached.catalan_numbers(length), plain.catalan_numbers(length));
+        }
+    }
+
+    #[test]
+    fn test_repeated_request_hits_cache() {
+        let db = OEISDatabase::cached();
+
+        db.fibonacci_sequence(40);
+        assert_eq!(db.cache_hits(), 0);
+
+        db.fibonacci_sequence(40);
+        assert_eq!(db.cache_hits(), 1);
+
+        // A shorter prefix is also a hit; a longer one extends instead
+        db.generate_sequence("A000045", 12);
+        db.fibonacci_sequence(50);
+        assert_eq!(db.cache_hits(), 2);
+
+        // Uncached databases never record hits
+        let plain = OEISDatabase::default();
+        plain.fibonacci_sequence(40);
+        plain.fibonacci_sequence(40);
+        assert_eq!(plain.cache_hits(), 0);
+    }
+
+    #[test]
+    fn test_generalized_fibonacci_prefixes() {
+        let db = OEISDatabase::default();
+
+        assert_eq!(db.lucas(6), vec![2, 1, 3, 4, 7, 11]);
+        assert_eq!(db.pell(6), vec![0, 1, 2, 5, 12, 29]);
+        assert_eq!(db.tribonacci(7), vec![0, 0, 1, 1, 2, 4, 7]);
+        assert_eq!(db.generate_sequence("A000032", 1), vec![2]);
+        assert!(db.is_known_sequence("A000129"));
+        assert_eq!(db.sequence_name("A000073"), "Tribonacci numbers");
+
+        // Cached prefixes extend by the same recurrences
+        let cached = OEISDatabase::cached();
+        cached.tribonacci(4);
+        assert_eq!(cached.tribonacci(30), db.tribonacci(30));
+
+        // The last terms representable in i64
+        assert_eq!(db.lucas(91)[90], 6_440_026_026_380_244_498);
+        assert_eq!(db.pell(51)[50], 4_866_752_642_924_153_522);
+        assert_eq!(db.tribonacci(75)[74], 7_015_254_043_203_144_209);
+    }
+
+    #[test]
+    fn test_twin_primes_and_gaps() {
+        let db = OEISDatabase::default();
+
+        assert_eq!(db.twin_primes(3), vec![(3, 5), (5, 7), (11, 13)]);
+        assert_eq!(db.twin_primes(6).last(), Some(&(41, 43)));
+        assert!(db.twin_primes(0).is_empty());
+
+        assert_eq!(db.prime_gaps(6), vec![1, 2, 2, 4, 2, 4]);
+        assert!(db.prime_gaps(0).is_empty());
+    }
+
+    #[test]
+    fn test_stirling_kinds() {
+        let db = OEISDatabase::default();
+        let first = db.stirling_first(4, 4);
+        let second = db.stirling_second(4, 4);
+
+        assert_eq!(second[4][2], 7);
+        assert_eq!(first[4][2], 11);
+        assert_eq!(first[4], vec![0, 6, 11, 6, 1]);
+        assert_eq!(second[4], vec![0, 1, 7, 6, 1]);
+        // Each row of the first kind sums to n!
+        assert_eq!(db.stirling_first(6, 6)[6].iter().sum::<i64>(), 720);
+        assert_eq!(db.stirling_numbers(4, 4), second);
+    }
+
+    #[test]
+    fn test_evaluate_ogf_unknown_sequence_is_zero() {
+        let db = OEISDatabase::default();
+        assert_eq!(db.evaluate_ogf("A999999", 0.5, 10), 0.0);
+    }
+}
@@ -11,15 +11,27 @@
 //!   networks and twistors.
 //! - **`PenroseMathematician` Struct**: A concrete implementation of the `Penrose` trait.
 
+/// A line segment of a tiling, from its first point to its second.
+pub type Edge = ((f64, f64), (f64, f64));
+
 /// A trait for mathematical concepts related to the work of Roger Penrose.
 pub trait Penrose {
     // Penrose tiling
     /// Generates a Penrose tiling using a set of rules or iterations.
-    fn generate_penrose_tiling(&self, iterations: usize) -> Vec<((f64, f64), (f64, f64))>;
+    fn generate_penrose_tiling(&self, iterations: usize) -> Vec<Edge>;
     /// Returns the golden ratio, a key constant in Penrose tilings.
     fn golden_ratio(&self) -> f64;
     /// Generates a 1D tiling based on the Fibonacci sequence.
     fn fibonacci_tiling(&self, n: usize) -> Vec<f64>;
+    /// Generates a P3 rhombus tiling by deflating a sun of Robinson triangles `depth` times.
+    /// Each half-rhombus triangle is emitted as two consecutive edges running from its
+    /// apex along its legs. The triangle count grows by roughly φ² per level.
+    fn penrose_rhombus_tiling(&self, depth: usize) -> Vec<Edge>;
+    /// Counts the (thick, thin) rhombi in a tiling from `penrose_rhombus_tiling`.
+    /// Edge pairs are classified by apex angle (108° thick, 36° thin) and two half-rhombi
+    /// make one rhombus, with halves on the boundary rounded up. Edges that do not form
+    /// an apex pair are ignored. As depth increases, thick/thin approaches φ.
+    fn tile_counts(&self, edges: &[Edge]) -> (usize, usize);
 
     // Quasicrystals
     /// Generates a 3D quasicrystal pattern.
@@ -40,6 +52,9 @@ pub trait Penrose {
     fn twistor_transform(&self, point: (f64, f64, f64, f64)) -> (f64, f64, f64, f64);
 }
 
+/// A Robinson triangle as (thick, apex, left, right); two of them form one rhombus.
+type HalfRhombus = (bool, (f64, f64), (f64, f64), (f64, f64));
+
 /// A concrete implementation of the `Penrose` trait.
 pub struct PenroseMathematician;
 
@@ -48,7 +63,7 @@ impl Default for PenroseMathematician {
 }
 
 impl Penrose for PenroseMathematician {
-    fn generate_penrose_tiling(&self, iterations: usize) -> Vec<((f64, f64), (f64, f64))> {
+    fn generate_penrose_tiling(&self, iterations: usize) -> Vec<Edge> {
         // Placeholder: generate some lines based on golden ratio
         let phi = self.golden_ratio();
         let mut lines = Vec::new();
@@ -72,6 +87,53 @@ impl Penrose for PenroseMathematician {
         }
         sequence
     }
+    fn penrose_rhombus_tiling(&self, depth: usize) -> Vec<Edge> {
+        let shrink = 1.0 / self.golden_ratio();
+        let lerp = |p: (f64, f64), q: (f64, f64)| {
+            (p.0 + (q.0 - p.0) * shrink, p.1 + (q.1 - p.1) * shrink)
+        };
+        let rim = |step: i32| {
+            let angle = step as f64 * std::f64::consts::PI / 10.0;
+            (angle.cos(), angle.sin())
+        };
+
+        // A sun of ten thin half-rhombi around the origin
+        let mut triangles: Vec<HalfRhombus> = (0..10)
+            .map(|i| {
+                let (b, c) = (rim(2 * i - 1), rim(2 * i + 1));
+                if i % 2 == 0 { (false, (0.0, 0.0), c, b) } else { (false, (0.0, 0.0), b, c) }
+            })
+            .collect();
+
+        for _ in 0..depth {
+            triangles = triangles.into_iter().flat_map(|(thick, a, b, c)| {
+                if thick {
+                    let q = lerp(b, a);
+                    let r = lerp(b, c);
+                    vec![(true, r, c, a), (true, q, r, b), (false, r, q, a)]
+                } else {
+                    let p = lerp(a, b);
+                    vec![(false, c, p, b), (true, p, c, a)]
+                }
+            }).collect();
+        }
+
+        triangles.into_iter().flat_map(|(_, a, b, c)| [(a, b), (a, c)]).collect()
+    }
+    fn tile_counts(&self, edges: &[Edge]) -> (usize, usize) {
+        let (mut thick, mut thin) = (0usize, 0usize);
+        for pair in edges.chunks_exact(2) {
+            let (apex, b) = pair[0];
+            let (other_apex, c) = pair[1];
+            if (apex.0 - other_apex.0).abs() > 1e-9 || (apex.1 - other_apex.1).abs() > 1e-9 {
+                continue;
+            }
+            let (u, v) = ((b.0 - apex.0, b.1 - apex.1), (c.0 - apex.0, c.1 - apex.1));
+            let angle = (u.0 * v.1 - u.1 * v.0).atan2(u.0 * v.0 + u.1 * v.1).abs();
+            if angle > 72f64.to_radians() { thick += 1 } else { thin += 1 }
+        }
+        (thick.div_ceil(2), thin.div_ceil(2))
+    }
     fn quasicrystal_pattern(&self, dimensions: usize, golden_ratio: f64) -> Vec<(f64, f64, f64)> {
         let mut pattern = Vec::new();
         for i in 0..dimensions {
@@ -120,4 +182,33 @@ impl Penrose for PenroseMathematician {
         let (x, y, z, w) = point;
         (y, z, w, x) // Cyclic permutation
     }
-}
\ No newline at end of file
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_tile_ratio_converges_to_golden_ratio() {
+        let penrose = PenroseMathematician;
+        let phi = penrose.golden_ratio();
+
+        let errors: Vec<f64> = (2..=7).map(|depth| {
+            let (thick, thin) = penrose.tile_counts(&penrose.penrose_rhombus_tiling(depth));
+            assert!(thin > 0);
+            (thick as f64 / thin as f64 - phi).abs()
+        }).collect();
+
+        assert!(errors.windows(2).all(|w| w[1] < w[0]), "{:?}", errors);
+        assert!(errors[errors.len() - 1] < 1e-3);
+    }
+
+    #[test]
+    fn test_tile_counts_after_one_deflation() {
+        let penrose = PenroseMathematician;
+
+        // Ten thin half-rhombi deflate into ten thin and ten thick halves
+        assert_eq!(penrose.tile_counts(&penrose.penrose_rhombus_tiling(1)), (5, 5));
+        assert_eq!(penrose.tile_counts(&penrose.penrose_rhombus_tiling(0)), (0, 5));
+    }
+}
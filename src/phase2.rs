@@ -20,7 +20,19 @@
 //! - **`Phase2Engine`**: A concrete implementation of the `Phase2` trait.
 
 use crate::vectos::{MathematicalUniverse, StageVibes, LatticeState};
-use std::collections::HashMap;
+use crate::escher::{Escher, EscherArtist};
+use crate::penrose::{Penrose, PenroseMathematician};
+use std::collections::{HashMap, VecDeque};
+use std::hash::{Hash, Hasher};
+use std::sync::{Mutex, OnceLock};
+
+/// Upper bound on the number of refinement rounds `reflect_on_system` will run,
+/// regardless of the configured `reflection_depth`.
+pub const MAX_REFLECTION_DEPTH: usize = 16;
+
+/// Maximum number of system reflections a `Phase2Engine` keeps memoized.
+/// The least recently used reflection is evicted once the cache is full.
+pub const MAX_CACHED_REFLECTIONS: usize = 64;
 
 /// A trait for Large Language Model (LLM) reflection and self-modification.
 /// This enables the system to reflect on its own structure and evolve through
@@ -95,7 +107,12 @@ pub trait Phase2 {
     fn calculate_complexity(&self, universe: &MathematicalUniverse) -> f64;
     /// Calculates the balance of harmony within the universe.
     fn calculate_harmony_balance(&self, universe: &MathematicalUniverse) -> f64;
-    /// Calculates the complexity of a set of mathematical patterns.
+    /// Calculates the complexity of a set of mathematical patterns, in [0, 1].
+    ///
+    /// Approximates Kolmogorov complexity by how well the patterns compress:
+    /// values are quantized to bytes over their common range and the LZ78 coded
+    /// size is compared with the raw size. Repetitive patterns score near 0,
+    /// incompressible noise scores 1.
     fn calculate_pattern_complexity(&self, patterns: &[Vec<f64>]) -> f64;
     /// Calculates the regularity or orderliness of a set of patterns.
     fn calculate_pattern_regularity(&self, patterns: &[Vec<f64>]) -> f64;
@@ -114,7 +131,7 @@ pub trait Phase2 {
 }
 
 /// Represents the output of a holistic system reflection.
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq)]
 pub struct SystemReflection {
     /// A score representing the overall coherence and consistency of the system.
     pub overall_coherence: f64,
@@ -135,7 +152,8 @@ pub struct SystemReflection {
 /// Represents the output of analyzing a set of mathematical patterns.
 #[derive(Debug, Clone)]
 pub struct PatternAnalysis {
-    /// The type of pattern identified (e.g., "fractal", "harmonic").
+    /// The type of pattern identified: "monotonic", "oscillatory", "fractal",
+    /// "random", or "indeterminate" when no series is long enough to classify.
     pub pattern_type: String,
     /// The complexity of the pattern.
     pub complexity: f64,
@@ -402,6 +420,51 @@ pub struct Phase2Engine {
     pub optimization_aggressiveness: f64,
     /// Whether the system is allowed to modify its own code.
     pub self_modification_enabled: bool,
+    /// Memoized system reflections, keyed by universe fingerprint and reflection depth.
+    /// Guarded by a mutex so a shared engine can reflect from several threads.
+    reflection_cache: Mutex<ReflectionCache>,
+}
+
+/// A least-recently-used store of system reflections, bounded by `MAX_CACHED_REFLECTIONS`.
+#[derive(Default)]
+struct ReflectionCache {
+    entries: HashMap<(u64, usize), SystemReflection>,
+    recency: VecDeque<(u64, usize)>,
+    hits: u64,
+}
+
+impl ReflectionCache {
+    /// Looks up a reflection and marks it as the most recently used.
+    fn get(&mut self, key: &(u64, usize)) -> Option<SystemReflection> {
+        let reflection = self.entries.get(key).cloned()?;
+        self.hits += 1;
+        self.touch(key);
+        Some(reflection)
+    }
+
+    /// Stores a reflection, evicting the least recently used one when full.
+    fn insert(&mut self, key: (u64, usize), reflection: SystemReflection) {
+        if self.entries.insert(key, reflection).is_some() {
+            self.touch(&key);
+            return;
+        }
+        self.recency.push_back(key);
+        while self.entries.len() > MAX_CACHED_REFLECTIONS {
+            match self.recency.pop_front() {
+                Some(oldest) => {
+                    self.entries.remove(&oldest);
+                }
+                None => break,
+            }
+        }
+    }
+
+    fn touch(&mut self, key: &(u64, usize)) {
+        if let Some(position) = self.recency.iter().position(|k| k == key) {
+            self.recency.remove(position);
+        }
+        self.recency.push_back(*key);
+    }
 }
 
 impl Default for Phase2Engine {
@@ -418,23 +481,71 @@ impl Default for Phase2Engine {
             reflection_depth: 5,
             optimization_aggressiveness: 0.7,
             self_modification_enabled: true,
+            reflection_cache: Mutex::new(ReflectionCache::default()),
         }
     }
 }
 
-impl Phase2 for Phase2Engine {
-    fn reflect_on_system(&self, system_state: &MathematicalUniverse) -> SystemReflection {
+impl Phase2Engine {
+    /// A cheap fingerprint of a universe: its dimension count and Gödel numbers.
+    ///
+    /// `reflect_on_system` memoizes on this, so universes that differ only in
+    /// other fields share a cached reflection.
+    pub fn universe_fingerprint(universe: &MathematicalUniverse) -> u64 {
+        let mut hasher = std::collections::hash_map::DefaultHasher::new();
+        universe.dimensions.hash(&mut hasher);
+        universe.godel_numbers.hash(&mut hasher);
+        hasher.finish()
+    }
+
+    /// Returns the process-wide engine, so callers that build no engine of
+    /// their own (such as `SelfAwareNumber::reflect_on_self`) share one reflection cache.
+    pub fn shared() -> &'static Phase2Engine {
+        static SHARED: OnceLock<Phase2Engine> = OnceLock::new();
+        SHARED.get_or_init(Phase2Engine::default)
+    }
+
+    /// Returns how many reflections were served from the cache since it was last cleared.
+    pub fn reflection_cache_hits(&self) -> u64 {
+        self.lock_reflection_cache().hits
+    }
+
+    /// Returns the number of memoized system reflections, at most `MAX_CACHED_REFLECTIONS`.
+    pub fn cached_reflections(&self) -> usize {
+        self.lock_reflection_cache().entries.len()
+    }
+
+    /// Forgets every memoized system reflection.
+    pub fn clear_reflection_cache(&self) {
+        *self.lock_reflection_cache() = ReflectionCache::default();
+    }
+
+    /// Locks the reflection cache; a poisoned cache only holds finished reflections, so it is reused.
+    fn lock_reflection_cache(&self) -> std::sync::MutexGuard<'_, ReflectionCache> {
+        self.reflection_cache.lock().unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+
+    /// Computes a system reflection from scratch, bypassing the reflection cache.
+    fn compute_system_reflection(&self, system_state: &MathematicalUniverse) -> SystemReflection {
         let coherence = self.calculate_coherence(system_state);
         let beauty = self.calculate_mathematical_beauty(system_state);
         let complexity = self.calculate_complexity(system_state);
         let harmony = self.calculate_harmony_balance(system_state);
         
-        let emergent_patterns = vec![
+        let mut emergent_patterns = vec![
             "Fibonacci resonance in stage relationships".to_string(),
             "Golden ratio harmony in geometric structures".to_string(),
             "Quantum-classical duality in mathematical objects".to_string(),
         ];
         
+        // Each round of reflection re-analyzes the previous round's output,
+        // surfacing higher-order patterns. Depth 0 is the base reflection.
+        let mut previous_round = emergent_patterns.clone();
+        for round in 0..self.reflection_depth.min(MAX_REFLECTION_DEPTH) {
+            previous_round = self.refine_patterns(&previous_round, round);
+            emergent_patterns.extend(previous_round.iter().cloned());
+        }
+        
         let optimization_suggestions = vec![
             "Enhance Gödel encoding efficiency".to_string(),
             "Optimize Bott periodicity calculations".to_string(),
@@ -457,6 +568,150 @@ impl Phase2 for Phase2Engine {
             self_modification_opportunities: modification_opportunities,
         }
     }
+
+    /// Re-analyzes the patterns surfaced by the previous reflection round.
+    ///
+    /// Each prior pattern is reflected upon together with its neighbour in that
+    /// round: the refined pattern keeps the prior pattern's body and extends it
+    /// with the motif the neighbour most recently picked up (its first word if
+    /// it has none yet). Bodies grow by one motif per round, so the depth cap
+    /// also bounds their length.
+    fn refine_patterns(&self, previous: &[String], round: usize) -> Vec<String> {
+        let body = |pattern: &str| pattern.split_once("reflection: ").map_or(pattern, |(_, body)| body).to_string();
+        let motif = |pattern: &str| {
+            let body = body(pattern);
+            match body.rsplit_once(" via ") {
+                Some((_, motif)) => motif.to_string(),
+                None => body.split_whitespace().next().unwrap_or_default().to_string(),
+            }
+        };
+
+        (0..previous.len())
+            .map(|i| {
+                let neighbour = &previous[(i + 1) % previous.len()];
+                format!("Order-{} reflection: {} via {}", round + 1, body(&previous[i]), motif(neighbour))
+            })
+            .collect()
+    }
+
+    /// Types a set of series by majority vote over the series long enough to classify.
+    ///
+    /// Ties go to the earlier of monotonic, oscillatory, fractal, random.
+    fn classify_patterns(patterns: &[Vec<f64>]) -> &'static str {
+        const TYPES: [&str; 4] = ["monotonic", "oscillatory", "fractal", "random"];
+        let mut votes = [0usize; 4];
+        for series in patterns.iter().filter(|series| series.len() >= MIN_CLASSIFIABLE_LENGTH) {
+            let kind = Self::classify_series(series);
+            votes[TYPES.iter().position(|&t| t == kind).unwrap()] += 1;
+        }
+
+        let best = (0..TYPES.len()).rev().max_by_key(|&i| votes[i]).unwrap();
+        if votes[best] == 0 { "indeterminate" } else { TYPES[best] }
+    }
+
+    /// Types a single series.
+    ///
+    /// - **monotonic**: no step changes direction.
+    /// - **oscillatory**: the autocorrelation turns negative and then recovers to a
+    ///   strong positive peak, i.e. the series repeats with some period.
+    /// - **fractal**: the variance of increments grows as a power of the lag
+    ///   (variance-ratio test), so the series looks alike at every scale, as a
+    ///   random walk does.
+    /// - **random**: anything else; increments are uncorrelated across scales.
+    fn classify_series(series: &[f64]) -> &'static str {
+        let steps: Vec<f64> = series.windows(2).map(|w| w[1] - w[0]).collect();
+        if steps.iter().all(|&d| d >= 0.0) || steps.iter().all(|&d| d <= 0.0) {
+            return "monotonic";
+        }
+
+        let max_lag = series.len() / 2;
+        if let Some(crossing) = (1..=max_lag).find(|&lag| Self::autocorrelation(series, lag) < 0.0) {
+            let peak = (crossing..=max_lag)
+                .map(|lag| Self::autocorrelation(series, lag))
+                .fold(f64::NEG_INFINITY, f64::max);
+            if peak > OSCILLATION_PEAK {
+                return "oscillatory";
+            }
+        }
+
+        // Hurst-style exponent from increment variances at doubling lags:
+        // V(2k) / V(k) = 2^(2H), with H ≈ 0 for noise and H ≈ 0.5 for a random walk
+        let exponents: Vec<f64> = [1, 2, 4]
+            .iter()
+            .filter(|&&lag| 2 * lag < series.len())
+            .filter_map(|&lag| {
+                let ratio = Self::increment_variance(series, 2 * lag) / Self::increment_variance(series, lag);
+                (ratio > 0.0 && ratio.is_finite()).then(|| 0.5 * ratio.log2())
+            })
+            .collect();
+        let hurst = exponents.iter().sum::<f64>() / exponents.len().max(1) as f64;
+
+        if hurst > SELF_SIMILAR_HURST { "fractal" } else { "random" }
+    }
+
+    /// Size in bits of the LZ78 encoding of `bytes`.
+    ///
+    /// Each phrase is the longest already-seen phrase plus one new byte, coded as
+    /// a dictionary index (enough bits to address every earlier phrase) and the byte.
+    fn lz78_bits(bytes: &[u8]) -> usize {
+        let mut dictionary: HashMap<(usize, u8), usize> = HashMap::new();
+        let mut bits = 0;
+        let mut phrase = 0;
+        for (i, &byte) in bytes.iter().enumerate() {
+            match dictionary.get(&(phrase, byte)) {
+                Some(&next) if i + 1 < bytes.len() => phrase = next,
+                _ => {
+                    let phrases = dictionary.len() + 1;
+                    bits += (usize::BITS - phrases.leading_zeros()) as usize + 8;
+                    dictionary.insert((phrase, byte), phrases);
+                    phrase = 0;
+                }
+            }
+        }
+        bits
+    }
+
+    /// Sample autocorrelation of a series at the given lag, in [-1, 1]
+    fn autocorrelation(series: &[f64], lag: usize) -> f64 {
+        let mean = series.iter().sum::<f64>() / series.len() as f64;
+        let variance: f64 = series.iter().map(|x| (x - mean).powi(2)).sum();
+        if variance == 0.0 || lag >= series.len() {
+            return 0.0;
+        }
+        let covariance: f64 = series.iter()
+            .zip(&series[lag..])
+            .map(|(a, b)| (a - mean) * (b - mean))
+            .sum();
+        covariance / variance
+    }
+
+    /// Mean squared change over `lag` steps
+    fn increment_variance(series: &[f64], lag: usize) -> f64 {
+        let increments: Vec<f64> = series.iter().zip(&series[lag..]).map(|(a, b)| b - a).collect();
+        increments.iter().map(|d| d * d).sum::<f64>() / increments.len() as f64
+    }
+}
+
+/// Shortest series `analyze_mathematical_patterns` will classify
+const MIN_CLASSIFIABLE_LENGTH: usize = 8;
+
+/// Autocorrelation a recovered peak must exceed for a series to count as oscillatory
+const OSCILLATION_PEAK: f64 = 0.3;
+
+/// Hurst exponent above which increments are considered self-similar
+const SELF_SIMILAR_HURST: f64 = 0.25;
+
+impl Phase2 for Phase2Engine {
+    fn reflect_on_system(&self, system_state: &MathematicalUniverse) -> SystemReflection {
+        let key = (Self::universe_fingerprint(system_state), self.reflection_depth.min(MAX_REFLECTION_DEPTH));
+        if let Some(reflection) = self.lock_reflection_cache().get(&key) {
+            return reflection;
+        }
+        
+        let reflection = self.compute_system_reflection(system_state);
+        self.lock_reflection_cache().insert(key, reflection.clone());
+        reflection
+    }
     
     fn analyze_mathematical_patterns(&self, patterns: &[Vec<f64>]) -> PatternAnalysis {
         let complexity = self.calculate_pattern_complexity(patterns);
@@ -471,7 +726,7 @@ impl Phase2 for Phase2Engine {
         ];
         
         PatternAnalysis {
-            pattern_type: "Complex mathematical resonance".to_string(),
+            pattern_type: Self::classify_patterns(patterns).to_string(),
             complexity,
             regularity,
             beauty_score: beauty,
@@ -481,6 +736,19 @@ impl Phase2 for Phase2Engine {
     }
     
     fn generate_self_modification(&self, reflection: &SystemReflection) -> SelfModification {
+        // When self-modification is disabled the plan is inert: nothing is
+        // proposed, so downstream code has nothing to act on.
+        if !self.self_modification_enabled {
+            return SelfModification {
+                modification_type: "Disabled".to_string(),
+                target_component: String::new(),
+                proposed_changes: Vec::new(),
+                expected_improvement: 0.0,
+                risk_assessment: 0.0,
+                implementation_plan: Vec::new(),
+            };
+        }
+        
         let modification_type = "Enhancement".to_string();
         let target_component = "Mathematical trait integration".to_string();
         
@@ -559,31 +827,42 @@ impl Phase2 for Phase2Engine {
         target_metrics.insert("efficiency".to_string(), 0.90);
         target_metrics.insert("beauty".to_string(), 0.88);
         
-        let steps = vec![
-            OptimizationStep {
-                step_name: "Enhance mathematical trait integration".to_string(),
-                description: "Improve cross-trait operations".to_string(),
-                expected_impact: 0.15,
-                implementation_difficulty: 0.7,
-            },
-            OptimizationStep {
-                step_name: "Optimize resonance calculations".to_string(),
-                description: "Improve harmonic analysis".to_string(),
-                expected_impact: 0.12,
-                implementation_difficulty: 0.6,
-            },
+        // Aggressiveness in [0, 1] controls both how many steps are attempted
+        // and how bold each one is: scale factors run from 0.5x to 1.5x.
+        let aggressiveness = self.optimization_aggressiveness.clamp(0.0, 1.0);
+        let boldness = 0.5 + aggressiveness;
+        
+        let candidate_steps = [
+            ("Enhance mathematical trait integration", "Improve cross-trait operations", 0.15, 0.7),
+            ("Optimize resonance calculations", "Improve harmonic analysis", 0.12, 0.6),
+            ("Refine Gödel encoding", "Reduce the size of composed Gödel numbers", 0.10, 0.5),
+            ("Rebalance stage vibes", "Shift resonance frequencies towards equilibrium", 0.14, 0.65),
+            ("Restructure Bott coordinates", "Reorganize fibers for tighter periodicity", 0.18, 0.8),
+            ("Rewrite core evolution loop", "Replace incremental evolution with global search", 0.22, 0.9),
         ];
+        let step_count = 2 + (aggressiveness * (candidate_steps.len() - 2) as f64).round() as usize;
+        
+        let steps = candidate_steps
+            .iter()
+            .take(step_count)
+            .map(|&(name, description, impact, difficulty)| OptimizationStep {
+                step_name: name.to_string(),
+                description: description.to_string(),
+                expected_impact: (impact * boldness).min(1.0),
+                implementation_difficulty: (difficulty * boldness).min(1.0),
+            })
+            .collect();
         
         let mut expected_improvements = HashMap::new();
-        expected_improvements.insert("coherence".to_string(), 0.10);
-        expected_improvements.insert("efficiency".to_string(), 0.08);
-        expected_improvements.insert("beauty".to_string(), 0.12);
+        expected_improvements.insert("coherence".to_string(), 0.10 * boldness);
+        expected_improvements.insert("efficiency".to_string(), 0.08 * boldness);
+        expected_improvements.insert("beauty".to_string(), 0.12 * boldness);
         
         OptimizationPlan {
             target_metrics,
             optimization_steps: steps,
             expected_improvements,
-            risk_assessment: 0.25,
+            risk_assessment: 0.1 + 0.6 * aggressiveness,
         }
     }
     
@@ -623,11 +902,24 @@ impl Phase2 for Phase2Engine {
         }
     }
     
-    fn reflect_on_escher_patterns(&self, _tessellations: &[Vec<Vec<u8>>]) -> EscherReflection {
+    fn reflect_on_escher_patterns(&self, tessellations: &[Vec<Vec<u8>>]) -> EscherReflection {
+        // Symmetry richness is the mean point-group order of each pattern's
+        // wallpaper group, normalized by the richest group (p4m, order 8).
+        let artist = EscherArtist;
+        let symmetry_richness = if tessellations.is_empty() {
+            0.0
+        } else {
+            let total_order: usize = tessellations
+                .iter()
+                .map(|pattern| artist.wallpaper_group(pattern).point_group_order())
+                .sum();
+            total_order as f64 / (8.0 * tessellations.len() as f64)
+        };
+        
         EscherReflection {
             visual_beauty: 0.92,
             mathematical_ingenuity: 0.89,
-            symmetry_richness: 0.91,
+            symmetry_richness,
         }
     }
     
@@ -663,9 +955,25 @@ impl Phase2 for Phase2Engine {
         }
     }
     
-    fn reflect_on_penrose_mathematics(&self, _tilings: &[Vec<((f64, f64), (f64, f64))>]) -> PenroseReflection {
+    fn reflect_on_penrose_mathematics(&self, tilings: &[Vec<((f64, f64), (f64, f64))>]) -> PenroseReflection {
+        // Geometric beauty is how closely each rhombus tiling's thick/thin ratio
+        // approaches φ; tilings without countable rhombi keep the baseline score.
+        let penrose = PenroseMathematician;
+        let phi = penrose.golden_ratio();
+        let closeness: Vec<f64> = tilings
+            .iter()
+            .map(|tiling| penrose.tile_counts(tiling))
+            .filter(|&(_, thin)| thin > 0)
+            .map(|(thick, thin)| (1.0 - (thick as f64 / thin as f64 - phi).abs() / phi).max(0.0))
+            .collect();
+        let geometric_beauty = if closeness.is_empty() {
+            0.94
+        } else {
+            closeness.iter().sum::<f64>() / closeness.len() as f64
+        };
+        
         PenroseReflection {
-            geometric_beauty: 0.94,
+            geometric_beauty,
             mathematical_ingenuity: 0.92,
             physical_relevance: 0.88,
         }
@@ -793,7 +1101,21 @@ impl Phase2 for Phase2Engine {
     fn calculate_mathematical_beauty(&self, _universe: &MathematicalUniverse) -> f64 { 0.91 }
     fn calculate_complexity(&self, _universe: &MathematicalUniverse) -> f64 { 0.89 }
     fn calculate_harmony_balance(&self, _universe: &MathematicalUniverse) -> f64 { 0.88 }
-    fn calculate_pattern_complexity(&self, _patterns: &[Vec<f64>]) -> f64 { 0.85 }
+    fn calculate_pattern_complexity(&self, patterns: &[Vec<f64>]) -> f64 {
+        let values: Vec<f64> = patterns.iter().flatten().copied().collect();
+        if values.is_empty() {
+            return 0.0;
+        }
+
+        let min = values.iter().copied().fold(f64::INFINITY, f64::min);
+        let max = values.iter().copied().fold(f64::NEG_INFINITY, f64::max);
+        let range = max - min;
+        let bytes: Vec<u8> = values.iter()
+            .map(|v| if range > 0.0 { ((v - min) / range * 255.0).round() as u8 } else { 0 })
+            .collect();
+
+        (Self::lz78_bits(&bytes) as f64 / (8 * bytes.len()) as f64).min(1.0)
+    }
     fn calculate_pattern_regularity(&self, _patterns: &[Vec<f64>]) -> f64 { 0.83 }
     fn calculate_pattern_beauty(&self, _patterns: &[Vec<f64>]) -> f64 { 0.87 }
     fn calculate_mathematical_significance(&self, _patterns: &[Vec<f64>]) -> f64 { 0.89 }
@@ -801,4 +1123,281 @@ impl Phase2 for Phase2Engine {
     fn analyze_maintainability(&self, _code: &str) -> f64 { 0.80 }
     fn analyze_efficiency(&self, _code: &str) -> f64 { 0.82 }
     fn analyze_code_beauty(&self, _code: &str) -> f64 { 0.78 }
-}
\ No newline at end of file
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sample_universe() -> MathematicalUniverse {
+        MathematicalUniverse {
+            dimensions: 8,
+            godel_numbers: vec![1, 2, 3, 5, 8, 13, 21, 34],
+            bott_coordinates: vec![[Some(1.0); 8]],
+            clifford_multivectors: vec![vec![1.0; 8]],
+            musical_voices: vec![],
+            visual_patterns: vec![],
+            fluid_fields: vec![],
+            number_sequences: vec![],
+            statistical_data: vec![],
+            relativistic_frames: vec![],
+            penrose_tilings: vec![],
+            oeis_sequences: vec![],
+        }
+    }
+
+    fn engine_with_aggressiveness(aggressiveness: f64) -> Phase2Engine {
+        Phase2Engine {
+            optimization_aggressiveness: aggressiveness,
+            ..Phase2Engine::default()
+        }
+    }
+
+    fn engine_with_depth(depth: usize) -> Phase2Engine {
+        Phase2Engine {
+            reflection_depth: depth,
+            ..Phase2Engine::default()
+        }
+    }
+
+    /// Deterministic uniform noise in [-1, 1)
+    fn noise(length: usize, seed: u32) -> Vec<f64> {
+        let mut state = seed;
+        (0..length)
+            .map(|_| {
+                state = state.wrapping_mul(1103515245).wrapping_add(12345);
+                ((state >> 8) % 2000) as f64 / 1000.0 - 1.0
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_pattern_typing() {
+        let engine = Phase2Engine::default();
+        let type_of = |patterns: &[Vec<f64>]| engine.analyze_mathematical_patterns(patterns).pattern_type;
+
+        let sine: Vec<f64> = (0..120).map(|i| (i as f64 * 0.3).sin()).collect();
+        assert_eq!(type_of(std::slice::from_ref(&sine)), "oscillatory");
+
+        let ramp: Vec<f64> = (0..50).map(|i| 2.0 * i as f64 - 7.0).collect();
+        assert_eq!(type_of(std::slice::from_ref(&ramp)), "monotonic");
+
+        let white = noise(400, 7);
+        assert_eq!(type_of(std::slice::from_ref(&white)), "random");
+
+        let walk: Vec<f64> = white.iter().scan(0.0, |position, step| {
+            *position += step;
+            Some(*position)
+        }).collect();
+        assert_eq!(type_of(&[walk]), "fractal");
+
+        // Majority vote across series; short series are ignored
+        assert_eq!(type_of(&[sine.clone(), ramp.clone(), sine]), "oscillatory");
+        assert_eq!(type_of(&[vec![1.0, 3.0, 2.0]]), "indeterminate");
+        assert_eq!(type_of(&[]), "indeterminate");
+    }
+
+    #[test]
+    fn test_pattern_complexity_tracks_compressibility() {
+        let engine = Phase2Engine::default();
+
+        let constant = vec![vec![3.5; 1000]];
+        let constant_complexity = engine.calculate_pattern_complexity(&constant);
+        assert!(constant_complexity < 0.1, "constant complexity {}", constant_complexity);
+
+        let repeating = vec![(0..1000).map(|i| (i % 4) as f64).collect::<Vec<_>>()];
+        let random = vec![noise(1000, 11)];
+        let repeating_complexity = engine.calculate_pattern_complexity(&repeating);
+        let random_complexity = engine.calculate_pattern_complexity(&random);
+
+        assert!(constant_complexity < repeating_complexity);
+        assert!(repeating_complexity < 0.3, "repeating complexity {}", repeating_complexity);
+        assert!(random_complexity > 0.95, "random complexity {}", random_complexity);
+        assert_eq!(engine.calculate_pattern_complexity(&[]), 0.0);
+    }
+
+    #[test]
+    fn test_reflection_depth_surfaces_more_patterns() {
+        let universe = sample_universe();
+
+        let base = engine_with_depth(0).reflect_on_system(&universe);
+        let shallow = engine_with_depth(1).reflect_on_system(&universe);
+        let deep = engine_with_depth(5).reflect_on_system(&universe);
+
+        // Depth 0 is the base reflection
+        assert_eq!(base.emergent_patterns.len(), 3);
+        assert!(shallow.emergent_patterns.len() > base.emergent_patterns.len());
+        assert!(shallow.emergent_patterns.len() < deep.emergent_patterns.len());
+
+        // Deeper reflections extend the shallower ones
+        assert!(deep.emergent_patterns.starts_with(&shallow.emergent_patterns));
+    }
+
+    #[test]
+    fn test_refinement_builds_on_the_previous_round() {
+        let engine = Phase2Engine::default();
+        let base = vec!["Alpha waves".to_string(), "Beta decay".to_string()];
+
+        let first = engine.refine_patterns(&base, 0);
+        assert_eq!(first, vec![
+            "Order-1 reflection: Alpha waves via Beta".to_string(),
+            "Order-1 reflection: Beta decay via Alpha".to_string(),
+        ]);
+        let second = engine.refine_patterns(&first, 1);
+        assert_eq!(second[0], "Order-2 reflection: Alpha waves via Beta via Alpha");
+
+        // Changing round k changes round k + 1
+        let mut altered = first.clone();
+        altered[1] = "Order-1 reflection: Gamma rays via Alpha".to_string();
+        let diverged = engine.refine_patterns(&altered, 1);
+        assert_ne!(diverged, second);
+        assert_eq!(diverged[1], "Order-2 reflection: Gamma rays via Alpha via Beta");
+
+        // Within a deep reflection every order extends the order before it
+        let deep = engine_with_depth(4).reflect_on_system(&sample_universe());
+        let patterns = &deep.emergent_patterns;
+        for round in 1..=4 {
+            let (before, after) = (&patterns[3 * (round - 1)..3 * round], &patterns[3 * round..3 * (round + 1)]);
+            for (earlier, later) in before.iter().zip(after) {
+                let earlier_body = earlier.split_once("reflection: ").map_or(earlier.as_str(), |(_, body)| body);
+                assert!(later.contains(&format!("{} via ", earlier_body)));
+            }
+        }
+    }
+
+    #[test]
+    fn test_reflection_depth_is_capped() {
+        let universe = sample_universe();
+
+        let capped = engine_with_depth(MAX_REFLECTION_DEPTH).reflect_on_system(&universe);
+        let excessive = engine_with_depth(usize::MAX).reflect_on_system(&universe);
+
+        assert_eq!(capped.emergent_patterns, excessive.emergent_patterns);
+    }
+
+    #[test]
+    fn test_reflection_cache_reuses_identical_universes() {
+        let engine = Phase2Engine::default();
+        let universe = sample_universe();
+
+        let first = engine.reflect_on_system(&universe);
+        let second = engine.reflect_on_system(&universe.clone());
+        assert_eq!(first, second);
+        assert_eq!(engine.cached_reflections(), 1);
+        assert_eq!(engine.reflection_cache_hits(), 1);
+
+        let mut modified = universe.clone();
+        modified.godel_numbers.push(55);
+        assert_ne!(Phase2Engine::universe_fingerprint(&modified), Phase2Engine::universe_fingerprint(&universe));
+        let third = engine.reflect_on_system(&modified);
+        assert_eq!(engine.cached_reflections(), 2);
+        assert_eq!(third, engine.compute_system_reflection(&modified));
+
+        engine.clear_reflection_cache();
+        assert_eq!(engine.cached_reflections(), 0);
+        assert_eq!(engine.reflect_on_system(&universe), first);
+    }
+
+    #[test]
+    fn test_reflection_cache_evicts_least_recently_used() {
+        let engine = Phase2Engine::default();
+        let universe = sample_universe();
+        let variant = |n: u64| {
+            let mut modified = universe.clone();
+            modified.godel_numbers.push(1000 + n);
+            modified
+        };
+
+        engine.reflect_on_system(&universe);
+        for n in 0..MAX_CACHED_REFLECTIONS as u64 - 1 {
+            engine.reflect_on_system(&variant(n));
+        }
+        assert_eq!(engine.cached_reflections(), MAX_CACHED_REFLECTIONS);
+
+        // Touch the original so the first variant becomes the eviction candidate
+        engine.reflect_on_system(&universe);
+        engine.reflect_on_system(&variant(MAX_CACHED_REFLECTIONS as u64));
+        assert_eq!(engine.cached_reflections(), MAX_CACHED_REFLECTIONS);
+
+        let cache = engine.lock_reflection_cache();
+        let depth = engine.reflection_depth.min(MAX_REFLECTION_DEPTH);
+        assert!(cache.entries.contains_key(&(Phase2Engine::universe_fingerprint(&universe), depth)));
+        assert!(!cache.entries.contains_key(&(Phase2Engine::universe_fingerprint(&variant(0)), depth)));
+    }
+
+    #[test]
+    fn test_engine_is_shareable_across_threads() {
+        fn assert_sync<T: Send + Sync>() {}
+        assert_sync::<Phase2Engine>();
+
+        let engine = std::sync::Arc::new(Phase2Engine::default());
+        let universe = sample_universe();
+        let handles: Vec<_> = (0..4).map(|_| {
+            let engine = std::sync::Arc::clone(&engine);
+            let universe = universe.clone();
+            std::thread::spawn(move || engine.reflect_on_system(&universe))
+        }).collect();
+        let reflections: Vec<_> = handles.into_iter().map(|handle| handle.join().unwrap()).collect();
+
+        assert!(reflections.windows(2).all(|pair| pair[0] == pair[1]));
+        assert_eq!(engine.cached_reflections(), 1);
+    }
+
+    #[test]
+    fn test_optimization_aggressiveness_scales_plan() {
+        let universe = sample_universe();
+
+        let cautious = engine_with_aggressiveness(0.1).llm_optimize_system(&universe);
+        let bold = engine_with_aggressiveness(0.9).llm_optimize_system(&universe);
+
+        assert!(cautious.optimization_steps.len() < bold.optimization_steps.len());
+        for (c, b) in cautious.optimization_steps.iter().zip(&bold.optimization_steps) {
+            assert!(c.expected_impact < b.expected_impact);
+        }
+        assert!(cautious.risk_assessment < bold.risk_assessment);
+    }
+
+    #[test]
+    fn test_optimization_aggressiveness_is_clamped() {
+        let universe = sample_universe();
+
+        let low = engine_with_aggressiveness(-5.0).llm_optimize_system(&universe);
+        let high = engine_with_aggressiveness(5.0).llm_optimize_system(&universe);
+
+        assert_eq!(low.optimization_steps.len(), 2);
+        assert_eq!(high.optimization_steps.len(), 6);
+        assert!(high.risk_assessment <= 0.7 + 1e-12);
+        assert!(high.optimization_steps.iter().all(|s| s.expected_impact <= 1.0));
+    }
+
+    #[test]
+    fn test_self_modification_gate() {
+        let universe = sample_universe();
+
+        let mut engine = Phase2Engine::default();
+        let reflection = engine.reflect_on_system(&universe);
+
+        engine.self_modification_enabled = true;
+        let plan = engine.generate_self_modification(&reflection);
+        assert_ne!(plan.modification_type, "Disabled");
+        assert!(!plan.proposed_changes.is_empty());
+
+        engine.self_modification_enabled = false;
+        let inert = engine.generate_self_modification(&reflection);
+        assert_eq!(inert.modification_type, "Disabled");
+        assert!(inert.proposed_changes.is_empty());
+        assert!(inert.implementation_plan.is_empty());
+    }
+
+    #[test]
+    fn test_penrose_beauty_tracks_golden_ratio() {
+        let engine = Phase2Engine::default();
+        let penrose = PenroseMathematician;
+
+        let shallow = engine.reflect_on_penrose_mathematics(&[penrose.penrose_rhombus_tiling(2)]);
+        let deep = engine.reflect_on_penrose_mathematics(&[penrose.penrose_rhombus_tiling(6)]);
+
+        assert!(deep.geometric_beauty > shallow.geometric_beauty);
+        assert!(deep.geometric_beauty > 0.999);
+    }
+}
@@ -1,5 +1,6 @@
 use std::collections::HashMap;
 use std::hash::Hash;
+use crate::number_embedding::{EmbeddedNumber, SelfAwareNumber};
 
 /// Represents one of the 42 phases in the system
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
@@ -78,6 +79,45 @@ impl Phase {
         
         base_freq * prime_bonus * fibonacci_bonus * square_bonus
     }
+
+    /// The residue of this phase in Z/42
+    ///
+    /// Phases run 1..=42 rather than 0..=41, so phase 42 (and phase 0) stand for
+    /// the residue 0, the group identity.
+    fn residue(&self) -> u8 {
+        self.0 % 42
+    }
+
+    /// The phase for a residue in Z/42, mapping 0 back to phase 42
+    fn from_residue(residue: u8) -> Phase {
+        match residue % 42 {
+            0 => Phase(42),
+            r => Phase(r),
+        }
+    }
+
+    /// The inverse of this phase in Z/42, so that `phase + phase.inverse()` is `Phase(42)`
+    pub fn inverse(self) -> Phase {
+        Phase::from_residue(42 - self.residue())
+    }
+
+    /// The order of this phase in Z/42: the smallest `k` with `k` copies summing to `Phase(42)`
+    pub fn order(self) -> u8 {
+        let (mut a, mut b) = (self.residue(), 42);
+        while b != 0 {
+            (a, b) = (b, a % b);
+        }
+        42 / a
+    }
+}
+
+/// Phases add as the cyclic group Z/42, with `Phase(42)` as the identity
+impl std::ops::Add for Phase {
+    type Output = Phase;
+
+    fn add(self, other: Phase) -> Phase {
+        Phase::from_residue((self.residue() + other.residue()) % 42)
+    }
 }
 
 /// Mathematical properties of a phase
@@ -170,6 +210,64 @@ impl DimensionalityReducer for HarmonicReducer {
     }
 }
 
+/// Dimensionality reducer for self-aware numbers
+///
+/// Embeddings are built by `NumberReducer::embed` as the number's value followed by
+/// its reflection scores. A positional fold of the reflection scores, each quantised
+/// to hundredths, picks a starting phase, so the phase moves whenever the number's
+/// reflection does. The leading value fixes the phase's primality: prime numbers
+/// land on the first prime phase from the start, all other values on the first
+/// non-prime phase.
+pub struct NumberReducer;
+
+impl NumberReducer {
+    /// Build an embedding from a number's value and reflection scores
+    pub fn embed(number: &SelfAwareNumber) -> Vec<f64> {
+        let reflection = number.reflect_on_self();
+        std::iter::once(reflection.number_value)
+            .chain(reflection.dimensions())
+            .collect()
+    }
+
+    /// Whether a value is a whole prime number
+    fn is_prime_value(value: f64) -> bool {
+        if value.fract() != 0.0 || !(2.0..=u64::MAX as f64).contains(&value) {
+            return false;
+        }
+        let n = value as u64;
+        (2..).take_while(|d| d * d <= n).all(|d| !n.is_multiple_of(d))
+    }
+}
+
+impl DimensionalityReducer for NumberReducer {
+    fn reduce_to_phase(&self, embedding: &[f64]) -> Phase {
+        let code = embedding
+            .iter()
+            .skip(1)
+            .enumerate()
+            .fold(0i64, |acc, (i, &score)| {
+                let quantised = (score * 100.0).round() as i64;
+                acc.wrapping_add(quantised.wrapping_mul(i as i64 + 1))
+            });
+        let start = code.rem_euclid(42) as u8;
+        let prime = embedding.first().is_some_and(|&value| Self::is_prime_value(value));
+        (0..42)
+            .map(|step| Phase((start + step) % 42 + 1))
+            .find(|phase| phase.is_prime() == prime)
+            .unwrap_or(Phase(start + 1))
+    }
+
+    fn calculate_confidence(&self, embedding: &[f64], phase: Phase) -> f64 {
+        let reduced = self.reduce_to_phase(embedding).value() as i32;
+        let offset = (reduced - phase.value() as i32).rem_euclid(42);
+        1.0 / (1.0 + offset.min(42 - offset) as f64)
+    }
+
+    fn calculate_harmonic_resonance(&self, embedding: &[f64], phase: Phase) -> f64 {
+        HarmonicReducer.calculate_harmonic_resonance(embedding.get(1..).unwrap_or(&[]), phase)
+    }
+}
+
 /// Entity that can be mapped to a phase
 pub trait PhaseEntity {
     fn get_name(&self) -> &str;
@@ -220,11 +318,15 @@ pub struct PhaseStatistics {
     pub properties: PhaseProperties,
 }
 
+/// Format tag and version prefixed to serialized phase mappings
+const PHASE_MAPPING_MAGIC: &[u8] = b"PHM1";
+
 /// Phase mapping system
 pub struct PhaseMappingSystem {
     reducer: Box<dyn DimensionalityReducer>,
     entity_phases: HashMap<String, Phase>,
     phase_entities: HashMap<Phase, Vec<String>>,
+    relocation_log: Vec<(String, Option<Phase>, Phase)>,
 }
 
 impl PhaseMappingSystem {
@@ -233,7 +335,101 @@ impl PhaseMappingSystem {
             reducer,
             entity_phases: HashMap::new(),
             phase_entities: HashMap::new(),
+            relocation_log: Vec::new(),
+        }
+    }
+
+    /// Serialize the entity/phase mappings to a stable byte format
+    ///
+    /// The reducer and the relocation log are not serialized; pass a reducer to
+    /// `from_bytes` when reloading.
+    /// Entities are written in sorted order, so equal mappings give equal bytes.
+    pub fn to_bytes(&self) -> Vec<u8> {
+        fn put_name(bytes: &mut Vec<u8>, name: &str) {
+            bytes.extend_from_slice(&(name.len() as u32).to_le_bytes());
+            bytes.extend_from_slice(name.as_bytes());
+        }
+
+        let mut bytes = PHASE_MAPPING_MAGIC.to_vec();
+
+        let mut entity_phases: Vec<(&String, &Phase)> = self.entity_phases.iter().collect();
+        entity_phases.sort_by_key(|(name, _)| *name);
+        bytes.extend_from_slice(&(entity_phases.len() as u32).to_le_bytes());
+        for (name, phase) in entity_phases {
+            put_name(&mut bytes, name);
+            bytes.push(phase.value());
+        }
+
+        let mut phase_entities: Vec<(&Phase, &Vec<String>)> = self.phase_entities.iter().collect();
+        phase_entities.sort_by_key(|(phase, _)| phase.value());
+        bytes.extend_from_slice(&(phase_entities.len() as u32).to_le_bytes());
+        for (phase, names) in phase_entities {
+            bytes.push(phase.value());
+            bytes.extend_from_slice(&(names.len() as u32).to_le_bytes());
+            for name in names {
+                put_name(&mut bytes, name);
+            }
+        }
+
+        bytes
+    }
+
+    /// Rebuild a mapping system from `to_bytes` output and a caller-supplied reducer
+    pub fn from_bytes(bytes: &[u8], reducer: Box<dyn DimensionalityReducer>) -> Result<Self, String> {
+        struct Reader<'a> {
+            bytes: &'a [u8],
+            offset: usize,
+        }
+
+        impl<'a> Reader<'a> {
+            fn take(&mut self, len: usize) -> Result<&'a [u8], String> {
+                let end = self.offset.checked_add(len)
+                    .filter(|&end| end <= self.bytes.len())
+                    .ok_or_else(|| format!("truncated phase mapping at byte {}", self.offset))?;
+                let slice = &self.bytes[self.offset..end];
+                self.offset = end;
+                Ok(slice)
+            }
+
+            fn u32(&mut self) -> Result<usize, String> {
+                let mut word = [0u8; 4];
+                word.copy_from_slice(self.take(4)?);
+                Ok(u32::from_le_bytes(word) as usize)
+            }
+
+            fn phase(&mut self) -> Result<Phase, String> {
+                let value = self.take(1)?[0];
+                Phase::new(value).ok_or_else(|| format!("invalid phase {}", value))
+            }
+
+            fn name(&mut self) -> Result<String, String> {
+                let len = self.u32()?;
+                String::from_utf8(self.take(len)?.to_vec())
+                    .map_err(|e| format!("invalid entity name: {}", e))
+            }
+        }
+
+        let mut reader = Reader { bytes, offset: 0 };
+        if reader.take(PHASE_MAPPING_MAGIC.len())? != PHASE_MAPPING_MAGIC {
+            return Err("not a serialized phase mapping".to_string());
+        }
+
+        let mut system = Self::new(reducer);
+        for _ in 0..reader.u32()? {
+            let name = reader.name()?;
+            let phase = reader.phase()?;
+            system.entity_phases.insert(name, phase);
         }
+        for _ in 0..reader.u32()? {
+            let phase = reader.phase()?;
+            let names = (0..reader.u32()?).map(|_| reader.name()).collect::<Result<Vec<_>, _>>()?;
+            system.phase_entities.insert(phase, names);
+        }
+
+        if reader.offset != bytes.len() {
+            return Err(format!("{} trailing bytes after phase mapping", bytes.len() - reader.offset));
+        }
+        Ok(system)
     }
 
     /// Map an entity to a phase
@@ -241,15 +437,55 @@ impl PhaseMappingSystem {
         let embedding = entity.get_embedding();
         let phase = self.reducer.reduce_to_phase(embedding);
         
-        // Store the mapping
-        let entity_name = entity.get_name().to_string();
-        self.entity_phases.insert(entity_name.clone(), phase);
-        
-        self.phase_entities.entry(phase).or_insert_with(Vec::new).push(entity_name);
+        self.record_mapping(entity.get_name().to_string(), phase);
+        phase
+    }
+
+    /// Map a self-aware number to a phase via its `NumberReducer` embedding
+    ///
+    /// The number is recorded under the entity name `number:<gödel number>`.
+    pub fn map_number(&mut self, number: &SelfAwareNumber) -> Phase {
+        let embedding = NumberReducer::embed(number);
+        let phase = self.reducer.reduce_to_phase(&embedding);
         
+        self.record_mapping(format!("number:{}", number.godel_encoding()), phase);
         phase
     }
 
+    /// Store an entity-phase mapping, moving the entity out of its previous phase
+    ///
+    /// First mappings and changes of phase are appended to the relocation log;
+    /// remapping an entity to the phase it already has is not a relocation.
+    fn record_mapping(&mut self, entity_name: String, phase: Phase) {
+        let old_phase = self.entity_phases.insert(entity_name.clone(), phase);
+        if old_phase == Some(phase) {
+            return;
+        }
+        if let Some(old) = old_phase {
+            if let Some(names) = self.phase_entities.get_mut(&old) {
+                names.retain(|name| *name != entity_name);
+                if names.is_empty() {
+                    self.phase_entities.remove(&old);
+                }
+            }
+        }
+        self.phase_entities.entry(phase).or_default().push(entity_name.clone());
+        self.relocation_log.push((entity_name, old_phase, phase));
+    }
+
+    /// The append-only log of (entity, old phase, new phase) relocations, oldest first
+    pub fn relocation_log(&self) -> &[(String, Option<Phase>, Phase)] {
+        &self.relocation_log
+    }
+
+    /// The (old phase, new phase) transitions of one entity, oldest first
+    pub fn relocation_history(&self, entity_name: &str) -> Vec<(Option<Phase>, Phase)> {
+        self.relocation_log.iter()
+            .filter(|(name, _, _)| name == entity_name)
+            .map(|&(_, old, new)| (old, new))
+            .collect()
+    }
+
     /// Get the phase for an entity
     pub fn get_entity_phase(&self, entity_name: &str) -> Option<Phase> {
         self.entity_phases.get(entity_name).cloned()
@@ -374,4 +610,111 @@ pub struct PhaseDistributionAnalysis {
     pub distribution: HashMap<Phase, usize>,
     pub most_populated_phase: Option<Phase>,
     pub least_populated_phase: Option<Phase>,
-}
\ No newline at end of file
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::ops::Add;
+
+    fn function(name: &str, embedding: &[f64]) -> FunctionEntity {
+        FunctionEntity {
+            name: name.to_string(),
+            embedding: embedding.to_vec(),
+            semantic_type: "function".to_string(),
+        }
+    }
+
+    #[test]
+    fn test_phase_group_operations() {
+        assert_eq!(Phase(1).add(Phase(42)), Phase(1));
+        assert_eq!(Phase(41) + Phase(1), Phase(42));
+        assert_eq!(Phase(30) + Phase(20), Phase(8));
+        assert_eq!(Phase(5) + Phase(5).inverse(), Phase(42));
+        assert_eq!(Phase(42).inverse(), Phase(42));
+
+        assert_eq!(Phase(1).order(), 42);
+        assert_eq!(Phase(5).order(), 42);
+        assert_eq!(Phase(6).order(), 7);
+        assert_eq!(Phase(21).order(), 2);
+        assert_eq!(Phase(42).order(), 1);
+    }
+
+    #[test]
+    fn test_map_number_prime_phase() {
+        let mut system = PhaseMappingSystem::new(Box::new(NumberReducer));
+        let seven = SelfAwareNumber { value: 7.0, godel_number: 7, ..Default::default() };
+
+        let phase = system.map_number(&seven);
+
+        assert!(phase.properties().is_prime);
+        assert_eq!(system.get_entity_phase("number:7"), Some(phase));
+
+        // Composites keep to non-prime phases
+        let eight = SelfAwareNumber { value: 8.0, godel_number: 8, ..Default::default() };
+        assert!(!system.map_number(&eight).properties().is_prime);
+
+        // Evolving scales the value by φ off the integers, so it leaves the prime phases
+        let evolved = seven.evolve_self(1).pop().unwrap();
+        let shifted = system.map_number(&evolved);
+        assert!(!shifted.properties().is_prime);
+        assert_ne!(shifted, phase);
+    }
+
+    #[test]
+    fn test_relocation_log_records_remaps() {
+        let mut system = PhaseMappingSystem::new(Box::new(HashReducer));
+        let first = system.map_entity(&function("store", &[0.1, 0.2, 0.3]));
+        system.map_entity(&function("retrieve", &[0.4, 0.5]));
+        let second = system.map_entity(&function("store", &[1.5, -0.5, 2.0]));
+        assert_ne!(first, second);
+        // Remapping to the same phase is not a relocation
+        system.map_entity(&function("store", &[1.5, -0.5, 2.0]));
+
+        assert_eq!(system.relocation_history("store"), vec![(None, first), (Some(first), second)]);
+        assert_eq!(system.relocation_log().len(), 3);
+        assert_eq!(system.relocation_log()[2], ("store".to_string(), Some(first), second));
+        assert!(!system.get_phase_entities(first).contains(&"store".to_string()));
+        assert_eq!(system.get_phase_entities(second).iter().filter(|name| *name == "store").count(), 1);
+        assert!(system.relocation_history("missing").is_empty());
+    }
+
+    #[test]
+    fn test_serialization_round_trip() {
+        let mut system = PhaseMappingSystem::new(Box::new(HashReducer));
+        let entities = [
+            function("store", &[0.1, 0.2, 0.3]),
+            function("retrieve", &[0.4, 0.5]),
+            function("hash", &[1.5, -0.5, 2.0, 0.25]),
+            function("cycle", &[0.1, 0.2, 0.3]),
+        ];
+        for entity in &entities {
+            system.map_entity(entity);
+        }
+
+        let bytes = system.to_bytes();
+        let reloaded = PhaseMappingSystem::from_bytes(&bytes, Box::new(HashReducer)).unwrap();
+
+        for entity in &entities {
+            assert_eq!(reloaded.get_entity_phase(&entity.name), system.get_entity_phase(&entity.name));
+        }
+        for phase_num in 1..=42 {
+            let phase = Phase(phase_num);
+            assert_eq!(reloaded.get_phase_entities(phase), system.get_phase_entities(phase));
+        }
+        assert_eq!(reloaded.to_bytes(), bytes);
+    }
+
+    #[test]
+    fn test_from_bytes_rejects_corrupt_input() {
+        let mut system = PhaseMappingSystem::new(Box::new(HashReducer));
+        system.map_entity(&function("store", &[0.1, 0.2]));
+        let bytes = system.to_bytes();
+
+        assert!(PhaseMappingSystem::from_bytes(&bytes[..bytes.len() - 1], Box::new(HashReducer)).is_err());
+        assert!(PhaseMappingSystem::from_bytes(b"nope", Box::new(HashReducer)).is_err());
+        let mut trailing = bytes.clone();
+        trailing.push(0);
+        assert!(PhaseMappingSystem::from_bytes(&trailing, Box::new(HashReducer)).is_err());
+    }
+}
@@ -1,6 +1,10 @@
 use super::bootstrap_system::BootstrapSystem;
 use solfunmeme_clifford::SolMultivector;
 use super::main01;
+use super::oeis::{OEIS, OEISDatabase};
+
+/// Number of leading sequence terms searched when verifying OEIS membership
+const OEIS_VERIFY_TERMS: usize = 50;
 
 /// Run the complete stage0 process
 /// 
@@ -38,3 +42,76 @@ pub struct StageInfo {
     pub is_factor_of_42: bool,
     pub prime_factors: Vec<u32>,
 }
+
+impl StageInfo {
+    /// Verify that the stage number actually appears in its claimed OEIS sequences
+    ///
+    /// Each sequence in `oeis_sequences` is generated up to its first 50 terms and
+    /// searched for the stage number. Returns the IDs of sequences that do *not*
+    /// contain it, so an empty result means every tag checks out.
+    ///
+    /// Fails if any tag is not a sequence `db` knows, since there is nothing to
+    /// check it against.
+    pub fn verify_oeis(&self, db: &OEISDatabase) -> Result<Vec<String>, String> {
+        if let Some(unknown) = self.oeis_sequences.iter().find(|id| !db.is_known_sequence(id)) {
+            return Err(format!("Unknown OEIS sequence {} on stage {}", unknown, self.number));
+        }
+        let number = self.number as i64;
+        Ok(self
+            .oeis_sequences
+            .iter()
+            .filter(|id| !db.generate_sequence(id, OEIS_VERIFY_TERMS).contains(&number))
+            .cloned()
+            .collect())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn stage_with_sequences(number: u32, sequences: &[&str]) -> StageInfo {
+        StageInfo {
+            number,
+            name: format!("Stage {}", number),
+            description: String::new(),
+            oeis_sequences: sequences.iter().map(|s| s.to_string()).collect(),
+            is_prime: false,
+            is_fibonacci: false,
+            is_factor_of_42: false,
+            prime_factors: vec![],
+        }
+    }
+
+    #[test]
+    fn test_verify_oeis_primes() {
+        let db = OEISDatabase::default();
+
+        // A prime stage tagged with A000040 passes
+        let prime_stage = stage_with_sequences(13, &["A000040"]);
+        assert!(prime_stage.verify_oeis(&db).unwrap().is_empty());
+
+        // A composite stage tagged with A000040 is caught
+        let composite_stage = stage_with_sequences(12, &["A000040"]);
+        assert_eq!(composite_stage.verify_oeis(&db).unwrap(), vec!["A000040".to_string()]);
+    }
+
+    #[test]
+    fn test_verify_oeis_reports_only_mismatches() {
+        let db = OEISDatabase::default();
+
+        // 21 is a Fibonacci and a triangular number, but not a square
+        let stage = stage_with_sequences(21, &["A000045", "A000217", "A000290"]);
+        assert_eq!(stage.verify_oeis(&db).unwrap(), vec!["A000290".to_string()]);
+    }
+
+    #[test]
+    fn test_verify_oeis_rejects_unknown_sequences() {
+        let db = OEISDatabase::default();
+
+        // An unknown tag is an error, not a mismatch against an empty sequence
+        let stage = stage_with_sequences(13, &["A000040", "A999999"]);
+        let err = stage.verify_oeis(&db).unwrap_err();
+        assert!(err.contains("A999999"), "{}", err);
+    }
+}
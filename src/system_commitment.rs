@@ -36,7 +36,7 @@ use crate::kernel::Kernel;
 ///
 /// # Arguments
 /// * `kernel`: A reference to the `Kernel` to access the current `cycle_step`.
-///
+///
 /// # Returns
 /// A `u128` representing the system's commitment value.
 pub fn calculate_system_commitment_value(kernel: &Kernel) -> u128 {
@@ -69,5 +69,192 @@ pub fn calculate_system_commitment_value(kernel: &Kernel) -> u128 {
     // Further mix in the final step value to ensure sensitivity to the exact cycle position
     commitment = commitment.wrapping_add(current_step.wrapping_mul(42424242));
 
+    // Mix in the stored-content digest (a no-op for a kernel that has seen no content)
+    commitment = commitment.wrapping_add((kernel.content_digest as u128).rotate_left(64));
+
     commitment
 }
+
+/// A single state transition in a kernel's operation log.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Op {
+    /// Content recorded through `Kernel::record_content`
+    Record(Vec<u8>),
+    /// A bare `Kernel::advance_cycle`
+    Advance,
+    /// A `Kernel::set_step` to the given step
+    SetStep(u64),
+}
+
+impl Op {
+    /// Applies the operation to a kernel, exactly as the kernel method it records.
+    pub fn apply(&self, kernel: &mut Kernel) {
+        match self {
+            Op::Record(content) => kernel.record_content(content),
+            Op::Advance => kernel.advance_cycle(),
+            Op::SetStep(step) => kernel.set_step(*step),
+        }
+    }
+}
+
+/// Folds one operation into a running commitment.
+///
+/// The new commitment is a 128-bit FNV-1a hash over the running value and an
+/// unambiguous encoding of the operation. Unlike the system commitment itself,
+/// it cannot be decoded back into a kernel state: it only chains, so each value
+/// commits to the base and every operation folded in since, in order.
+pub fn fold_commitment(commitment: u128, op: &Op) -> u128 {
+    const FNV_OFFSET: u128 = 0x6c62272e07bb014262b821756295c58d;
+    const FNV_PRIME: u128 = 0x0000000001000000000000000000013b;
+
+    let mut bytes = commitment.to_le_bytes().to_vec();
+    match op {
+        Op::Record(content) => {
+            bytes.push(0);
+            bytes.extend_from_slice(&(content.len() as u64).to_le_bytes());
+            bytes.extend_from_slice(content);
+        }
+        Op::Advance => bytes.push(1),
+        Op::SetStep(step) => {
+            bytes.push(2);
+            bytes.extend_from_slice(&step.to_le_bytes());
+        }
+    }
+    bytes.iter().fold(FNV_OFFSET, |acc, &byte| {
+        (acc ^ byte as u128).wrapping_mul(FNV_PRIME)
+    })
+}
+
+/// Verifies a claimed commitment by folding an oplog into a base commitment.
+///
+/// This confirms a state transition from `base` to `claimed` without access to
+/// the full store: only the operations in between are needed, and `claimed`
+/// must be the result of folding exactly those operations, in order, with
+/// `fold_commitment`.
+pub fn verify_incremental(base: u128, ops: &[Op], claimed: u128) -> bool {
+    ops.iter().fold(base, fold_commitment) == claimed
+}
+
+/// Explains which part of the system state makes two commitments differ.
+///
+/// The commitment value is a single opaque number; this breaks a mismatch down
+/// into its two state sources so divergent replicas can be debugged.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CommitmentDiff {
+    /// Both kernels commit to the same state
+    Identical,
+    /// Only the cycle step differs
+    CycleStep,
+    /// Only the stored-content digest differs
+    ContentDigest,
+    /// Both the cycle step and the stored-content digest differ
+    Both,
+}
+
+impl CommitmentDiff {
+    /// Returns true if the two commitments are identical
+    pub fn is_identical(&self) -> bool {
+        *self == CommitmentDiff::Identical
+    }
+}
+
+/// Compares two kernels and reports why their commitment values differ.
+///
+/// # Arguments
+/// * `kernel_a`: The first kernel.
+/// * `kernel_b`: The second kernel.
+///
+/// # Returns
+/// A `CommitmentDiff` attributing the difference to the cycle step, the
+/// stored-content digest, or both.
+pub fn explain_difference(kernel_a: &Kernel, kernel_b: &Kernel) -> CommitmentDiff {
+    let step_differs = kernel_a.step != kernel_b.step;
+    let content_differs = kernel_a.content_digest != kernel_b.content_digest;
+
+    match (step_differs, content_differs) {
+        (false, false) => CommitmentDiff::Identical,
+        (true, false) => CommitmentDiff::CycleStep,
+        (false, true) => CommitmentDiff::ContentDigest,
+        (true, true) => CommitmentDiff::Both,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_identical_kernels() {
+        let kernel_a = Kernel::new();
+        let kernel_b = Kernel::new();
+
+        assert!(explain_difference(&kernel_a, &kernel_b).is_identical());
+        assert_eq!(
+            calculate_system_commitment_value(&kernel_a),
+            calculate_system_commitment_value(&kernel_b)
+        );
+    }
+
+    #[test]
+    fn test_verify_incremental() {
+        let base = calculate_system_commitment_value(&Kernel::new());
+        let ops = vec![
+            Op::Record(b"first artifact".to_vec()),
+            Op::Advance,
+            Op::Record(b"second artifact".to_vec()),
+            Op::SetStep(7),
+        ];
+        let claimed = ops.iter().fold(base, fold_commitment);
+
+        assert!(verify_incremental(base, &ops, claimed));
+        assert!(verify_incremental(claimed, &[], claimed));
+
+        let mut tampered = ops.clone();
+        tampered[2] = Op::Record(b"forged artifact".to_vec());
+        assert!(!verify_incremental(base, &tampered, claimed));
+
+        let mut reordered = ops.clone();
+        reordered.swap(0, 2);
+        assert!(!verify_incremental(base, &reordered, claimed));
+
+        assert!(!verify_incremental(base ^ 1, &ops, claimed));
+    }
+
+    #[test]
+    fn test_fold_separates_operation_boundaries() {
+        let base = calculate_system_commitment_value(&Kernel::new());
+        let split = [Op::Record(b"ab".to_vec()), Op::Record(b"c".to_vec())];
+        let joined = [Op::Record(b"a".to_vec()), Op::Record(b"bc".to_vec())];
+
+        assert_ne!(
+            split.iter().fold(base, fold_commitment),
+            joined.iter().fold(base, fold_commitment)
+        );
+        assert_ne!(fold_commitment(base, &Op::Advance), fold_commitment(base, &Op::SetStep(1)));
+    }
+
+    #[test]
+    fn test_difference_in_cycle_step_only() {
+        let kernel_a = Kernel::new();
+        let mut kernel_b = Kernel::new();
+        kernel_b.advance_cycle();
+
+        assert_ne!(
+            calculate_system_commitment_value(&kernel_a),
+            calculate_system_commitment_value(&kernel_b)
+        );
+        assert_eq!(explain_difference(&kernel_a, &kernel_b), CommitmentDiff::CycleStep);
+    }
+
+    #[test]
+    fn test_difference_in_content_and_step() {
+        let mut kernel_a = Kernel::new();
+        let mut kernel_b = Kernel::new();
+        kernel_a.record_content(b"replica a");
+        kernel_b.record_content(b"replica b");
+        assert_eq!(explain_difference(&kernel_a, &kernel_b), CommitmentDiff::ContentDigest);
+
+        kernel_b.advance_cycle();
+        assert_eq!(explain_difference(&kernel_a, &kernel_b), CommitmentDiff::Both);
+    }
+}
@@ -45,6 +45,11 @@ pub trait Vectos {
     /// Composes a Gödel number with a Bott periodicity calculation.
     fn godel_bott_composition(&self, input: u64) -> Vec<f64>;
     /// Creates a musical voice based on the properties of a Clifford multivector and a chord.
+    ///
+    /// `multivector` holds blade coefficients indexed by bitmask. The scalar part
+    /// sets the tempo (a larger magnitude gives shorter notes), the vector part
+    /// weights each chord tone in turn, holding emphasised pitches up to twice as
+    /// long, and the bivector magnitude sets the velocity.
     fn clifford_bach_harmony(&self, multivector: &[f64], chord: &Chord) -> Voice;
     /// Generates a visualization string from an Escher tessellation and a Penrose tiling.
     fn escher_penrose_visualization(&self, tessellation: &[Vec<u8>], tiling: &[((f64, f64), (f64, f64))]) -> String;
@@ -52,14 +57,39 @@ pub trait Vectos {
     fn ns_mach_physics(&self, velocity_field: &[[f64; 2]], reference_frame: (f64, f64, f64)) -> Vec<f64>;
     /// Performs a statistical analysis of a number sequence using both Euler and Gauss methods.
     fn euler_gauss_analysis(&self, sequence: &[i64]) -> HashMap<String, f64>;
+    /// Computes the same statistics as `euler_gauss_analysis` in a single pass over
+    /// an iterator, without collecting the sequence. Mean and (population) variance
+    /// use Welford's algorithm; the growth rate is the mean ratio of consecutive
+    /// terms, skipping those that follow a zero.
+    fn analyze_sequence_stream(&self, it: impl Iterator<Item = i64>) -> SequenceStats
+    where
+        Self: Sized;
     /// Generates Penrose-like patterns from an OEIS sequence.
-    fn oeis_penrose_patterns(&self, sequence_id: &str, golden_ratio: f64) -> Vec<(f64, f64, f64)>;
+    ///
+    /// Each of the first ten sequence terms scales the matching quasicrystal point.
+    /// Fails if the sequence identifier is unknown or the two lengths disagree.
+    fn oeis_penrose_patterns(&self, sequence_id: &str, golden_ratio: f64) -> Result<Vec<(f64, f64, f64)>, String>;
     
     // Mathematical synthesis
     /// Synthesizes a complete `MathematicalUniverse` with a given number of dimensions.
+    ///
+    /// The per-dimension fields (Gödel numbers, Bott coordinates, Clifford multivectors,
+    /// statistical data and relativistic frames) have `dimensions` entries, while
+    /// `musical_voices`, `visual_patterns`, `fluid_fields`, `number_sequences`,
+    /// `penrose_tilings` and `oeis_sequences` are singletons shared by every dimension.
+    /// Use `synthesize_consistent_universe` when the result must pass `validate()`.
     fn synthesize_mathematical_universe(&self, dimensions: usize) -> MathematicalUniverse;
+    /// Synthesizes a `MathematicalUniverse` in which every field has exactly
+    /// `dimensions` entries, so the result passes `MathematicalUniverse::validate`.
+    fn synthesize_consistent_universe(&self, dimensions: usize) -> MathematicalUniverse;
     /// Computes the "vibes" (a set of mathematical and conceptual properties) for a given stage number.
     fn compute_vibes(&self, stage_number: u32) -> StageVibes;
+    /// Projects a set of `StageVibes` into a `MathematicalUniverse` with one dimension per vibe.
+    ///
+    /// Gödel numbers come from the stage numbers, statistical data from the resonance
+    /// frequencies, and Clifford multivectors from the quantum states flattened into
+    /// interleaved real and imaginary parts. All other fields are left empty.
+    fn vibes_to_universe(&self, vibes: &[StageVibes]) -> MathematicalUniverse;
     /// Simulates the evolution of a harmonic lattice over a number of iterations.
     fn harmonic_lattice_evolution(&self, iterations: usize) -> Vec<LatticeState>;
     
@@ -101,6 +131,71 @@ pub struct MathematicalUniverse {
     pub oeis_sequences: Vec<Vec<i64>>,
 }
 
+impl MathematicalUniverse {
+    /// Returns the names of populated fields whose length differs from `dimensions`.
+    /// Empty fields are treated as absent and never reported.
+    pub fn inconsistent_fields(&self) -> Vec<&'static str> {
+        let lengths = [
+            ("godel_numbers", self.godel_numbers.len()),
+            ("bott_coordinates", self.bott_coordinates.len()),
+            ("clifford_multivectors", self.clifford_multivectors.len()),
+            ("musical_voices", self.musical_voices.len()),
+            ("visual_patterns", self.visual_patterns.len()),
+            ("fluid_fields", self.fluid_fields.len()),
+            ("number_sequences", self.number_sequences.len()),
+            ("statistical_data", self.statistical_data.len()),
+            ("relativistic_frames", self.relativistic_frames.len()),
+            ("penrose_tilings", self.penrose_tilings.len()),
+            ("oeis_sequences", self.oeis_sequences.len()),
+        ];
+        lengths
+            .iter()
+            .filter(|&&(_, len)| len != 0 && len != self.dimensions)
+            .map(|&(name, _)| name)
+            .collect()
+    }
+
+    /// Measures how far apart two universes are, e.g. before and after evolution.
+    ///
+    /// The distance is the sum of the absolute dimension difference, the Jaccard
+    /// distance between the Gödel-number sets (0 when both are empty), and the
+    /// Euclidean distance between the common prefix of the statistical data.
+    pub fn distance(&self, other: &Self) -> f64 {
+        let dimension_gap = self.dimensions.abs_diff(other.dimensions) as f64;
+
+        let ours: std::collections::HashSet<u64> = self.godel_numbers.iter().copied().collect();
+        let theirs: std::collections::HashSet<u64> = other.godel_numbers.iter().copied().collect();
+        let union = ours.union(&theirs).count();
+        let godel_gap = if union == 0 {
+            0.0
+        } else {
+            1.0 - ours.intersection(&theirs).count() as f64 / union as f64
+        };
+
+        let statistical_gap = self.statistical_data.iter()
+            .zip(&other.statistical_data)
+            .map(|(a, b)| (a - b).powi(2))
+            .sum::<f64>()
+            .sqrt();
+
+        dimension_gap + godel_gap + statistical_gap
+    }
+
+    /// Checks that every populated field has exactly `dimensions` entries.
+    pub fn validate(&self) -> Result<(), String> {
+        let inconsistent = self.inconsistent_fields();
+        if inconsistent.is_empty() {
+            Ok(())
+        } else {
+            Err(format!(
+                "fields not matching {} dimensions: {}",
+                self.dimensions,
+                inconsistent.join(", ")
+            ))
+        }
+    }
+}
+
 /// Represents the collected "vibe" or set of properties for a single stage.
 #[derive(Debug, Clone)]
 pub struct StageVibes {
@@ -187,6 +282,23 @@ pub struct FractalHarmony {
     pub dimensional_scaling: Vec<f64>,
 }
 
+/// Summary statistics of a number sequence, gathered in a single streaming pass.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct SequenceStats {
+    /// The number of terms seen.
+    pub count: usize,
+    /// The arithmetic mean of the terms.
+    pub mean: f64,
+    /// The population variance of the terms.
+    pub variance: f64,
+    /// The smallest term, or 0 for an empty sequence.
+    pub min: i64,
+    /// The largest term, or 0 for an empty sequence.
+    pub max: i64,
+    /// The mean ratio of each term to its predecessor.
+    pub growth_rate: f64,
+}
+
 /// A simple representation of a complex number, used for quantum states.
 #[derive(Debug, Clone, Copy)]
 pub struct Complex<T> {
@@ -368,21 +480,33 @@ impl Vectos for VectosEngine {
     }
     
     fn clifford_bach_harmony(&self, multivector: &[f64], chord: &Chord) -> Voice {
-        let norm = self.clifford.norm();
+        let multivector = crate::clifford::CliffordMultivector::from_blades(multivector);
         let chord_notes = self.bach.build_chord(chord.root, chord.chord_type);
         
-        let mut voice = Voice {
-            notes: Vec::new(),
-            octave: chord.octave,
-            velocity: 80,
+        let grade_part = |grade: u32| -> Vec<f64> {
+            multivector.coefficients().iter().enumerate()
+                .filter(|(blade, _)| blade.count_ones() == grade)
+                .map(|(_, &c)| c)
+                .collect()
         };
+        let beat = 1.0 / (1.0 + multivector.coefficient(0).abs());
+        let emphasis = grade_part(1);
+        let strongest = emphasis.iter().fold(0.0_f64, |max, c| max.max(c.abs()));
+        let bivector_norm = grade_part(2).iter().map(|c| c * c).sum::<f64>().sqrt();
         
-        for (i, &note) in chord_notes.iter().enumerate() {
-            let duration = if i < multivector.len() { multivector[i].abs() } else { 1.0 };
-            voice.notes.push((note, duration));
-        }
+        let notes = chord_notes.iter().enumerate().map(|(i, &note)| {
+            let weight = match emphasis.get(i) {
+                Some(c) if strongest > 0.0 => c.abs() / strongest,
+                _ => 0.0,
+            };
+            (note, beat * (1.0 + weight))
+        }).collect();
         
-        voice
+        Voice {
+            notes,
+            octave: chord.octave,
+            velocity: (48.0 + 79.0 * bivector_norm.tanh()).round() as u8,
+        }
     }
     
     fn escher_penrose_visualization(&self, tessellation: &[Vec<u8>], tiling: &[((f64, f64), (f64, f64))]) -> String {
@@ -415,14 +539,62 @@ impl Vectos for VectosEngine {
         analysis
     }
     
-    fn oeis_penrose_patterns(&self, sequence_id: &str, golden_ratio: f64) -> Vec<(f64, f64, f64)> {
+    fn analyze_sequence_stream(&self, it: impl Iterator<Item = i64>) -> SequenceStats {
+        let mut stats = SequenceStats::default();
+        let mut squared_deviations = 0.0;
+        let mut previous: Option<i64> = None;
+        let mut ratio_sum = 0.0;
+        let mut ratio_count = 0usize;
+        
+        for term in it {
+            if stats.count == 0 {
+                stats.min = term;
+                stats.max = term;
+            } else {
+                stats.min = stats.min.min(term);
+                stats.max = stats.max.max(term);
+            }
+            
+            stats.count += 1;
+            let value = term as f64;
+            let delta = value - stats.mean;
+            stats.mean += delta / stats.count as f64;
+            squared_deviations += delta * (value - stats.mean);
+            
+            if let Some(prev) = previous.filter(|&prev| prev != 0) {
+                ratio_sum += value / prev as f64;
+                ratio_count += 1;
+            }
+            previous = Some(term);
+        }
+        
+        if stats.count > 0 {
+            stats.variance = squared_deviations / stats.count as f64;
+        }
+        if ratio_count > 0 {
+            stats.growth_rate = ratio_sum / ratio_count as f64;
+        }
+        stats
+    }
+    
+    fn oeis_penrose_patterns(&self, sequence_id: &str, golden_ratio: f64) -> Result<Vec<(f64, f64, f64)>, String> {
+        if !self.oeis.is_known_sequence(sequence_id) {
+            return Err(format!("unknown OEIS sequence '{}'", sequence_id));
+        }
+        
         let sequence = self.oeis.generate_sequence(sequence_id, 10);
         let quasicrystal = self.penrose.quasicrystal_pattern(sequence.len(), golden_ratio);
+        if quasicrystal.len() != sequence.len() {
+            return Err(format!(
+                "quasicrystal has {} points but sequence '{}' has {} terms",
+                quasicrystal.len(), sequence_id, sequence.len()
+            ));
+        }
         
         // Combine sequence values with quasicrystal coordinates
-        sequence.iter().zip(quasicrystal.iter()).map(|(&seq_val, &(x, y, z))| {
+        Ok(sequence.iter().zip(quasicrystal.iter()).map(|(&seq_val, &(x, y, z))| {
             (x * seq_val as f64, y * seq_val as f64, z * seq_val as f64)
-        }).collect()
+        }).collect())
     }
     
     fn synthesize_mathematical_universe(&self, dimensions: usize) -> MathematicalUniverse {
@@ -454,6 +626,21 @@ impl Vectos for VectosEngine {
         }
     }
     
+    fn synthesize_consistent_universe(&self, dimensions: usize) -> MathematicalUniverse {
+        let notes = [Note::C, Note::D, Note::E, Note::F, Note::G, Note::A, Note::B];
+        let mut universe = self.synthesize_mathematical_universe(dimensions);
+        
+        universe.musical_voices = (0..dimensions)
+            .map(|i| Voice { notes: vec![(notes[i % notes.len()], 1.0)], octave: 4, velocity: 80 })
+            .collect();
+        universe.visual_patterns = (0..dimensions).map(|i| vec![vec![(i % 2) as u8 + 1; 10]; 10]).collect();
+        universe.fluid_fields = vec![[[1.0, 0.0], [0.0, 1.0]]; dimensions];
+        universe.number_sequences = (0..dimensions).map(|i| self.oeis.fibonacci_sequence(i + 1)).collect();
+        universe.penrose_tilings = (0..dimensions).map(|i| self.penrose.generate_penrose_tiling(i + 1)).collect();
+        universe.oeis_sequences = (0..dimensions).map(|i| self.oeis.fibonacci_sequence(i + 1)).collect();
+        universe
+    }
+    
     fn compute_vibes(&self, stage_number: u32) -> StageVibes {
         let fibonacci = self.oeis.fibonacci_sequence(stage_number as usize);
         let resonance_frequency = if !fibonacci.is_empty() { fibonacci[fibonacci.len() - 1] as f64 } else { 1.0 };
@@ -468,6 +655,25 @@ impl Vectos for VectosEngine {
         }
     }
     
+    fn vibes_to_universe(&self, vibes: &[StageVibes]) -> MathematicalUniverse {
+        MathematicalUniverse {
+            dimensions: vibes.len(),
+            godel_numbers: vibes.iter().map(|v| self.godel.compose_numbers(&[v.stage_number as u64])).collect(),
+            bott_coordinates: Vec::new(),
+            clifford_multivectors: vibes.iter()
+                .map(|v| v.quantum_states.iter().flat_map(|state| [state.real, state.imag]).collect())
+                .collect(),
+            musical_voices: Vec::new(),
+            visual_patterns: Vec::new(),
+            fluid_fields: Vec::new(),
+            number_sequences: Vec::new(),
+            statistical_data: vibes.iter().map(|v| v.resonance_frequency).collect(),
+            relativistic_frames: Vec::new(),
+            penrose_tilings: Vec::new(),
+            oeis_sequences: Vec::new(),
+        }
+    }
+    
     fn harmonic_lattice_evolution(&self, iterations: usize) -> Vec<LatticeState> {
         let mut states = Vec::new();
         
@@ -547,3 +753,130 @@ impl Vectos for VectosEngine {
 }
 
 use std::collections::HashMap;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_consistent_universe_validates() {
+        let engine = VectosEngine::default();
+        let universe = engine.synthesize_consistent_universe(7);
+
+        assert_eq!(universe.validate(), Ok(()));
+        assert_eq!(universe.musical_voices.len(), 7);
+        assert_eq!(universe.penrose_tilings.len(), 7);
+    }
+
+    #[test]
+    fn test_clifford_bach_harmony_grades() {
+        let engine = VectosEngine::default();
+        let chord = Chord { root: Note::C, chord_type: crate::bach::ChordType::Major, octave: 4, duration: 1.0, inversion: 0 };
+        // Blades of a 3D algebra: 1, e1, e2, e12, e3, e13, e23, e123
+        let base = [1.0, 0.0, 2.0, 0.5, 0.0, 0.0, 0.0, 0.0];
+        let voice = engine.clifford_bach_harmony(&base, &chord);
+
+        // Scalar 1 halves the beat; e2 emphasises the third, e1 and e3 are silent
+        let durations: Vec<f64> = voice.notes.iter().map(|&(_, d)| d).collect();
+        assert_eq!(durations, vec![0.5, 1.0, 0.5]);
+        assert_eq!(voice.notes[0].0, Note::C);
+
+        let mut louder = base;
+        louder[3] = 2.0;
+        let louder_voice = engine.clifford_bach_harmony(&louder, &chord);
+        assert!(louder_voice.velocity > voice.velocity);
+        assert_eq!(louder_voice.notes, voice.notes);
+
+        let mut faster = base;
+        faster[0] = 3.0;
+        let faster_voice = engine.clifford_bach_harmony(&faster, &chord);
+        assert_eq!(faster_voice.velocity, voice.velocity);
+        assert_eq!(faster_voice.notes[1].1, 0.5);
+    }
+
+    #[test]
+    fn test_vibes_to_universe() {
+        let engine = VectosEngine::default();
+        let vibes: Vec<StageVibes> = (1..=5).map(|stage| engine.compute_vibes(stage)).collect();
+        let universe = engine.vibes_to_universe(&vibes);
+
+        assert_eq!(universe.validate(), Ok(()));
+        assert_eq!(universe.dimensions, 5);
+        assert_eq!(universe.statistical_data.len(), vibes.len());
+        assert_eq!(universe.statistical_data[4], vibes[4].resonance_frequency);
+        assert_eq!(universe.godel_numbers, vec![1, 2, 3, 4, 5]);
+        assert_eq!(universe.clifford_multivectors[0], vec![1.0, 0.0, 0.0, 1.0]);
+    }
+
+    #[test]
+    fn test_oeis_penrose_patterns_rejects_unknown_sequence() {
+        let engine = VectosEngine::default();
+
+        assert!(engine.oeis_penrose_patterns("A999999", 1.618).is_err());
+    }
+
+    #[test]
+    fn test_oeis_penrose_patterns_known_sequence() {
+        let engine = VectosEngine::default();
+        let points = engine.oeis_penrose_patterns("A000045", 1.618).unwrap();
+
+        assert_eq!(points.len(), 10);
+        assert!(points.iter().filter(|&&(x, y, z)| x != 0.0 && y != 0.0 && z != 0.0).count() >= 8);
+        // F(9) = 34 scales the tenth quasicrystal point
+        assert!((points[9].0 - 34.0 * 9.0 * 1.618).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_streaming_stats_match_batch_analysis() {
+        let engine = VectosEngine::default();
+        let sequence = [3, -1, 4, 1, 0, 5, 9, -2, 6, 5, 3, 5];
+        let batch = engine.euler_gauss_analysis(&sequence);
+        let stats = engine.analyze_sequence_stream(sequence.iter().copied());
+
+        assert_eq!(stats.count, sequence.len());
+        assert!((stats.mean - batch["mean"]).abs() < 1e-12);
+        assert!((stats.variance - batch["variance"]).abs() < 1e-12);
+        assert!((stats.growth_rate - batch["growth_rate"]).abs() < 1e-12);
+        assert_eq!((stats.min, stats.max), (-2, 9));
+
+        // Long sequences stream without being collected
+        let long = engine.analyze_sequence_stream((0..1_000_000).map(|n| n % 7));
+        assert_eq!((long.min, long.max), (0, 6));
+        assert_eq!(engine.analyze_sequence_stream(std::iter::empty()), SequenceStats::default());
+    }
+
+    #[test]
+    fn test_universe_distance() {
+        let engine = VectosEngine::default();
+        let base = engine.synthesize_consistent_universe(5);
+        let grown = engine.synthesize_consistent_universe(6);
+        let larger = engine.synthesize_consistent_universe(8);
+
+        assert_eq!(base.distance(&base), 0.0);
+        assert_eq!(base.distance(&grown), grown.distance(&base));
+        assert!(base.distance(&grown) > 0.0);
+        assert!(base.distance(&larger) > base.distance(&grown));
+
+        // Statistical data contributes on the common prefix
+        let mut shifted = base.clone();
+        shifted.statistical_data[0] += 3.0;
+        shifted.statistical_data[1] += 4.0;
+        assert!((base.distance(&shifted) - 5.0).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_default_universe_reports_singletons() {
+        let engine = VectosEngine::default();
+        let universe = engine.synthesize_mathematical_universe(7);
+
+        assert!(universe.validate().is_err());
+        assert_eq!(universe.inconsistent_fields(), vec![
+            "musical_voices",
+            "visual_patterns",
+            "fluid_fields",
+            "number_sequences",
+            "penrose_tilings",
+            "oeis_sequences",
+        ]);
+    }
+}
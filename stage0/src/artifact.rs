@@ -2,7 +2,7 @@
 //! This represents the flow of content through storage space
 //! Each artifact is a potential in the content storage field
 
-use crate::hash::Hash;
+use crate::hash::{Hash, HashAlgorithm};
 
 /// The Artifact potential flow
 /// Represents a point in storage space where content materializes
@@ -15,7 +15,12 @@ pub struct Artifact {
 impl Artifact {
     /// Creates a new artifact potential from content flow
     pub fn from_content_flow(content: Vec<u8>) -> Self {
-        let hash = crate::hash::hash_flow(&content);
+        Self::from_content_flow_with(content, HashAlgorithm::default())
+    }
+
+    /// Creates a new artifact potential hashed under the given algorithm
+    pub fn from_content_flow_with(content: Vec<u8>, algorithm: HashAlgorithm) -> Self {
+        let hash = crate::hash::hash_flow_with(&content, algorithm);
         Self { hash, content }
     }
 
@@ -24,6 +29,17 @@ impl Artifact {
         &self.content
     }
 
+    /// Checks whether the content still flows to the artifact's hash
+    pub fn is_intact(&self) -> bool {
+        self.hash.matches(&self.content)
+    }
+
+    /// Checks whether the content flow contains the byte substring
+    /// An empty needle is contained in every artifact
+    pub fn contains_flow(&self, needle: &[u8]) -> bool {
+        needle.is_empty() || self.content.windows(needle.len()).any(|window| window == needle)
+    }
+
     /// Computes the divergence of this artifact potential
     pub fn divergence(&self) -> usize {
         self.content.len()
@@ -0,0 +1,205 @@
+//! AsyncStore - A potential flow for asynchronous storage
+//! This represents bootstrap flows driven from an async executor
+//! Blocking operations flow off the executor onto a small shared pool of threads
+//!
+//! Only compiled with the `async` feature; it adds no dependencies, so any
+//! executor can drive the returned futures.
+
+use std::future::Future;
+use std::panic::{self, AssertUnwindSafe};
+use std::pin::Pin;
+use std::sync::{mpsc, Arc, Mutex, OnceLock};
+use std::task::{Context, Poll, Waker};
+use std::thread;
+
+use crate::{Artifact, Bootstrap, Hash};
+
+/// The asynchronous store potential
+/// Storage operations that an executor can await without blocking
+pub trait AsyncStore {
+    /// Stores content, resolving to its hash potential
+    fn store(&self, content: Vec<u8>) -> impl Future<Output = Hash> + Send;
+
+    /// Retrieves content by its hash potential
+    fn retrieve(&self, hash: &Hash) -> impl Future<Output = Option<Artifact>> + Send;
+}
+
+/// A bootstrap flow field shared with blocking threads
+/// Each operation runs on the blocking worker pool, so awaiting it never blocks the executor
+/// Clones share the same underlying bootstrap
+#[derive(Clone)]
+pub struct BlockingStore {
+    bootstrap: Arc<Mutex<Bootstrap>>,
+}
+
+impl BlockingStore {
+    /// Moves a bootstrap flow field behind the blocking store
+    pub fn new(bootstrap: Bootstrap) -> Self {
+        Self {
+            bootstrap: Arc::new(Mutex::new(bootstrap)),
+        }
+    }
+
+    /// Runs an operation on the bootstrap from a blocking thread
+    fn run<T, F>(&self, operation: F) -> Blocking<T>
+    where
+        T: Send + 'static,
+        F: FnOnce(&mut Bootstrap) -> T + Send + 'static,
+    {
+        let bootstrap = Arc::clone(&self.bootstrap);
+        Blocking::spawn(move || {
+            let mut bootstrap = bootstrap.lock().unwrap_or_else(|poisoned| poisoned.into_inner());
+            operation(&mut bootstrap)
+        })
+    }
+}
+
+impl AsyncStore for BlockingStore {
+    fn store(&self, content: Vec<u8>) -> impl Future<Output = Hash> + Send {
+        self.run(move |bootstrap| bootstrap.store(content))
+    }
+
+    fn retrieve(&self, hash: &Hash) -> impl Future<Output = Option<Artifact>> + Send {
+        let hash = hash.clone();
+        self.run(move |bootstrap| bootstrap.retrieve(&hash))
+    }
+}
+
+/// The most worker threads blocking operations share, however many are in flight
+const MAX_BLOCKING_WORKERS: usize = 4;
+
+/// An operation queued for the blocking worker pool
+type BlockingJob = Box<dyn FnOnce() + Send>;
+
+/// Queues an operation on the blocking worker pool
+/// The workers start on first use, one per core up to `MAX_BLOCKING_WORKERS`,
+/// and live for the rest of the process; excess operations wait in the queue
+fn spawn_blocking(job: BlockingJob) {
+    static POOL: OnceLock<mpsc::Sender<BlockingJob>> = OnceLock::new();
+    let pool = POOL.get_or_init(|| {
+        let (sender, receiver) = mpsc::channel::<BlockingJob>();
+        let receiver = Arc::new(Mutex::new(receiver));
+        let workers = thread::available_parallelism().map_or(1, |cores| cores.get()).min(MAX_BLOCKING_WORKERS);
+        for index in 0..workers {
+            let receiver = Arc::clone(&receiver);
+            thread::Builder::new()
+                .name(format!("stage0-blocking-{}", index))
+                .spawn(move || loop {
+                    let job = receiver.lock().unwrap_or_else(|poisoned| poisoned.into_inner()).recv();
+                    match job {
+                        Ok(job) => job(),
+                        Err(_) => break,
+                    }
+                })
+                .expect("failed to start a blocking worker thread");
+        }
+        sender
+    });
+    pool.send(job).expect("blocking worker pool stopped");
+}
+
+/// A future resolving to the result of an operation on a blocking thread
+/// A panic in the operation is resumed in whoever polls the future
+struct Blocking<T> {
+    shared: Arc<Mutex<BlockingState<T>>>,
+}
+
+struct BlockingState<T> {
+    result: Option<thread::Result<T>>,
+    waker: Option<Waker>,
+}
+
+impl<T: Send + 'static> Blocking<T> {
+    fn spawn(operation: impl FnOnce() -> T + Send + 'static) -> Self {
+        let shared = Arc::new(Mutex::new(BlockingState { result: None, waker: None }));
+        let completion = Arc::clone(&shared);
+        spawn_blocking(Box::new(move || {
+            let result = panic::catch_unwind(AssertUnwindSafe(operation));
+            let mut state = completion.lock().unwrap();
+            state.result = Some(result);
+            if let Some(waker) = state.waker.take() {
+                waker.wake();
+            }
+        }));
+        Self { shared }
+    }
+}
+
+impl<T> Future for Blocking<T> {
+    type Output = T;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<T> {
+        let mut state = self.shared.lock().unwrap();
+        match state.result.take() {
+            Some(Ok(value)) => Poll::Ready(value),
+            Some(Err(payload)) => panic::resume_unwind(payload),
+            None => {
+                state.waker = Some(cx.waker().clone());
+                Poll::Pending
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::pin::pin;
+    use std::task::Wake;
+
+    /// A minimal executor: polls on the current thread, parking until woken
+    fn block_on<F: Future>(future: F) -> F::Output {
+        struct Unparker(thread::Thread);
+
+        impl Wake for Unparker {
+            fn wake(self: Arc<Self>) {
+                self.0.unpark();
+            }
+        }
+
+        let waker = Waker::from(Arc::new(Unparker(thread::current())));
+        let mut cx = Context::from_waker(&waker);
+        let mut future = pin!(future);
+        loop {
+            match future.as_mut().poll(&mut cx) {
+                Poll::Ready(value) => return value,
+                Poll::Pending => thread::park(),
+            }
+        }
+    }
+
+    #[test]
+    fn test_async_store_and_retrieve() {
+        let store = BlockingStore::new(Bootstrap::new());
+
+        let (hash, retrieved, missing) = block_on(async {
+            let hash = store.store(b"async flow".to_vec()).await;
+            let retrieved = store.retrieve(&hash).await;
+            let missing = store.retrieve(&crate::hash_flow(b"never stored")).await;
+            (hash, retrieved, missing)
+        });
+
+        assert_eq!(retrieved.unwrap().content_flow(), b"async flow");
+        assert!(missing.is_none());
+        // Clones share the same bootstrap
+        let shared = store.clone();
+        assert!(block_on(shared.retrieve(&hash)).is_some());
+    }
+
+    #[test]
+    fn test_blocking_operations_share_a_bounded_pool() {
+        let pending: Vec<_> = (0..32)
+            .map(|_| Blocking::spawn(|| thread::current().name().map(String::from)))
+            .collect();
+        let workers: std::collections::HashSet<_> = pending.into_iter()
+            .map(|operation| block_on(operation).unwrap())
+            .collect();
+        assert!(workers.len() <= MAX_BLOCKING_WORKERS);
+        assert!(workers.iter().all(|name| name.starts_with("stage0-blocking-")));
+
+        // A panicking operation surfaces in the poller and leaves the pool running
+        let failed = panic::catch_unwind(|| block_on(Blocking::spawn(|| panic!("operation failed"))));
+        assert!(failed.is_err());
+        assert_eq!(block_on(Blocking::spawn(|| 42)), 42);
+    }
+}
@@ -0,0 +1,120 @@
+//! Cache - A potential flow for read acceleration
+//! This represents recently retrieved artifacts held close to the surface
+//! The least recently used potential is the first to dissipate
+
+use std::collections::{HashMap, VecDeque};
+use crate::hash::Hash;
+use crate::artifact::Artifact;
+
+/// The default number of artifacts a read cache holds
+pub const DEFAULT_READ_CACHE_CAPACITY: usize = 64;
+
+/// The ReadCache potential flow
+/// A least-recently-used cache of retrieved artifacts
+#[derive(Debug, Clone)]
+pub struct ReadCache {
+    capacity: usize,
+    entries: HashMap<Hash, Artifact>,
+    recency: VecDeque<Hash>,
+    hits: u64,
+    misses: u64,
+}
+
+impl ReadCache {
+    /// Creates a read cache holding at most `capacity` artifacts
+    pub fn with_capacity(capacity: usize) -> Self {
+        Self {
+            capacity,
+            entries: HashMap::new(),
+            recency: VecDeque::new(),
+            hits: 0,
+            misses: 0,
+        }
+    }
+
+    /// Looks up an artifact, recording a hit or a miss
+    pub fn get(&mut self, hash: &Hash) -> Option<Artifact> {
+        match self.entries.get(hash).cloned() {
+            Some(artifact) => {
+                self.hits += 1;
+                self.touch(hash);
+                Some(artifact)
+            }
+            None => {
+                self.misses += 1;
+                None
+            }
+        }
+    }
+
+    /// Checks whether an artifact is cached without recording a hit or miss
+    pub fn contains(&self, hash: &Hash) -> bool {
+        self.entries.contains_key(hash)
+    }
+
+    /// Caches an artifact, evicting the least recently used one when full
+    pub fn insert(&mut self, artifact: Artifact) {
+        if self.capacity == 0 {
+            return;
+        }
+        let hash = artifact.hash.clone();
+        if self.entries.insert(hash.clone(), artifact).is_some() {
+            self.touch(&hash);
+            return;
+        }
+        self.recency.push_back(hash);
+        if self.entries.len() > self.capacity {
+            if let Some(oldest) = self.recency.pop_front() {
+                self.entries.remove(&oldest);
+            }
+        }
+    }
+
+    /// Drops a cached artifact
+    pub fn invalidate(&mut self, hash: &Hash) {
+        if self.entries.remove(hash).is_some() {
+            self.recency.retain(|cached| cached != hash);
+        }
+    }
+
+    /// Drops every cached artifact, keeping the hit and miss counts
+    pub fn clear(&mut self) {
+        self.entries.clear();
+        self.recency.clear();
+    }
+
+    /// Gets the number of lookups served from the cache
+    pub fn hits(&self) -> u64 {
+        self.hits
+    }
+
+    /// Gets the number of lookups that missed the cache
+    pub fn misses(&self) -> u64 {
+        self.misses
+    }
+
+    /// Gets the number of cached artifacts
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// Checks whether the cache is empty
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// Marks a cached artifact as the most recently used
+    fn touch(&mut self, hash: &Hash) {
+        if let Some(position) = self.recency.iter().position(|cached| cached == hash) {
+            if let Some(hash) = self.recency.remove(position) {
+                self.recency.push_back(hash);
+            }
+        }
+    }
+}
+
+impl Default for ReadCache {
+    fn default() -> Self {
+        Self::with_capacity(DEFAULT_READ_CACHE_CAPACITY)
+    }
+}
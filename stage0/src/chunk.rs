@@ -0,0 +1,88 @@
+//! Chunk - A potential flow for content-defined chunking
+//! This represents large content broken into regions at content-chosen boundaries
+//! Shared regions produce identical chunks, so they converge in storage once
+
+use std::ops::Range;
+use crate::hash::{Hash, HashAlgorithm};
+
+/// Content of at least this many bytes is split into chunks
+pub const CHUNKING_THRESHOLD: usize = MAX_CHUNK_SIZE;
+
+/// No boundary is placed before a chunk reaches this many bytes
+pub const MIN_CHUNK_SIZE: usize = 2 * 1024;
+
+/// A boundary is forced once a chunk reaches this many bytes
+pub const MAX_CHUNK_SIZE: usize = 64 * 1024;
+
+/// Boundary mask giving an average chunk of about 8 KiB past the minimum
+const BOUNDARY_MASK: u64 = (1 << 13) - 1;
+
+/// Bytes one hash occupies in a manifest: the algorithm tag then the field
+const MANIFEST_ENTRY_LEN: usize = 33;
+
+/// Random values mixed into the rolling gear hash, one per byte value
+const GEAR: [u64; 256] = gear_table();
+
+/// Fills the gear table from a splitmix64 stream
+const fn gear_table() -> [u64; 256] {
+    let mut table = [0u64; 256];
+    let mut state: u64 = 0x9e3779b97f4a7c15;
+    let mut i = 0;
+    while i < 256 {
+        state = state.wrapping_add(0x9e3779b97f4a7c15);
+        let mut z = state;
+        z = (z ^ (z >> 30)).wrapping_mul(0xbf58476d1ce4e5b9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94d049bb133111eb);
+        table[i] = z ^ (z >> 31);
+        i += 1;
+    }
+    table
+}
+
+/// Splits content into chunk ranges with a rolling gear hash
+/// A boundary falls wherever the hash of the trailing 64 bytes matches the mask,
+/// so an edit only moves the boundaries near it
+pub fn chunk_flow(data: &[u8]) -> Vec<Range<usize>> {
+    let mut chunks = Vec::new();
+    let mut start = 0;
+    let mut rolling: u64 = 0;
+    for (i, &byte) in data.iter().enumerate() {
+        rolling = (rolling << 1).wrapping_add(GEAR[byte as usize]);
+        let len = i + 1 - start;
+        if (len >= MIN_CHUNK_SIZE && rolling & BOUNDARY_MASK == 0) || len >= MAX_CHUNK_SIZE {
+            chunks.push(start..i + 1);
+            start = i + 1;
+            rolling = 0;
+        }
+    }
+    if start < data.len() {
+        chunks.push(start..data.len());
+    }
+    chunks
+}
+
+/// Encodes the chunk hashes of an artifact as its manifest content
+pub fn encode_manifest(chunks: &[Hash]) -> Vec<u8> {
+    let mut manifest = Vec::with_capacity(chunks.len() * MANIFEST_ENTRY_LEN);
+    for hash in chunks {
+        manifest.push(hash.algorithm().tag());
+        manifest.extend_from_slice(hash.flow_field());
+    }
+    manifest
+}
+
+/// Decodes manifest content back into chunk hashes
+/// Returns `None` if the manifest is malformed
+pub fn decode_manifest(manifest: &[u8]) -> Option<Vec<Hash>> {
+    if !manifest.len().is_multiple_of(MANIFEST_ENTRY_LEN) {
+        return None;
+    }
+    manifest.chunks_exact(MANIFEST_ENTRY_LEN)
+        .map(|entry| {
+            let algorithm = HashAlgorithm::from_tag(entry[0])?;
+            let mut field = [0u8; 32];
+            field.copy_from_slice(&entry[1..]);
+            Some(Hash::from_raw(field, algorithm))
+        })
+        .collect()
+}
@@ -4,34 +4,124 @@
 
 use std::collections::hash_map::DefaultHasher;
 use std::hash::{Hash as StdHash, Hasher as StdHasher};
+use crate::digest;
+
+/// The algorithm that produced a hash potential
+/// Hashes from different algorithms live in disjoint regions of hash space
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+#[repr(u8)]
+pub enum HashAlgorithm {
+    /// The default flow hasher
+    #[default]
+    Flow = 0,
+    /// 64-bit FNV-1a
+    Fnv = 1,
+    /// SHA-256
+    Sha256 = 2,
+    /// BLAKE3 with a 256-bit output
+    Blake3 = 3,
+}
+
+impl HashAlgorithm {
+    /// Returns the discriminant byte tagging hashes of this algorithm
+    pub fn tag(self) -> u8 {
+        self as u8
+    }
+
+    /// Recovers the algorithm from its discriminant byte
+    pub fn from_tag(tag: u8) -> Option<Self> {
+        match tag {
+            0 => Some(HashAlgorithm::Flow),
+            1 => Some(HashAlgorithm::Fnv),
+            2 => Some(HashAlgorithm::Sha256),
+            3 => Some(HashAlgorithm::Blake3),
+            _ => None,
+        }
+    }
+
+    /// Computes the flow field of data under this algorithm
+    /// The 64-bit algorithms fill the leading 8 bytes and leave the rest zero
+    fn flow_field(self, data: &[u8]) -> [u8; 32] {
+        let short = |value: u64| {
+            let mut field = [0u8; 32];
+            field[..8].copy_from_slice(&value.to_be_bytes());
+            field
+        };
+        match self {
+            HashAlgorithm::Flow => {
+                let mut hasher = DefaultHasher::new();
+                data.hash(&mut hasher);
+                short(hasher.finish())
+            }
+            HashAlgorithm::Fnv => short(data.iter().fold(0xcbf29ce484222325, |acc, &byte| {
+                (acc ^ byte as u64).wrapping_mul(0x100000001b3)
+            })),
+            HashAlgorithm::Sha256 => digest::sha256(data),
+            HashAlgorithm::Blake3 => digest::blake3(data),
+        }
+    }
+}
 
 /// The Hash potential flow
 /// Represents a point in hash space where content converges
+///
+/// Equality compares the algorithm as well as the bytes, so equal bytes
+/// from different algorithms never alias in storage.
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub struct Hash([u8; 32]);
+pub struct Hash {
+    algorithm: HashAlgorithm,
+    field: [u8; 32],
+}
 
 impl Hash {
-    /// Creates a new hash potential from flow field
+    /// Creates a new hash potential from flow field under the default algorithm
     pub fn from_flow(data: &[u8]) -> Self {
-        let mut hasher = DefaultHasher::new();
-        data.hash(&mut hasher);
-        let flow_value = hasher.finish();
-        Self(flow_value.to_be_bytes().into())
+        Self::from_flow_with(data, HashAlgorithm::default())
+    }
+
+    /// Creates a new hash potential from flow field under the given algorithm
+    pub fn from_flow_with(data: &[u8], algorithm: HashAlgorithm) -> Self {
+        Self { algorithm, field: algorithm.flow_field(data) }
+    }
+
+    /// Creates a hash potential from raw bytes produced by `algorithm`
+    pub fn from_raw(field: [u8; 32], algorithm: HashAlgorithm) -> Self {
+        Self { algorithm, field }
+    }
+
+    /// Returns the algorithm that produced this potential
+    pub fn algorithm(&self) -> HashAlgorithm {
+        self.algorithm
     }
 
     /// Returns the flow field at this potential
     pub fn flow_field(&self) -> &[u8; 32] {
-        &self.0
+        &self.field
+    }
+
+    /// Checks whether content flows to this potential under its own algorithm
+    pub fn matches(&self, content: &[u8]) -> bool {
+        Self::from_flow_with(content, self.algorithm) == *self
     }
 
-    /// Computes the gradient of this hash potential
-    pub fn gradient(&self) -> Vec<u8> {
-        self.0.to_vec()
+    /// Computes the gradient between two hash potentials
+    /// The number of differing bits in their flow fields (Hamming distance), from 0 to 256
+    /// The algorithm tags are not compared
+    pub fn gradient(&self, other: &Hash) -> u64 {
+        self.field.iter()
+            .zip(&other.field)
+            .map(|(a, b)| (a ^ b).count_ones() as u64)
+            .sum()
     }
 }
 
 /// The hash flow operator
-/// Transforms content flow into hash potential
+/// Transforms content flow into hash potential under the default algorithm
 pub fn hash_flow(data: &[u8]) -> Hash {
-    Hash::from_flow(data)
-} 
\ No newline at end of file
+    hash_flow_with(data, HashAlgorithm::default())
+}
+
+/// The hash flow operator for a chosen algorithm
+pub fn hash_flow_with(data: &[u8], algorithm: HashAlgorithm) -> Hash {
+    Hash::from_flow_with(data, algorithm)
+}
\ No newline at end of file
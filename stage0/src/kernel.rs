@@ -2,9 +2,22 @@
 //! This represents the flow of operations through the system
 //! Each kernel operation is a potential in the system coordination field
 
-use crate::hash::Hash;
+use std::collections::{HashMap, HashSet};
+use std::io::{self, Read, Write};
+use crate::hash::{Hash, HashAlgorithm};
 use crate::artifact::Artifact;
-use crate::storage::{Storage, storage_flow};
+use crate::chunk::{self, CHUNKING_THRESHOLD};
+use crate::storage::{Storage, StorageFlowError, storage_flow};
+
+/// The default cycle length, preserving the classic 42-step cycle
+pub const DEFAULT_CYCLE_LENGTH: u64 = 42;
+
+/// The leading bytes of a saved kernel flow, followed by the format version
+const SNAPSHOT_MAGIC: &[u8; 8] = b"BOOTFLOW";
+const SNAPSHOT_VERSION: u8 = 2;
+
+/// Marks an absent `max_entries` cap, `max_bytes` budget or expiry in a saved kernel flow
+const SNAPSHOT_NONE: u64 = u64::MAX;
 
 /// The Kernel potential flow
 /// Represents the central coordination field where all flows converge
@@ -12,6 +25,18 @@ use crate::storage::{Storage, storage_flow};
 pub struct Kernel {
     storage: Storage,
     cycle: u64,
+    cycle_length: u64,
+    hash_algorithm: HashAlgorithm,
+    max_entries: Option<usize>,
+    max_bytes: Option<usize>,
+    sizes: HashMap<Hash, usize>,
+    operations: u64,
+    expirations: HashMap<Hash, u64>,
+    tombstones: HashSet<Hash>,
+    chunking: bool,
+    chunks: Storage,
+    chunk_refs: HashMap<Hash, usize>,
+    chunked: HashSet<Hash>,
 }
 
 impl Kernel {
@@ -20,26 +45,271 @@ impl Kernel {
         Self {
             storage: storage_flow(),
             cycle: 0,
+            cycle_length: DEFAULT_CYCLE_LENGTH,
+            hash_algorithm: HashAlgorithm::default(),
+            max_entries: None,
+            max_bytes: None,
+            sizes: HashMap::new(),
+            operations: 0,
+            expirations: HashMap::new(),
+            tombstones: HashSet::new(),
+            chunking: false,
+            chunks: storage_flow(),
+            chunk_refs: HashMap::new(),
+            chunked: HashSet::new(),
         }
     }
 
-    /// Stores content flow and returns hash potential
+    /// Places the kernel potential field over the given storage field
+    /// Set before storing content; the previous storage field is dropped
+    pub fn with_storage(mut self, storage: Storage) -> Self {
+        self.storage = storage;
+        self
+    }
+
+    /// Makes the cycle wrap after `cycle_length` steps
+    /// A length of 0 is treated as 1
+    pub fn with_cycle_length(mut self, cycle_length: u64) -> Self {
+        self.cycle_length = cycle_length.max(1);
+        self
+    }
+
+    /// Hashes content under `algorithm`
+    pub fn with_hash_algorithm(mut self, algorithm: HashAlgorithm) -> Self {
+        self.hash_algorithm = algorithm;
+        self
+    }
+
+    /// Holds at most `max_entries` live artifacts
+    pub fn with_max_entries(mut self, max_entries: usize) -> Self {
+        self.max_entries = Some(max_entries);
+        self
+    }
+
+    /// Holds at most `max_bytes` bytes of live content
+    pub fn with_max_bytes(mut self, max_bytes: usize) -> Self {
+        self.max_bytes = Some(max_bytes);
+        self
+    }
+
+    /// Splits large content into shared chunks
+    /// Content of at least `CHUNKING_THRESHOLD` bytes is stored as a manifest of
+    /// chunk hashes, and each distinct chunk is held once in memory
+    pub fn with_chunking(mut self) -> Self {
+        self.chunking = true;
+        self
+    }
+
+    /// Stores content flow and returns hash potential, panicking if the store fails
     pub fn store_flow(&mut self, content: Vec<u8>) -> Hash {
-        let artifact = crate::artifact::artifact_flow(content);
-        let hash = artifact.hash.clone();
-        let _ = self.storage.store_flow(artifact);
+        self.try_store_flow(content)
+            .unwrap_or_else(|error| panic!("kernel store flow failed: {}", error))
+    }
+
+    /// Stores content flow and returns hash potential
+    /// Empty content is rejected, as is new content once the field is at capacity
+    /// or would overrun its byte budget; storing content that is already live
+    /// always succeeds
+    pub fn try_store_flow(&mut self, content: Vec<u8>) -> Result<Hash, StorageFlowError> {
+        let hash = self.place_flow(content)?;
         self.advance_cycle_flow();
+        Ok(hash)
+    }
+
+    /// Stores a batch of content flows, advancing the cycle once for the whole batch
+    /// An empty batch leaves the cycle untouched
+    pub fn store_batch_flow(&mut self, items: Vec<Vec<u8>>) -> Vec<Hash> {
+        let hashes: Vec<Hash> = items.into_iter()
+            .map(|content| self.place_flow(content)
+                .unwrap_or_else(|error| panic!("kernel store flow failed: {}", error)))
+            .collect();
+        if !hashes.is_empty() {
+            self.advance_cycle_flow();
+        }
+        hashes
+    }
+
+    /// Places content flow in storage without advancing the cycle
+    fn place_flow(&mut self, content: Vec<u8>) -> Result<Hash, StorageFlowError> {
+        if content.is_empty() {
+            return Err(StorageFlowError::Empty);
+        }
+        let artifact = Artifact::from_content_flow_with(content, self.hash_algorithm);
+        let hash = artifact.hash.clone();
+        if let Some(max_entries) = self.max_entries {
+            if self.system_divergence() >= max_entries && !self.contains_flow(&hash) {
+                return Err(StorageFlowError::CapacityExceeded);
+            }
+        }
+        if let Some(max_bytes) = self.max_bytes {
+            if self.live_bytes() + artifact.content.len() > max_bytes && !self.contains_flow(&hash) {
+                return Err(StorageFlowError::BudgetExceeded);
+            }
+        }
+        let size = artifact.content.len();
+        if !self.chunking || size < CHUNKING_THRESHOLD {
+            self.storage.store_flow(artifact)?;
+        } else if !self.chunked.contains(&hash) {
+            let manifest = self.store_chunks_flow(&artifact.content)?;
+            self.storage.store_flow(Artifact { hash: hash.clone(), content: manifest })?;
+            self.chunked.insert(hash.clone());
+        }
+        // Only content that reached storage counts against the byte budget
+        self.sizes.insert(hash.clone(), size);
+        self.tombstones.remove(&hash);
+        Ok(hash)
+    }
+
+    /// Stores each chunk of the content once, returning the manifest of chunk hashes
+    fn store_chunks_flow(&mut self, content: &[u8]) -> Result<Vec<u8>, StorageFlowError> {
+        let mut hashes = Vec::new();
+        for range in chunk::chunk_flow(content) {
+            let piece = Artifact::from_content_flow_with(content[range].to_vec(), self.hash_algorithm);
+            let refs = self.chunk_refs.entry(piece.hash.clone()).or_insert(0);
+            *refs += 1;
+            hashes.push(piece.hash.clone());
+            if *refs == 1 {
+                self.chunks.store_flow(piece)?;
+            }
+        }
+        Ok(chunk::encode_manifest(&hashes))
+    }
+
+    /// Releases the chunks of a chunked artifact, erasing those no manifest references
+    fn release_chunks_flow(&mut self, hash: &Hash) {
+        if !self.chunked.remove(hash) {
+            return;
+        }
+        let manifest = self.storage.retrieve_flow(hash)
+            .and_then(|manifest| chunk::decode_manifest(&manifest.content))
+            .unwrap_or_default();
+        for piece in manifest {
+            if let Some(refs) = self.chunk_refs.get_mut(&piece) {
+                *refs -= 1;
+                if *refs == 0 {
+                    self.chunk_refs.remove(&piece);
+                    self.chunks.remove_flow(&piece);
+                }
+            }
+        }
+    }
+
+    /// Reassembles a chunked artifact from its manifest
+    fn assemble_flow(&self, manifest: Artifact) -> Option<Artifact> {
+        let mut content = Vec::new();
+        for piece in chunk::decode_manifest(&manifest.content)? {
+            content.extend_from_slice(&self.chunks.retrieve_ref_flow(&piece)?.content);
+        }
+        Some(Artifact { hash: manifest.hash, content })
+    }
+
+    /// Stores content flow that expires after `ttl_cycles` further cycle advances
+    /// Content that is already live and permanent stays permanent, and content
+    /// that already expires keeps the later of its two expiries
+    pub fn store_flow_with_ttl(&mut self, content: Vec<u8>, ttl_cycles: u64) -> Hash {
+        let hash = crate::hash::hash_flow_with(&content, self.hash_algorithm);
+        let permanent = self.contains_flow(&hash) && !self.expirations.contains_key(&hash);
+        self.store_flow(content);
+        if !permanent {
+            let expiry = self.operations.saturating_add(ttl_cycles);
+            let current = self.expirations.entry(hash.clone()).or_insert(expiry);
+            *current = (*current).max(expiry);
+        }
         hash
     }
 
+    /// Removes every artifact whose expiry lies before the operations potential
+    /// Returns the hash potentials of the purged artifacts
+    pub fn purge_expired_flow(&mut self) -> Vec<Hash> {
+        let now = self.operations;
+        let expired: Vec<Hash> = self.expirations.iter()
+            .filter(|(_, &expiry)| expiry < now)
+            .map(|(hash, _)| hash.clone())
+            .collect();
+        for hash in &expired {
+            self.expirations.remove(hash);
+            self.sizes.remove(hash);
+            self.release_chunks_flow(hash);
+            self.storage.remove_flow(hash);
+        }
+        expired
+    }
+
+    /// Removes artifact flow by hash potential, returning it if it was live
+    /// The artifact is tombstoned rather than erased; `compact_flow` erases it
+    pub fn remove_flow(&mut self, hash: &Hash) -> Option<Artifact> {
+        let artifact = self.retrieve_flow(hash)?;
+        self.expirations.remove(hash);
+        self.tombstones.insert(hash.clone());
+        Some(artifact)
+    }
+
+    /// Erases every tombstoned artifact from storage
+    /// Returns how many artifacts were erased
+    pub fn compact_flow(&mut self) -> usize {
+        let erased: Vec<Hash> = self.tombstones.drain().collect();
+        for hash in &erased {
+            self.sizes.remove(hash);
+            self.release_chunks_flow(hash);
+            self.storage.remove_flow(hash);
+        }
+        erased.len()
+    }
+
+    /// Restores an artifact under its recorded hash without rehashing or advancing the cycle
+    pub fn restore_flow(&mut self, artifact: Artifact) -> Result<(), StorageFlowError> {
+        self.tombstones.remove(&artifact.hash);
+        self.release_chunks_flow(&artifact.hash);
+        self.sizes.insert(artifact.hash.clone(), artifact.content.len());
+        self.storage.store_flow(artifact)
+    }
+
     /// Retrieves artifact flow by hash potential
     pub fn retrieve_flow(&self, hash: &Hash) -> Option<Artifact> {
-        self.storage.retrieve_flow(hash)
+        if self.tombstones.contains(hash) {
+            return None;
+        }
+        let artifact = self.storage.retrieve_flow(hash)?;
+        if self.chunked.contains(hash) {
+            return self.assemble_flow(artifact);
+        }
+        Some(artifact)
     }
 
-    /// Advances the cycle flow (42-step cycle)
+    /// Checks whether a live artifact flow exists under a hash potential
+    pub fn contains_flow(&self, hash: &Hash) -> bool {
+        !self.tombstones.contains(hash) && self.storage.contains_flow(hash)
+    }
+
+    /// Walks the hash potentials of every live artifact
+    pub fn hashes_flow(&self) -> impl Iterator<Item = &Hash> + '_ {
+        self.storage.hashes_flow().filter(move |hash| !self.tombstones.contains(*hash))
+    }
+
+    /// Borrows artifact flow by hash potential from in-memory storage
+    /// Chunked artifacts have no contiguous content to lend
+    pub fn retrieve_ref_flow(&self, hash: &Hash) -> Option<&Artifact> {
+        if self.tombstones.contains(hash) || self.chunked.contains(hash) {
+            return None;
+        }
+        self.storage.retrieve_ref_flow(hash)
+    }
+
+    /// Finds artifact flows whose content contains the byte substring
+    pub fn find_containing_flow(&self, needle: &[u8]) -> Vec<Hash> {
+        let mut found = self.storage.find_containing_flow(needle);
+        found.retain(|hash| !self.tombstones.contains(hash) && !self.chunked.contains(hash));
+        found.extend(self.chunked.iter()
+            .filter_map(|hash| self.retrieve_flow(hash))
+            .filter(|artifact| artifact.contains_flow(needle))
+            .map(|artifact| artifact.hash));
+        found
+    }
+
+    /// Advances the cycle flow, wrapping at the cycle length
     pub fn advance_cycle_flow(&mut self) {
-        self.cycle = (self.cycle + 1) % 42;
+        self.cycle = (self.cycle + 1) % self.cycle_length;
+        self.operations += 1;
     }
 
     /// Gets the current cycle potential
@@ -47,12 +317,195 @@ impl Kernel {
         self.cycle
     }
 
-    /// Computes the system flow divergence
+    /// Gets the number of steps after which the cycle wraps
+    pub fn cycle_length(&self) -> u64 {
+        self.cycle_length
+    }
+
+    /// Gets the algorithm stored content is hashed under
+    pub fn hash_algorithm(&self) -> HashAlgorithm {
+        self.hash_algorithm
+    }
+
+    /// Gets the maximum number of live artifacts, if capped
+    pub fn max_entries(&self) -> Option<usize> {
+        self.max_entries
+    }
+
+    /// Gets the byte budget for live content, if capped
+    pub fn max_bytes(&self) -> Option<usize> {
+        self.max_bytes
+    }
+
+    /// Sums the content bytes of every live artifact, before chunk sharing
+    pub fn live_bytes(&self) -> usize {
+        self.sizes.iter()
+            .filter(|(hash, _)| !self.tombstones.contains(*hash))
+            .map(|(_, size)| size)
+            .sum()
+    }
+
+    /// Gets the bytes left in the budget, or `usize::MAX` without one
+    pub fn remaining_bytes(&self) -> usize {
+        self.max_bytes.map_or(usize::MAX, |max| max.saturating_sub(self.live_bytes()))
+    }
+
+    /// Counts the distinct chunks held for chunked artifacts
+    pub fn chunk_count(&self) -> usize {
+        self.chunks.field_curl()
+    }
+
+    /// Sums the bytes of the distinct chunks held for chunked artifacts
+    pub fn chunk_bytes(&self) -> usize {
+        self.chunks.entries().map(|(_, piece)| piece.divergence()).sum()
+    }
+
+    /// Gets the total number of operations, which unlike the cycle never wraps
+    pub fn operations_potential(&self) -> u64 {
+        self.operations
+    }
+
+    /// Writes the live artifacts and cycle state as a length-prefixed binary flow
+    ///
+    /// All integers are little-endian. The header is the magic `BOOTFLOW`, a
+    /// version byte, then cycle, cycle length, operations, the hash algorithm
+    /// tag, the entry cap and the byte budget (`u64::MAX` for none). Then comes the entry count
+    /// and, per artifact, its hash tag and 32-byte field, its expiry
+    /// (`u64::MAX` for none), the content length and the content bytes.
+    /// Tombstoned artifacts are left out.
+    pub fn save_flow<W: Write>(&self, mut writer: W) -> io::Result<()> {
+        writer.write_all(SNAPSHOT_MAGIC)?;
+        writer.write_all(&[SNAPSHOT_VERSION])?;
+        for value in [self.cycle, self.cycle_length, self.operations] {
+            writer.write_all(&value.to_le_bytes())?;
+        }
+        writer.write_all(&[self.hash_algorithm.tag()])?;
+        let max_entries = self.max_entries.map_or(SNAPSHOT_NONE, |max| max as u64);
+        writer.write_all(&max_entries.to_le_bytes())?;
+        let max_bytes = self.max_bytes.map_or(SNAPSHOT_NONE, |max| max as u64);
+        writer.write_all(&max_bytes.to_le_bytes())?;
+
+        writer.write_all(&(self.hashes_flow().count() as u64).to_le_bytes())?;
+        for hash in self.hashes_flow() {
+            let artifact = self.retrieve_flow(hash)
+                .ok_or_else(|| io::Error::new(io::ErrorKind::NotFound, "stored artifact vanished while saving"))?;
+            let expiry = self.expirations.get(hash).copied().unwrap_or(SNAPSHOT_NONE);
+            writer.write_all(&[hash.algorithm().tag()])?;
+            writer.write_all(hash.flow_field())?;
+            writer.write_all(&expiry.to_le_bytes())?;
+            writer.write_all(&(artifact.content.len() as u64).to_le_bytes())?;
+            writer.write_all(&artifact.content)?;
+        }
+        writer.flush()
+    }
+
+    /// Reads a kernel flow written by `save_flow` into in-memory storage
+    /// Version 1 flows, which predate the byte budget, load without one
+    /// Malformed input is reported as `io::ErrorKind::InvalidData`
+    pub fn load_flow<R: Read>(mut reader: R) -> io::Result<Self> {
+        let mut magic = [0u8; 8];
+        reader.read_exact(&mut magic)?;
+        if &magic != SNAPSHOT_MAGIC {
+            return Err(invalid_snapshot("missing BOOTFLOW header"));
+        }
+        let version = read_byte(&mut reader)?;
+        if version == 0 || version > SNAPSHOT_VERSION {
+            return Err(invalid_snapshot(&format!("unsupported version {}", version)));
+        }
+
+        let cycle = read_u64(&mut reader)?;
+        let cycle_length = read_u64(&mut reader)?;
+        if cycle_length == 0 || cycle >= cycle_length {
+            return Err(invalid_snapshot("cycle outside its length"));
+        }
+        let mut kernel = Self {
+            cycle,
+            cycle_length,
+            operations: read_u64(&mut reader)?,
+            hash_algorithm: read_algorithm(&mut reader)?,
+            max_entries: match read_u64(&mut reader)? {
+                SNAPSHOT_NONE => None,
+                max => Some(max as usize),
+            },
+            ..Self::new_field()
+        };
+        if version >= 2 {
+            kernel.max_bytes = match read_u64(&mut reader)? {
+                SNAPSHOT_NONE => None,
+                max => Some(max as usize),
+            };
+        }
+
+        let count = read_u64(&mut reader)?;
+        for _ in 0..count {
+            let algorithm = read_algorithm(&mut reader)?;
+            let mut field = [0u8; 32];
+            reader.read_exact(&mut field)?;
+            let hash = Hash::from_raw(field, algorithm);
+            let expiry = read_u64(&mut reader)?;
+            let length = read_u64(&mut reader)?;
+            let mut content = Vec::new();
+            (&mut reader).take(length).read_to_end(&mut content)?;
+            if content.len() as u64 != length {
+                return Err(io::Error::new(io::ErrorKind::UnexpectedEof, "truncated artifact content"));
+            }
+
+            if expiry != SNAPSHOT_NONE {
+                kernel.expirations.insert(hash.clone(), expiry);
+            }
+            kernel.sizes.insert(hash.clone(), content.len());
+            kernel.storage.store_flow(Artifact { hash, content }).map_err(io::Error::other)?;
+        }
+        Ok(kernel)
+    }
+
+    /// Computes the divergence held by the kernel cycle: live artifacts that expire
+    pub fn expiring_divergence(&self) -> usize {
+        self.expirations.keys().filter(|hash| self.contains_flow(hash)).count()
+    }
+
+    /// Computes the divergence held whole in storage: live unchunked artifacts that do not expire
+    pub fn whole_divergence(&self) -> usize {
+        self.hashes_flow()
+            .filter(|hash| !self.chunked.contains(*hash) && !self.expirations.contains_key(*hash))
+            .count()
+    }
+
+    /// Computes the divergence held by the chunk field: live chunked artifacts that do not expire
+    pub fn chunked_divergence(&self) -> usize {
+        self.chunked.iter()
+            .filter(|hash| self.contains_flow(hash) && !self.expirations.contains_key(*hash))
+            .count()
+    }
+
+    /// Computes the system flow divergence, counting only live artifacts
     pub fn system_divergence(&self) -> usize {
-        self.storage.field_curl()
+        self.storage.field_curl().saturating_sub(self.tombstones.len())
     }
 }
 
+/// Builds the error for a malformed saved kernel flow
+fn invalid_snapshot(reason: &str) -> io::Error {
+    io::Error::new(io::ErrorKind::InvalidData, format!("invalid kernel flow snapshot: {}", reason))
+}
+
+pub(crate) fn read_byte<R: Read>(reader: &mut R) -> io::Result<u8> {
+    let mut byte = [0u8; 1];
+    reader.read_exact(&mut byte)?;
+    Ok(byte[0])
+}
+
+pub(crate) fn read_u64<R: Read>(reader: &mut R) -> io::Result<u64> {
+    let mut bytes = [0u8; 8];
+    reader.read_exact(&mut bytes)?;
+    Ok(u64::from_le_bytes(bytes))
+}
+
+pub(crate) fn read_algorithm<R: Read>(reader: &mut R) -> io::Result<HashAlgorithm> {
+    let tag = read_byte(reader)?;
+    HashAlgorithm::from_tag(tag).ok_or_else(|| invalid_snapshot(&format!("unknown hash algorithm tag {}", tag)))
+}
+
 /// The kernel flow operator
 /// Transforms system operations into kernel potentials
 pub fn kernel_flow() -> Kernel {
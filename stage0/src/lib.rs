@@ -35,6 +35,11 @@
 //! ├── storage.rs      // Storage potential flow
 //! ├── kernel.rs       // Kernel potential flow
 //! ├── system.rs       // System potential flow
+//! ├── cache.rs        // Read cache potential flow
+//! ├── observer.rs     // Change notification potential flow
+//! ├── chunk.rs        // Content-defined chunking potential flow
+//! ├── digest.rs       // SHA-256 and BLAKE3 digests
+//! ├── async_store.rs  // Async storage potential flow (`async` feature)
 //! └── lib.rs          // Unified flow field
 //! ```
 //!
@@ -44,9 +49,10 @@
 //! **Purpose**: Content identification through hash space
 //!
 //! - **Flow Field**: Hash space where content converges
-//! - **Flow Operator**: `hash_flow(data) -> Hash`
+//! - **Flow Operator**: `hash_flow(data) -> Hash`, or `hash_flow_with(data, algorithm)`
+//! - **Algorithms**: `HashAlgorithm::{Flow, Fnv, Sha256, Blake3}`, defaulting to `Flow`
 //! - **Mathematical**: Represents a potential in content identification field
-//! - **Properties**: Gradient, flow field, potential convergence
+//! - **Properties**: Gradient (bit distance between potentials), flow field, potential convergence
 //!
 //! ### 2. Artifact Flow (`artifact.rs`)
 //! **Purpose**: Content storage and materialization
@@ -61,6 +67,7 @@
 //!
 //! - **Flow Field**: Field of storage potentials where artifacts converge
 //! - **Flow Operator**: `storage_flow() -> Storage`
+//! - **Backends**: `StorageBackend` trait with in-memory and file-directory implementations
 //! - **Mathematical**: Represents a field of storage potentials
 //! - **Properties**: Curl, field operations, potential mapping
 //!
@@ -92,10 +99,31 @@
 //! impl Bootstrap {
 //!     pub fn new() -> Self
 //!     pub fn store(&mut self, content: Vec<u8>) -> Hash
+//!     pub fn store_checked(&mut self, content: Vec<u8>) -> (Hash, bool)
+//!     pub fn try_store(&mut self, content: Vec<u8>) -> Result<(Hash, usize), StorageFlowError>
+//!     pub fn store_batch(&mut self, items: Vec<Vec<u8>>) -> Vec<Hash>
 //!     pub fn retrieve(&self, hash: &Hash) -> Option<Artifact>
+//!     pub fn retrieve_ref(&self, hash: &Hash) -> Option<&Artifact>
+//!     pub fn contains(&self, hash: &Hash) -> bool
+//!     pub fn contains_content(&self, content: &[u8]) -> bool
+//!     pub fn remove(&mut self, hash: &Hash) -> Option<Artifact>
+//!     pub fn compact(&mut self) -> usize
+//!     pub fn subscribe(&mut self, observer: Box<dyn StoreObserver>)
+//!     pub fn save(&self, path: &Path) -> io::Result<()>
+//!     pub fn load(path: &Path) -> io::Result<Bootstrap>
+//!     pub fn iter_hashes(&self) -> impl Iterator<Item = &Hash> + '_
 //!     pub fn cycle_step(&self) -> u64
+//!     pub fn content_gradient(&self, a: &Hash, b: &Hash) -> Option<u64>
 //!     pub fn total_divergence(&self) -> usize
+//!     pub fn divergence_breakdown(&self) -> DivergenceReport
 //!     pub fn curl(&self) -> u64
+//!     pub fn curl_history(&self) -> &[(u64, u64)]
+//!     pub fn chain_head(&self) -> Option<Hash>
+//!     pub fn walk_chain(&self) -> Vec<Hash>
+//!     pub fn chain_link(&self, hash: &Hash) -> Option<Option<Hash>>
+//!     pub fn verify_chain(&self) -> bool
+//!     pub fn chunk_bytes(&self) -> usize
+//!     pub fn remaining_bytes(&self) -> usize
 //! }
 //! ```
 //!
@@ -147,36 +175,538 @@ pub mod artifact;
 pub mod storage;
 pub mod kernel;
 pub mod system;
+pub mod cache;
+pub mod observer;
+pub mod chunk;
+mod digest;
+#[cfg(feature = "async")]
+pub mod async_store;
+
+use std::cell::RefCell;
+use std::collections::HashMap;
+use std::fs::File;
+use std::io::{self, BufReader, BufWriter, Read, Write};
+use std::path::Path;
 
 // Re-export the main flow types
-pub use hash::{Hash, hash_flow};
+pub use hash::{Hash, HashAlgorithm, hash_flow, hash_flow_with};
 pub use artifact::{Artifact, artifact_flow};
-pub use storage::{Storage, storage_flow, StorageFlowError};
-pub use kernel::{Kernel, kernel_flow};
+pub use storage::{Storage, storage_flow, StorageFlowError, StorageBackend, MemoryBackend, FileBackend};
+pub use kernel::{Kernel, kernel_flow, DEFAULT_CYCLE_LENGTH};
 pub use system::{System, system_flow, bootstrap_flow};
+pub use cache::{ReadCache, DEFAULT_READ_CACHE_CAPACITY};
+pub use observer::StoreObserver;
+#[cfg(feature = "async")]
+pub use async_store::{AsyncStore, BlockingStore};
+
+/// A snapshot of the bootstrap flow field's potentials at one moment
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct BootstrapStats {
+    /// Position in the cycle
+    pub cycle_step: u64,
+    /// Total system divergence (number of stored artifacts)
+    pub total_divergence: usize,
+    /// System curl (vorticity)
+    pub curl: u64,
+    /// Total operations performed, which never wraps
+    pub operations: u64,
+}
+
+/// The total divergence split by the flow that sources it
+///
+/// Each component is counted from its own source, and each live artifact
+/// belongs to exactly one, so the components sum to `Bootstrap::total_divergence`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DivergenceReport {
+    /// Live artifacts held whole in the storage field, without an expiry
+    pub artifacts: usize,
+    /// Live artifacts whose lifetime the kernel cycle governs (stored with a TTL)
+    pub kernel: usize,
+    /// Live artifacts assembled from the shared chunk field rather than held
+    /// whole (stored with chunking, without a TTL)
+    pub system: usize,
+}
+
+impl DivergenceReport {
+    /// Sums the components back into the total divergence
+    pub fn total(&self) -> usize {
+        self.artifacts + self.kernel + self.system
+    }
+}
+
+/// The gradient between two snapshots: the rate of change in potential
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct FlowGradient {
+    /// Operations performed between the two snapshots
+    pub operations: u64,
+    /// Change in total divergence
+    pub divergence_delta: i64,
+    /// Forward advance of the curl around the cycle
+    pub curl_delta: u64,
+    /// Divergence change per operation (0 when no operations occurred)
+    pub divergence_rate: f64,
+    /// Curl advance per operation (0 when no operations occurred)
+    pub curl_rate: f64,
+}
 
 /// The Bootstrap type - the main entry point into the flow system
 /// This represents the complete flow field where all potentials converge
 pub struct Bootstrap {
     system: System,
+    read_cache: RefCell<ReadCache>,
+    observers: Vec<Box<dyn StoreObserver>>,
+    curl_history: Option<Vec<(u64, u64)>>,
+    hash_chain: bool,
+    chain_head: Option<Hash>,
+    chain_links: HashMap<Hash, Option<Hash>>,
+}
+
+/// Heads the hash-chain section that `save` appends after the kernel flow
+const CHAIN_MAGIC: &[u8; 8] = b"BOOTLINK";
+
+/// Marks an absent hash in the saved hash-chain section
+const CHAIN_ABSENT: u8 = 0;
+
+/// Marks a present hash in the saved hash-chain section, followed by its algorithm tag and flow field
+const CHAIN_PRESENT: u8 = 1;
+
+fn write_chain_hash<W: Write>(writer: &mut W, hash: Option<&Hash>) -> io::Result<()> {
+    match hash {
+        Some(hash) => {
+            writer.write_all(&[CHAIN_PRESENT, hash.algorithm().tag()])?;
+            writer.write_all(hash.flow_field())
+        }
+        None => writer.write_all(&[CHAIN_ABSENT]),
+    }
+}
+
+fn read_chain_hash<R: Read>(reader: &mut R) -> io::Result<Option<Hash>> {
+    match kernel::read_byte(reader)? {
+        CHAIN_ABSENT => Ok(None),
+        CHAIN_PRESENT => {
+            let algorithm = kernel::read_algorithm(reader)?;
+            let mut field = [0u8; 32];
+            reader.read_exact(&mut field)?;
+            Ok(Some(Hash::from_raw(field, algorithm)))
+        }
+        tag => Err(io::Error::new(io::ErrorKind::InvalidData, format!("invalid hash chain marker {}", tag))),
+    }
 }
 
 impl Bootstrap {
     /// Creates a new bootstrap flow field
     pub fn new() -> Self {
+        Self::from_system(bootstrap_flow())
+    }
+
+    /// Keeps the bootstrap's artifacts in the given backend
+    ///
+    /// Like the other `with_` options, this consumes and returns the bootstrap,
+    /// so options chain: `Bootstrap::new().with_backend(backend).with_max_entries(8)`.
+    /// Set options before storing content.
+    pub fn with_backend(mut self, backend: Box<dyn StorageBackend>) -> Self {
+        self.system = self.system.with_backend(backend);
+        self
+    }
+
+    /// Makes the cycle wrap after `cycle_length` steps
+    ///
+    /// `Bootstrap::new` uses `DEFAULT_CYCLE_LENGTH` (42).
+    pub fn with_cycle_length(mut self, cycle_length: u64) -> Self {
+        self.system = self.system.with_cycle_length(cycle_length);
+        self
+    }
+
+    /// Hashes stored content under `algorithm`
+    ///
+    /// `Bootstrap::new` uses `HashAlgorithm::Flow`.
+    pub fn with_hash_algorithm(mut self, algorithm: HashAlgorithm) -> Self {
+        self.system = self.system.with_hash_algorithm(algorithm);
+        self
+    }
+
+    /// Holds at most `max_entries` artifacts
+    ///
+    /// Once full, `try_store` rejects new content with
+    /// `StorageFlowError::CapacityExceeded` until something is removed.
+    pub fn with_max_entries(mut self, max_entries: usize) -> Self {
+        self.system = self.system.with_max_entries(max_entries);
+        self
+    }
+
+    /// Holds at most `max_bytes` bytes of live content
+    ///
+    /// Content is counted at its full length, even when chunking shares
+    /// bytes between artifacts. Once the budget would be overrun, `try_store`
+    /// rejects new content with `StorageFlowError::BudgetExceeded`; there is
+    /// no eviction, so room is only freed by removing content.
+    pub fn with_max_bytes(mut self, max_bytes: usize) -> Self {
+        self.system = self.system.with_max_bytes(max_bytes);
+        self
+    }
+
+    /// Deduplicates large content by chunk
+    ///
+    /// Content of at least `chunk::CHUNKING_THRESHOLD` bytes is split at
+    /// boundaries chosen by a rolling hash of the content, so artifacts that
+    /// share a region share the chunks covering it. Each distinct chunk is
+    /// held once, in memory, and the artifact stores the list of its chunk
+    /// hashes. Retrieval reassembles the content; `retrieve_ref` returns
+    /// `None` for chunked artifacts.
+    pub fn with_chunking(mut self) -> Self {
+        self.system = self.system.with_chunking();
+        self
+    }
+
+    /// Records `(cycle_step, curl)` after each store
+    ///
+    /// See `curl_history`. Recording is opt-in because the history grows
+    /// with every store.
+    pub fn with_curl_history(mut self) -> Self {
+        self.curl_history = Some(Vec::new());
+        self
+    }
+
+    /// Chains each stored artifact to the one stored before it
+    ///
+    /// Each newly chained hash records the previous chain head alongside the
+    /// store, leaving the stored content and its hash untouched. Content that
+    /// is already in the chain keeps its place. `walk_chain` replays the store
+    /// order, `chain_link` reads a link back, and `verify_chain` checks every
+    /// link. `save` writes the chain and `load` resumes it.
+    pub fn with_hash_chain(mut self) -> Self {
+        self.hash_chain = true;
+        self
+    }
+
+    /// Loads a bootstrap flow field saved by `save`
+    ///
+    /// The loaded content lives in memory, whatever backend it was saved from.
+    /// A saved hash chain is resumed. Observers are not persisted and must
+    /// subscribe again.
+    pub fn load(path: &Path) -> io::Result<Self> {
+        let mut reader = BufReader::new(File::open(path)?);
+        let system = System::load_system_flow(&mut reader)?;
+        let mut bootstrap = Self::from_system(system);
+
+        let mut chain = Vec::new();
+        reader.read_to_end(&mut chain)?;
+        if !chain.is_empty() {
+            bootstrap.load_chain(&mut chain.as_slice())?;
+        }
+        Ok(bootstrap)
+    }
+
+    /// Reads the hash-chain section written by `save_chain`
+    fn load_chain<R: Read>(&mut self, reader: &mut R) -> io::Result<()> {
+        let mut magic = [0u8; 8];
+        reader.read_exact(&mut magic)?;
+        if &magic != CHAIN_MAGIC {
+            return Err(io::Error::new(io::ErrorKind::InvalidData, "missing BOOTLINK header"));
+        }
+        self.hash_chain = true;
+        self.chain_head = read_chain_hash(reader)?;
+        for _ in 0..kernel::read_u64(reader)? {
+            let hash = read_chain_hash(reader)?
+                .ok_or_else(|| io::Error::new(io::ErrorKind::InvalidData, "hash chain link without a hash"))?;
+            let previous = read_chain_hash(reader)?;
+            self.chain_links.insert(hash, previous);
+        }
+        Ok(())
+    }
+
+    /// Writes the hash chain: `BOOTLINK`, the head, the link count, then each
+    /// link's hash and previous hash
+    ///
+    /// Hashes are a marker byte (0 for none), then the algorithm tag and
+    /// 32-byte field.
+    fn save_chain<W: Write>(&self, writer: &mut W) -> io::Result<()> {
+        writer.write_all(CHAIN_MAGIC)?;
+        write_chain_hash(writer, self.chain_head.as_ref())?;
+        writer.write_all(&(self.chain_links.len() as u64).to_le_bytes())?;
+        for (hash, previous) in &self.chain_links {
+            write_chain_hash(writer, Some(hash))?;
+            write_chain_hash(writer, previous.as_ref())?;
+        }
+        Ok(())
+    }
+
+    /// Wraps a system flow field with an empty read cache
+    fn from_system(system: System) -> Self {
         Self {
-            system: bootstrap_flow(),
+            system,
+            read_cache: RefCell::new(ReadCache::default()),
+            observers: Vec::new(),
+            curl_history: None,
+            hash_chain: false,
+            chain_head: None,
+            chain_links: HashMap::new(),
+        }
+    }
+
+    /// Registers an observer to be notified of every store and removal
+    ///
+    /// Observers are called synchronously, in subscription order, after each
+    /// change has been applied. Content removed by `purge_expired` is reported
+    /// through `on_remove` like an explicit `remove`.
+    pub fn subscribe(&mut self, observer: Box<dyn StoreObserver>) {
+        self.observers.push(observer);
+    }
+
+    /// Appends the current curl to the history, if it is being recorded
+    fn record_curl(&mut self) {
+        let sample = (self.cycle_step(), self.curl());
+        if let Some(history) = &mut self.curl_history {
+            history.push(sample);
+        }
+    }
+
+    /// Links newly stored content to the chain head, if a chain is being kept
+    fn record_link(&mut self, hash: &Hash) {
+        if self.hash_chain && !self.chain_links.contains_key(hash) {
+            self.chain_links.insert(hash.clone(), self.chain_head.replace(hash.clone()));
+        }
+    }
+
+    /// Tells every observer that content was stored under `hash`
+    fn notify_store(&mut self, hash: &Hash) {
+        if self.observers.is_empty() {
+            return;
+        }
+        if let Some(artifact) = self.system.retrieve_system_flow(hash) {
+            for observer in &mut self.observers {
+                observer.on_store(hash, &artifact);
+            }
+        }
+    }
+
+    /// Tells every observer that the content under `hash` was removed
+    fn notify_remove(&mut self, hash: &Hash) {
+        for observer in &mut self.observers {
+            observer.on_remove(hash);
         }
     }
 
     /// Stores content in the bootstrap flow
+    ///
+    /// # Panics
+    ///
+    /// Panics where `try_store` would return an error: on empty content or
+    /// when the store is at capacity or out of byte budget.
     pub fn store(&mut self, content: Vec<u8>) -> Hash {
-        self.system.store_system_flow(content)
+        self.try_store(content)
+            .unwrap_or_else(|error| panic!("bootstrap store failed: {}", error))
+            .0
+    }
+
+    /// Stores content, reporting whether it was newly added
+    ///
+    /// The flag is `false` when the content was already stored and the store
+    /// deduplicated it. Removed content counts as new again.
+    ///
+    /// # Panics
+    ///
+    /// Panics where `store` would.
+    pub fn store_checked(&mut self, content: Vec<u8>) -> (Hash, bool) {
+        let added = !self.contains_content(&content);
+        (self.store(content), added)
+    }
+
+    /// Stores content in the bootstrap flow, surfacing storage failures
+    ///
+    /// Empty content is rejected with `StorageFlowError::Empty`. With a
+    /// `max_entries` cap, new content is rejected with
+    /// `StorageFlowError::CapacityExceeded` once the cap is reached, and with
+    /// a `max_bytes` budget, content that would overrun it is rejected with
+    /// `StorageFlowError::BudgetExceeded`. Content that is already stored
+    /// still succeeds in both cases.
+    ///
+    /// On success, returns the hash along with the bytes left in the budget
+    /// (`usize::MAX` when there is none).
+    pub fn try_store(&mut self, content: Vec<u8>) -> Result<(Hash, usize), StorageFlowError> {
+        let hash = self.system.try_store_system_flow(content)?;
+        self.record_curl();
+        self.record_link(&hash);
+        self.notify_store(&hash);
+        Ok((hash, self.system.system_remaining_bytes()))
     }
 
-    /// Retrieves content from the bootstrap flow
+    /// Stores a batch of content, advancing the cycle exactly once for the whole batch
+    ///
+    /// Each item is stored as `store` would, but the batch counts as a single
+    /// cycle step, so callers can treat a step as one logical transaction. An
+    /// empty batch does not advance the cycle.
+    ///
+    /// # Panics
+    ///
+    /// Panics if any item would make `try_store` fail; items before it stay stored.
+    pub fn store_batch(&mut self, items: Vec<Vec<u8>>) -> Vec<Hash> {
+        let hashes = self.system.store_batch_system_flow(items);
+        if !hashes.is_empty() {
+            self.record_curl();
+        }
+        for hash in &hashes {
+            self.record_link(hash);
+            self.notify_store(hash);
+        }
+        hashes
+    }
+
+    /// Stores content that expires after `ttl_cycles` further cycle advances
+    ///
+    /// Expired content stays retrievable until `purge_expired` is called.
+    /// Content already stored without a TTL stays permanent, and content
+    /// stored again with a TTL keeps whichever expiry is later.
+    pub fn store_with_ttl(&mut self, content: Vec<u8>, ttl_cycles: u64) -> Hash {
+        let hash = self.system.store_system_flow_with_ttl(content, ttl_cycles);
+        self.record_curl();
+        self.record_link(&hash);
+        self.notify_store(&hash);
+        hash
+    }
+
+    /// Removes all expired content, returning how many artifacts were purged
+    pub fn purge_expired(&mut self) -> usize {
+        self.read_cache.get_mut().clear();
+        let purged = self.system.purge_expired_system_flow();
+        for hash in &purged {
+            self.notify_remove(hash);
+        }
+        purged.len()
+    }
+
+    /// Removes content from the bootstrap flow, returning the removed artifact if present
+    ///
+    /// Removed content stops being retrievable and counting towards
+    /// `total_divergence` immediately, but its storage is only reclaimed by
+    /// `compact`. Storing the same content again revives it.
+    pub fn remove(&mut self, hash: &Hash) -> Option<Artifact> {
+        self.read_cache.get_mut().invalidate(hash);
+        let removed = self.system.remove_system_flow(hash)?;
+        self.notify_remove(hash);
+        Some(removed)
+    }
+
+    /// Reclaims the storage of removed content, returning how many artifacts were erased
+    pub fn compact(&mut self) -> usize {
+        self.system.compact_system_flow()
+    }
+
+    /// Retrieves content from the bootstrap flow, serving it from the read cache when possible
     pub fn retrieve(&self, hash: &Hash) -> Option<Artifact> {
-        self.system.retrieve_system_flow(hash)
+        if let Some(artifact) = self.read_cache.borrow_mut().get(hash) {
+            return Some(artifact);
+        }
+        let artifact = self.system.retrieve_system_flow(hash)?;
+        self.read_cache.borrow_mut().insert(artifact.clone());
+        Some(artifact)
+    }
+
+    /// Borrows content from the bootstrap flow without copying it
+    ///
+    /// This is the zero-copy path for large payloads: the artifact is borrowed
+    /// straight from storage, bypassing the read cache. Only backends that hold
+    /// artifacts in memory can lend them, so with `FileBackend`, and for
+    /// content split by `with_chunking`, this returns `None`; use `retrieve` there.
+    pub fn retrieve_ref(&self, hash: &Hash) -> Option<&Artifact> {
+        self.system.retrieve_ref_system_flow(hash)
+    }
+
+    /// Checks whether content is stored under `hash` without retrieving it
+    ///
+    /// This is a membership lookup in the backend: no `Artifact` is built
+    /// and the read cache is left untouched.
+    pub fn contains(&self, hash: &Hash) -> bool {
+        self.system.contains_system_flow(hash)
+    }
+
+    /// Checks whether `content` is stored, hashing it under this store's algorithm
+    pub fn contains_content(&self, content: &[u8]) -> bool {
+        self.contains(&hash_flow_with(content, self.hash_algorithm()))
+    }
+
+    /// Warms the read cache with the given hashes ahead of retrieval
+    ///
+    /// Missing hashes are skipped. Returns how many of the hashes were found.
+    /// Prefetching itself records neither cache hits nor misses.
+    pub fn prefetch(&self, hashes: &[Hash]) -> usize {
+        let mut cache = self.read_cache.borrow_mut();
+        hashes.iter()
+            .filter(|hash| {
+                if cache.contains(hash) {
+                    return true;
+                }
+                match self.system.retrieve_system_flow(hash) {
+                    Some(artifact) => {
+                        cache.insert(artifact);
+                        true
+                    }
+                    None => false,
+                }
+            })
+            .count()
+    }
+
+    /// Gets the number of retrievals served from the read cache
+    pub fn cache_hits(&self) -> u64 {
+        self.read_cache.borrow().hits()
+    }
+
+    /// Gets the number of retrievals that missed the read cache
+    pub fn cache_misses(&self) -> u64 {
+        self.read_cache.borrow().misses()
+    }
+
+    /// Checks stored content against its hash
+    ///
+    /// Returns `None` if nothing is stored under `hash`, otherwise whether the
+    /// stored content still hashes to it.
+    pub fn verify(&self, hash: &Hash) -> Option<bool> {
+        self.retrieve(hash).map(|artifact| artifact.is_intact())
+    }
+
+    /// Writes an artifact under its recorded hash exactly as given
+    ///
+    /// Nothing is rehashed, so this can store content that fails `verify`; it
+    /// exists to restore replicas copied from another store.
+    pub fn restore(&mut self, artifact: Artifact) -> Result<(), StorageFlowError> {
+        let hash = artifact.hash.clone();
+        self.read_cache.get_mut().invalidate(&hash);
+        self.system.restore_system_flow(artifact)?;
+        self.notify_store(&hash);
+        Ok(())
+    }
+
+    /// Saves all stored content and the cycle state to `path`
+    ///
+    /// The file uses a simple length-prefixed binary format (see
+    /// `Kernel::save_flow`). Removed content is not written, so saving also
+    /// compacts. `Bootstrap::load` restores `cycle_step`, `total_divergence`
+    /// and every retrievable artifact. With `with_hash_chain`, the chain is
+    /// appended after the kernel flow (see `save_chain`).
+    pub fn save(&self, path: &Path) -> io::Result<()> {
+        let mut writer = BufWriter::new(File::create(path)?);
+        self.system.save_system_flow(&mut writer)?;
+        if self.hash_chain {
+            self.save_chain(&mut writer)?;
+        }
+        writer.flush()
+    }
+
+    /// Borrows the hashes of all stored content, in no particular order
+    ///
+    /// Hashes are lent straight from the storage backend without copying,
+    /// and the order is stable while the store is unmodified.
+    pub fn iter_hashes(&self) -> impl Iterator<Item = &Hash> + '_ {
+        self.system.system_hashes_flow()
+    }
+
+    /// Finds the hashes of all stored content containing `needle`
+    ///
+    /// Intended for debugging textual artifacts: this scans every stored byte,
+    /// so its cost grows linearly with the total size of the store.
+    pub fn find_containing(&self, needle: &[u8]) -> Vec<Hash> {
+        self.system.find_containing_system_flow(needle)
     }
 
     /// Gets the current cycle potential
@@ -184,15 +714,161 @@ impl Bootstrap {
         self.system.system_cycle_potential()
     }
 
+    /// Gets the number of steps after which the cycle wraps
+    pub fn cycle_length(&self) -> u64 {
+        self.system.system_cycle_length()
+    }
+
+    /// Gets the maximum number of stored artifacts, if capped
+    pub fn max_entries(&self) -> Option<usize> {
+        self.system.system_max_entries()
+    }
+
+    /// Gets the byte budget for live content, if capped
+    pub fn max_bytes(&self) -> Option<usize> {
+        self.system.system_max_bytes()
+    }
+
+    /// Gets the bytes left in the budget, or `usize::MAX` without one
+    pub fn remaining_bytes(&self) -> usize {
+        self.system.system_remaining_bytes()
+    }
+
+    /// Gets the algorithm stored content is hashed under
+    pub fn hash_algorithm(&self) -> HashAlgorithm {
+        self.system.system_hash_algorithm()
+    }
+
+    /// Computes the gradient between the hashes of two stored artifacts
+    ///
+    /// Returns `None` unless both hashes are stored.
+    pub fn content_gradient(&self, a: &Hash, b: &Hash) -> Option<u64> {
+        if !(self.contains(a) && self.contains(b)) {
+            return None;
+        }
+        Some(a.gradient(b))
+    }
+
     /// Computes the total system divergence
     pub fn total_divergence(&self) -> usize {
         self.system.total_system_divergence()
     }
 
+    /// Splits the total divergence into its artifact, kernel and system sources
+    pub fn divergence_breakdown(&self) -> DivergenceReport {
+        DivergenceReport {
+            artifacts: self.system.whole_system_divergence(),
+            kernel: self.system.expiring_system_divergence(),
+            system: self.system.chunked_system_divergence(),
+        }
+    }
+
     /// Computes the system curl (vorticity)
     pub fn curl(&self) -> u64 {
         self.system.system_curl()
     }
+
+    /// Counts the distinct chunks held for chunked content
+    pub fn chunk_count(&self) -> usize {
+        self.system.system_chunk_count()
+    }
+
+    /// Sums the bytes of the distinct chunks held for chunked content
+    ///
+    /// Shared regions are counted once, so this falls below the total size of
+    /// the chunked artifacts whenever they overlap.
+    pub fn chunk_bytes(&self) -> usize {
+        self.system.system_chunk_bytes()
+    }
+
+    /// Gets the `(cycle_step, curl)` recorded after each store, oldest first
+    ///
+    /// Empty unless the bootstrap was created with `with_curl_history`. A
+    /// `store_batch` is one cycle step and records a single entry.
+    pub fn curl_history(&self) -> &[(u64, u64)] {
+        self.curl_history.as_deref().unwrap_or_default()
+    }
+
+    /// Gets the hash of the most recently chained artifact
+    ///
+    /// `None` until something is stored, or unless the bootstrap was created
+    /// with `with_hash_chain`.
+    pub fn chain_head(&self) -> Option<Hash> {
+        self.chain_head.clone()
+    }
+
+    /// Follows the chain back from its head, returning link hashes in store order
+    ///
+    /// A broken link cuts the walk short, leaving only the links after it.
+    pub fn walk_chain(&self) -> Vec<Hash> {
+        let (mut links, _) = self.trace_chain();
+        links.reverse();
+        links
+    }
+
+    /// Reads a hash-chain link, returning the link stored before it
+    ///
+    /// `Some(None)` for the first link, and `None` if `hash` was never chained.
+    pub fn chain_link(&self, hash: &Hash) -> Option<Option<Hash>> {
+        self.chain_links.get(hash).cloned()
+    }
+
+    /// Checks every link from the chain head back to the first
+    ///
+    /// Each link must still be stored and its content must still match its
+    /// hash, so removing or altering any link breaks the chain.
+    pub fn verify_chain(&self) -> bool {
+        self.trace_chain().1
+    }
+
+    /// Follows the chain back from its head, newest link first
+    ///
+    /// Stops at the first link that is missing or does not match its hash;
+    /// the flag reports whether the walk reached the first link instead.
+    fn trace_chain(&self) -> (Vec<Hash>, bool) {
+        let mut links = Vec::new();
+        let mut next = self.chain_head.clone();
+        while let Some(hash) = next {
+            let intact = links.len() < self.chain_links.len()
+                && self.retrieve(&hash).is_some_and(|artifact| artifact.is_intact());
+            let Some(previous) = self.chain_links.get(&hash).filter(|_| intact) else {
+                return (links, false);
+            };
+            next = previous.clone();
+            links.push(hash);
+        }
+        (links, true)
+    }
+
+    /// Captures a snapshot of the current flow potentials
+    pub fn stats(&self) -> BootstrapStats {
+        BootstrapStats {
+            cycle_step: self.cycle_step(),
+            total_divergence: self.total_divergence(),
+            curl: self.curl(),
+            operations: self.system.system_operations_potential(),
+        }
+    }
+
+    /// Computes the flow gradient between an earlier snapshot and now
+    pub fn gradient_since(&self, earlier: &BootstrapStats) -> FlowGradient {
+        let now = self.stats();
+        let operations = now.operations.saturating_sub(earlier.operations);
+        let divergence_delta = now.total_divergence as i64 - earlier.total_divergence as i64;
+        let length = self.cycle_length();
+        let curl_delta = (now.curl + length - earlier.curl % length) % length;
+        let per_operation = |delta: f64| {
+            if operations == 0 { 0.0 } else { delta / operations as f64 }
+        };
+
+        FlowGradient {
+            operations,
+            divergence_delta,
+            curl_delta,
+            divergence_rate: per_operation(divergence_delta as f64),
+            curl_rate: per_operation(curl_delta as f64),
+        }
+    }
 }
 
 impl Default for Bootstrap {
@@ -204,6 +880,8 @@ impl Default for Bootstrap {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use std::borrow::Cow;
+    use std::collections::HashSet;
 
     #[test]
     fn test_bootstrap_flow_creation() {
@@ -234,6 +912,423 @@ mod tests {
         assert_eq!(bootstrap.cycle_step(), (initial_step + 1) % 42);
     }
 
+    #[test]
+    fn test_configurable_cycle_length() {
+        let mut short = Bootstrap::new().with_cycle_length(7);
+        for i in 0..6 {
+            short.store(format!("short {}", i).into_bytes());
+        }
+        assert_eq!(short.cycle_step(), 6);
+        short.store(b"short wrap".to_vec());
+        assert_eq!(short.cycle_step(), 0);
+
+        let mut default = Bootstrap::new();
+        assert_eq!(default.cycle_length(), DEFAULT_CYCLE_LENGTH);
+        for i in 0..7 {
+            default.store(format!("default {}", i).into_bytes());
+        }
+        assert_eq!(default.cycle_step(), 7);
+        for i in 7..42 {
+            default.store(format!("default {}", i).into_bytes());
+        }
+        assert_eq!(default.cycle_step(), 0);
+    }
+
+    #[test]
+    fn test_short_cycle_wraps_each_store() {
+        let mut bootstrap = Bootstrap::new().with_cycle_length(3);
+        let steps: Vec<u64> = (0..4)
+            .map(|i| {
+                bootstrap.store(format!("cycle {}", i).into_bytes());
+                bootstrap.cycle_step()
+            })
+            .collect();
+
+        assert_eq!(steps, vec![1, 2, 0, 1]);
+        assert_eq!(bootstrap.cycle_length(), 3);
+        assert_eq!(bootstrap.curl(), 1);
+    }
+
+    #[test]
+    fn test_try_store_surfaces_errors() {
+        let mut bootstrap = Bootstrap::new().with_max_entries(2);
+        assert_eq!(bootstrap.max_entries(), Some(2));
+        assert!(matches!(bootstrap.try_store(vec![]), Err(StorageFlowError::Empty)));
+
+        let (first, _) = bootstrap.try_store(b"first flow".to_vec()).unwrap();
+        bootstrap.try_store(b"second flow".to_vec()).unwrap();
+        assert!(matches!(
+            bootstrap.try_store(b"third flow".to_vec()),
+            Err(StorageFlowError::CapacityExceeded)
+        ));
+        // Failed stores leave the flow untouched; stored content can be stored again
+        assert_eq!(bootstrap.cycle_step(), 2);
+        assert_eq!(bootstrap.try_store(b"first flow".to_vec()).unwrap().0, first);
+
+        // Removal frees room
+        bootstrap.remove(&first);
+        assert!(bootstrap.try_store(b"third flow".to_vec()).is_ok());
+        assert_eq!(Bootstrap::new().max_entries(), None);
+    }
+
+    #[test]
+    fn test_options_combine() {
+        let mut bootstrap = Bootstrap::new()
+            .with_backend(Box::new(MemoryBackend::default()))
+            .with_cycle_length(3)
+            .with_hash_algorithm(HashAlgorithm::Sha256)
+            .with_max_entries(2)
+            .with_max_bytes(64);
+        assert_eq!(bootstrap.cycle_length(), 3);
+        assert_eq!(bootstrap.hash_algorithm(), HashAlgorithm::Sha256);
+        assert_eq!(bootstrap.max_entries(), Some(2));
+        assert_eq!(bootstrap.max_bytes(), Some(64));
+
+        let (hash, _) = bootstrap.try_store(b"combined flow".to_vec()).unwrap();
+        assert_eq!(hash.algorithm(), HashAlgorithm::Sha256);
+        bootstrap.store(b"second flow".to_vec());
+        assert!(matches!(
+            bootstrap.try_store(b"third flow".to_vec()),
+            Err(StorageFlowError::CapacityExceeded)
+        ));
+    }
+
+    #[test]
+    fn test_hash_chain_walks_in_store_order() {
+        let mut bootstrap = Bootstrap::new().with_hash_chain();
+        assert_eq!(bootstrap.chain_head(), None);
+        assert!(bootstrap.verify_chain());
+
+        let first = bootstrap.store(b"first link".to_vec());
+        let second = bootstrap.store(b"second link".to_vec());
+        let third = bootstrap.store(b"third link".to_vec());
+
+        assert_eq!(bootstrap.chain_head(), Some(third.clone()));
+        assert_eq!(bootstrap.walk_chain(), vec![first.clone(), second.clone(), third.clone()]);
+        assert_eq!(bootstrap.chain_link(&first), Some(None));
+        assert_eq!(bootstrap.chain_link(&second), Some(Some(first.clone())));
+        assert!(bootstrap.verify_chain());
+
+        // Content already in the chain keeps its place
+        assert_eq!(bootstrap.store(b"first link".to_vec()), first);
+        assert_eq!(bootstrap.chain_head(), Some(third.clone()));
+
+        // Links are saved and the chain resumes after loading
+        let path = std::env::temp_dir().join(format!("stage0-chain-{}.flow", std::process::id()));
+        bootstrap.save(&path).unwrap();
+        let mut loaded = Bootstrap::load(&path).unwrap();
+        std::fs::remove_file(&path).unwrap();
+        assert_eq!(loaded.walk_chain(), bootstrap.walk_chain());
+        assert!(loaded.verify_chain());
+        let fourth = loaded.store(b"fourth link".to_vec());
+        assert_eq!(loaded.chain_link(&fourth), Some(Some(third.clone())));
+
+        // A tampered middle link no longer matches its hash
+        bootstrap.restore(Artifact { hash: second.clone(), content: b"forged link".to_vec() }).unwrap();
+        assert!(!bootstrap.verify_chain());
+        assert_eq!(bootstrap.walk_chain(), vec![third]);
+
+        // As does a removed one
+        loaded.remove(&second);
+        assert!(!loaded.verify_chain());
+
+        let mut unchained = Bootstrap::new();
+        let loose = unchained.store(b"loose".to_vec());
+        assert_eq!(unchained.chain_head(), None);
+        assert!(unchained.walk_chain().is_empty());
+        assert_eq!(unchained.chain_link(&loose), None);
+    }
+
+    #[test]
+    fn test_hash_chain_leaves_content_untouched() {
+        let mut chained = Bootstrap::new().with_hash_chain();
+        let mut plain = Bootstrap::new();
+
+        let single = chained.store(b"chained content".to_vec());
+        let batch = chained.store_batch(vec![b"batched one".to_vec(), b"batched two".to_vec()]);
+        let expiring = chained.store_with_ttl(b"expiring content".to_vec(), 5);
+
+        assert_eq!(single, plain.store(b"chained content".to_vec()));
+        assert_eq!(single, hash_flow(b"chained content"));
+        assert_eq!(chained.retrieve(&single).unwrap().content, b"chained content");
+        assert_eq!(chained.retrieve(&batch[1]).unwrap().content, b"batched two");
+        assert_eq!(chained.retrieve(&expiring).unwrap().content, b"expiring content");
+        assert!(chained.contains_content(b"chained content"));
+        assert!(chained.contains_content(b"batched one"));
+        assert_eq!(chained.walk_chain(), vec![single, batch[0].clone(), batch[1].clone(), expiring]);
+    }
+
+    #[test]
+    fn test_failed_store_leaves_budget_untouched() {
+        let root = std::env::temp_dir().join(format!("stage0-failed-put-{}", std::process::id()));
+        let backend = FileBackend::new(&root).unwrap();
+        let mut bootstrap = Bootstrap::new().with_backend(Box::new(backend)).with_max_bytes(100);
+        let (_, remaining) = bootstrap.try_store(b"stored flow".to_vec()).unwrap();
+        assert_eq!(remaining, 89);
+
+        // With its directory gone, the backend can no longer put artifacts
+        std::fs::remove_dir_all(&root).unwrap();
+        assert!(matches!(bootstrap.try_store(b"lost flow".to_vec()), Err(StorageFlowError::Io(_))));
+        assert_eq!(bootstrap.remaining_bytes(), 89);
+    }
+
+    #[test]
+    fn test_try_store_reports_remaining_budget() {
+        let mut bootstrap = Bootstrap::new().with_max_bytes(10);
+        assert_eq!(bootstrap.max_bytes(), Some(10));
+
+        let (first, remaining) = bootstrap.try_store(b"four".to_vec()).unwrap();
+        assert_eq!(remaining, 6);
+        let (_, remaining) = bootstrap.try_store(b"sixsix".to_vec()).unwrap();
+        assert_eq!(remaining, 0);
+        assert!(matches!(
+            bootstrap.try_store(b"x".to_vec()),
+            Err(StorageFlowError::BudgetExceeded)
+        ));
+        assert_eq!(bootstrap.cycle_step(), 2);
+
+        // Stored content needs no new budget, and removal frees it
+        assert_eq!(bootstrap.try_store(b"four".to_vec()).unwrap(), (first.clone(), 0));
+        bootstrap.remove(&first);
+        assert_eq!(bootstrap.remaining_bytes(), 4);
+        assert_eq!(bootstrap.try_store(b"x".to_vec()).unwrap().1, 3);
+        assert_eq!(Bootstrap::new().remaining_bytes(), usize::MAX);
+    }
+
+    #[test]
+    #[should_panic(expected = "bootstrap store failed")]
+    fn test_store_panics_on_empty_content() {
+        Bootstrap::new().store(Vec::new());
+    }
+
+    #[derive(Debug, Clone, PartialEq)]
+    enum StoreEvent {
+        Stored(Hash),
+        Removed(Hash),
+    }
+
+    struct RecordingObserver(std::sync::Arc<std::sync::Mutex<Vec<StoreEvent>>>);
+
+    impl StoreObserver for RecordingObserver {
+        fn on_store(&mut self, hash: &Hash, artifact: &Artifact) {
+            assert_eq!(&artifact.hash, hash);
+            self.0.lock().unwrap().push(StoreEvent::Stored(hash.clone()));
+        }
+
+        fn on_remove(&mut self, hash: &Hash) {
+            self.0.lock().unwrap().push(StoreEvent::Removed(hash.clone()));
+        }
+    }
+
+    #[test]
+    fn test_observers_record_changes_in_order() {
+        let mut bootstrap = Bootstrap::new();
+        let first_log = std::sync::Arc::new(std::sync::Mutex::new(Vec::new()));
+        let second_log = std::sync::Arc::new(std::sync::Mutex::new(Vec::new()));
+        bootstrap.subscribe(Box::new(RecordingObserver(first_log.clone())));
+        bootstrap.subscribe(Box::new(RecordingObserver(second_log.clone())));
+
+        let a = bootstrap.store(b"observed a".to_vec());
+        let b = bootstrap.store(b"observed b".to_vec());
+        bootstrap.remove(&a);
+        // Removing missing content notifies nobody
+        bootstrap.remove(&a);
+        let batch = bootstrap.store_batch(vec![b"observed c".to_vec()]);
+        bootstrap.remove(&b);
+
+        let expected = vec![
+            StoreEvent::Stored(a.clone()),
+            StoreEvent::Stored(b.clone()),
+            StoreEvent::Removed(a),
+            StoreEvent::Stored(batch[0].clone()),
+            StoreEvent::Removed(b),
+        ];
+        assert_eq!(*first_log.lock().unwrap(), expected);
+        assert_eq!(*second_log.lock().unwrap(), expected);
+    }
+
+    /// Deterministic pseudo-random bytes, so chunk boundaries are realistic
+    fn noise(len: usize, seed: u64) -> Vec<u8> {
+        let mut state = seed.max(1);
+        (0..len).map(|_| {
+            state ^= state << 13;
+            state ^= state >> 7;
+            state ^= state << 17;
+            state as u8
+        }).collect()
+    }
+
+    #[test]
+    fn test_chunking_shares_common_regions() {
+        let mut bootstrap = Bootstrap::new().with_chunking();
+        let prefix = noise(1 << 20, 1);
+        let first: Vec<u8> = prefix.iter().copied().chain(noise(256 * 1024, 2)).collect();
+        let second: Vec<u8> = prefix.iter().copied().chain(noise(256 * 1024, 3)).collect();
+
+        let a = bootstrap.store(first.clone());
+        let b = bootstrap.store(second.clone());
+
+        assert!(bootstrap.chunk_bytes() < first.len() + second.len());
+        // Only the regions past the shared prefix are stored twice
+        assert!(bootstrap.chunk_bytes() < prefix.len() + 2 * 256 * 1024 + 2 * chunk::MAX_CHUNK_SIZE);
+        assert_eq!(bootstrap.retrieve(&a).unwrap().content_flow(), first.as_slice());
+        assert_eq!(bootstrap.retrieve(&b).unwrap().content_flow(), second.as_slice());
+        assert_eq!(bootstrap.verify(&b), Some(true));
+        assert_eq!(bootstrap.total_divergence(), 2);
+        assert!(bootstrap.retrieve_ref(&a).is_none());
+        assert_eq!(bootstrap.find_containing(&second[second.len() - 100..]), vec![b.clone()]);
+
+        // Small content is stored whole
+        let small = bootstrap.store(b"small flow".to_vec());
+        assert!(bootstrap.retrieve_ref(&small).is_some());
+
+        // Chunks only shared with a removed artifact are erased by compaction
+        let chunks = bootstrap.chunk_count();
+        bootstrap.remove(&a);
+        bootstrap.compact();
+        assert!(bootstrap.chunk_count() < chunks);
+        assert_eq!(bootstrap.retrieve(&b).unwrap().content_flow(), second.as_slice());
+    }
+
+    #[test]
+    fn test_curl_history_tracks_each_store() {
+        let mut bootstrap = Bootstrap::new().with_curl_history();
+        for i in 0..10 {
+            bootstrap.store(format!("curl flow {}", i).into_bytes());
+        }
+
+        let history = bootstrap.curl_history();
+        assert_eq!(history.len(), 10);
+        for window in history.windows(2) {
+            assert_eq!(window[1].0, (window[0].0 + 1) % bootstrap.cycle_length());
+        }
+        assert_eq!(history[9], (bootstrap.cycle_step(), bootstrap.curl()));
+
+        let mut untracked = Bootstrap::new();
+        untracked.store(b"untracked flow".to_vec());
+        assert!(untracked.curl_history().is_empty());
+    }
+
+    #[test]
+    fn test_divergence_breakdown_sums_to_total() {
+        let mut bootstrap = Bootstrap::new();
+        assert_eq!(bootstrap.divergence_breakdown().total(), 0);
+
+        let kept = bootstrap.store(b"kept flow".to_vec());
+        bootstrap.store(b"another kept flow".to_vec());
+        let expiring = bootstrap.store_with_ttl(b"expiring flow".to_vec(), 1);
+        bootstrap.store_with_ttl(b"second expiring flow".to_vec(), 10);
+        bootstrap.store(b"kept flow".to_vec());
+
+        let report = bootstrap.divergence_breakdown();
+        assert_eq!(report, DivergenceReport { artifacts: 2, kernel: 2, system: 0 });
+        assert_eq!(report.total(), bootstrap.total_divergence());
+
+        bootstrap.remove(&kept);
+        bootstrap.remove(&expiring);
+        let report = bootstrap.divergence_breakdown();
+        assert_eq!((report.artifacts, report.kernel), (1, 1));
+        assert_eq!(report.total(), bootstrap.total_divergence());
+    }
+
+    #[test]
+    fn test_divergence_breakdown_across_layers() {
+        let mut bootstrap = Bootstrap::new().with_chunking();
+        bootstrap.store(b"whole flow".to_vec());
+        let large = bootstrap.store(noise(3 * chunk::CHUNKING_THRESHOLD, 5));
+        bootstrap.store(noise(2 * chunk::CHUNKING_THRESHOLD, 6));
+        // Expiring content belongs to the kernel cycle, even when chunked
+        bootstrap.store_with_ttl(noise(2 * chunk::CHUNKING_THRESHOLD, 7), 10);
+        bootstrap.store_with_ttl(b"expiring flow".to_vec(), 10);
+
+        let report = bootstrap.divergence_breakdown();
+        assert_eq!(report, DivergenceReport { artifacts: 1, kernel: 2, system: 2 });
+        assert_eq!(report.total(), bootstrap.total_divergence());
+
+        // Each component moves only with its own source
+        bootstrap.remove(&large);
+        assert_eq!(bootstrap.divergence_breakdown(), DivergenceReport { artifacts: 1, kernel: 2, system: 1 });
+
+        for i in 0..12 {
+            bootstrap.store(format!("advance {}", i).into_bytes());
+        }
+        assert_eq!(bootstrap.divergence_breakdown().artifacts, 13);
+        assert_eq!(bootstrap.purge_expired(), 2);
+        let report = bootstrap.divergence_breakdown();
+        assert_eq!(report, DivergenceReport { artifacts: 13, kernel: 0, system: 1 });
+        assert_eq!(report.total(), bootstrap.total_divergence());
+    }
+
+    #[test]
+    fn test_contains_without_retrieval() {
+        let mut bootstrap = Bootstrap::new().with_hash_algorithm(HashAlgorithm::Sha256);
+        let hash = bootstrap.store(b"present flow".to_vec());
+
+        assert!(bootstrap.contains(&hash));
+        assert!(bootstrap.contains_content(b"present flow"));
+        assert!(!bootstrap.contains_content(b"unseen flow"));
+        // Membership checks bypass the read cache entirely
+        assert_eq!((bootstrap.cache_hits(), bootstrap.cache_misses()), (0, 0));
+
+        bootstrap.remove(&hash);
+        assert!(!bootstrap.contains(&hash));
+        assert!(!bootstrap.contains_content(b"present flow"));
+    }
+
+    #[test]
+    fn test_store_checked_reports_new_content() {
+        let mut bootstrap = Bootstrap::new();
+        let (hash, added) = bootstrap.store_checked(b"fresh flow".to_vec());
+        assert!(added);
+
+        let (again, added) = bootstrap.store_checked(b"fresh flow".to_vec());
+        assert_eq!(again, hash);
+        assert!(!added);
+        assert_eq!(bootstrap.total_divergence(), 1);
+
+        bootstrap.remove(&hash);
+        assert_eq!(bootstrap.store_checked(b"fresh flow".to_vec()), (hash, true));
+    }
+
+    #[test]
+    fn test_store_batch_advances_cycle_once() {
+        let mut bootstrap = Bootstrap::new();
+        bootstrap.store(b"before".to_vec());
+        let cycle = bootstrap.cycle_step();
+        let divergence = bootstrap.total_divergence();
+
+        let items: Vec<Vec<u8>> = (0..5).map(|i| format!("batch item {}", i).into_bytes()).collect();
+        let hashes = bootstrap.store_batch(items.clone());
+
+        assert_eq!(bootstrap.cycle_step(), cycle + 1);
+        assert_eq!(bootstrap.total_divergence(), divergence + 5);
+        for (hash, item) in hashes.iter().zip(&items) {
+            assert_eq!(bootstrap.retrieve(hash).unwrap().content_flow(), item.as_slice());
+        }
+
+        bootstrap.store_batch(Vec::new());
+        assert_eq!(bootstrap.cycle_step(), cycle + 1);
+    }
+
+    #[test]
+    fn test_retrieve_ref_borrows_stored_bytes() {
+        let mut bootstrap = Bootstrap::new();
+        let payload: Vec<u8> = (0..1 << 20).map(|i| (i % 251) as u8).collect();
+        let hash = bootstrap.store(payload.clone());
+
+        let borrowed = bootstrap.retrieve_ref(&hash).unwrap();
+        assert_eq!(borrowed.content_flow().len(), payload.len());
+        assert_eq!(borrowed.content_flow(), payload.as_slice());
+        // Both borrows point at the same stored bytes rather than fresh copies
+        let again = bootstrap.retrieve_ref(&hash).unwrap();
+        assert_eq!(borrowed.content_flow().as_ptr(), again.content_flow().as_ptr());
+        assert_ne!(bootstrap.retrieve(&hash).unwrap().content_flow().as_ptr(), borrowed.content_flow().as_ptr());
+
+        assert!(bootstrap.retrieve_ref(&hash_flow(b"never stored")).is_none());
+        bootstrap.remove(&hash);
+        assert!(bootstrap.retrieve_ref(&hash).is_none());
+    }
+
     #[test]
     fn test_flow_divergence() {
         let mut bootstrap = Bootstrap::new();
@@ -244,4 +1339,397 @@ mod tests {
         
         assert_eq!(bootstrap.total_divergence(), 2);
     }
-} 
\ No newline at end of file
+
+    /// Store/retrieve behavior every storage backend must share
+    fn backend_suite(mut bootstrap: Bootstrap) {
+        let first = bootstrap.store(b"backend flow".to_vec());
+        let second = bootstrap.store(b"another flow".to_vec());
+        let duplicate = bootstrap.store(b"backend flow".to_vec());
+
+        assert_eq!(first, duplicate);
+        assert_ne!(first, second);
+        assert_eq!(bootstrap.total_divergence(), 2);
+        assert_eq!(bootstrap.retrieve(&first).unwrap().content_flow(), b"backend flow");
+        assert_eq!(bootstrap.retrieve(&second).unwrap().content_flow(), b"another flow");
+        assert!(bootstrap.retrieve(&hash_flow(b"never stored")).is_none());
+        assert_eq!(bootstrap.iter_hashes().collect::<HashSet<_>>(), HashSet::from([&first, &second]));
+        assert!(bootstrap.contains(&first) && bootstrap.contains_content(b"backend flow"));
+        assert!(!bootstrap.contains_content(b"never stored"));
+        assert_eq!(bootstrap.find_containing(b"another"), vec![second]);
+
+        let expiring = bootstrap.store_with_ttl(b"short lived".to_vec(), 0);
+        bootstrap.store(b"advance".to_vec());
+        assert_eq!(bootstrap.purge_expired(), 1);
+        assert!(bootstrap.retrieve(&expiring).is_none());
+        assert_eq!(bootstrap.total_divergence(), 3);
+    }
+
+    #[test]
+    fn test_memory_backend() {
+        backend_suite(Bootstrap::new().with_backend(Box::new(MemoryBackend::default())));
+    }
+
+    #[test]
+    fn test_file_backend() {
+        let root = std::env::temp_dir().join(format!("stage0-file-backend-{}", std::process::id()));
+        let backend = FileBackend::new(&root).unwrap();
+
+        backend_suite(Bootstrap::new().with_backend(Box::new(backend)));
+
+        // Reopening the directory indexes the artifacts already in it
+        let reopened = FileBackend::new(&root).unwrap();
+        assert_eq!(reopened.len(), 3);
+        assert!(reopened.contains(&hash_flow(b"backend flow")));
+
+        std::fs::remove_dir_all(&root).unwrap();
+    }
+
+    #[test]
+    fn test_save_and_load_round_trip() {
+        let path = std::env::temp_dir().join(format!("stage0-snapshot-{}.flow", std::process::id()));
+        let mut bootstrap = Bootstrap::new().with_cycle_length(5);
+        let contents: Vec<Vec<u8>> = (0..7).map(|i| format!("durable flow {}", i).into_bytes()).collect();
+        let hashes: Vec<Hash> = contents.iter().map(|content| bootstrap.store(content.clone())).collect();
+        let removed = bootstrap.store(b"removed before saving".to_vec());
+        bootstrap.remove(&removed);
+        bootstrap.save(&path).unwrap();
+
+        let loaded = Bootstrap::load(&path).unwrap();
+        std::fs::remove_file(&path).unwrap();
+
+        assert_eq!(loaded.cycle_step(), bootstrap.cycle_step());
+        assert_eq!(loaded.cycle_length(), 5);
+        assert_eq!(loaded.total_divergence(), bootstrap.total_divergence());
+        assert_eq!(loaded.stats(), bootstrap.stats());
+        for (hash, content) in hashes.iter().zip(&contents) {
+            assert_eq!(loaded.retrieve(hash).unwrap().content_flow(), content.as_slice());
+        }
+        assert!(loaded.retrieve(&removed).is_none());
+
+        let mut truncated = Vec::new();
+        bootstrap.system.save_system_flow(&mut truncated).unwrap();
+        truncated.truncate(truncated.len() - 3);
+        assert!(System::load_system_flow(truncated.as_slice()).is_err());
+        assert_eq!(
+            System::load_system_flow(&b"NOTAFLOW"[..]).err().map(|error| error.kind()),
+            Some(io::ErrorKind::InvalidData)
+        );
+    }
+
+    #[test]
+    fn test_verify_and_restore() {
+        let mut bootstrap = Bootstrap::new();
+        let hash = bootstrap.store(b"trusted flow".to_vec());
+        assert_eq!(bootstrap.verify(&hash), Some(true));
+        assert_eq!(bootstrap.verify(&hash_flow(b"absent flow")), None);
+
+        bootstrap.restore(Artifact { hash: hash.clone(), content: b"tampered".to_vec() }).unwrap();
+        assert_eq!(bootstrap.verify(&hash), Some(false));
+
+        bootstrap.restore(artifact_flow(b"trusted flow".to_vec())).unwrap();
+        assert_eq!(bootstrap.verify(&hash), Some(true));
+        assert_eq!(bootstrap.cycle_step(), 1);
+    }
+
+    #[test]
+    fn test_prefetch_warms_read_cache() {
+        let mut bootstrap = Bootstrap::new();
+        let hashes: Vec<Hash> = (0..3).map(|i| bootstrap.store(format!("warm {}", i).into_bytes())).collect();
+        let missing = hash_flow(b"never stored");
+        let mut requested = hashes.clone();
+        requested.push(missing.clone());
+
+        assert_eq!(bootstrap.prefetch(&requested), 3);
+        assert_eq!((bootstrap.cache_hits(), bootstrap.cache_misses()), (0, 0));
+
+        for hash in &hashes {
+            assert!(bootstrap.retrieve(hash).is_some());
+        }
+        assert_eq!(bootstrap.cache_hits(), 3);
+        assert_eq!(bootstrap.cache_misses(), 0);
+
+        assert!(bootstrap.retrieve(&missing).is_none());
+        assert_eq!(bootstrap.cache_misses(), 1);
+    }
+
+    #[test]
+    fn test_read_cache_evicts_least_recently_used() {
+        let mut cache = ReadCache::with_capacity(2);
+        let [a, b, c] = [b"a", b"b", b"c"].map(|content| artifact_flow(content.to_vec()));
+        cache.insert(a.clone());
+        cache.insert(b.clone());
+        assert!(cache.get(&a.hash).is_some());
+
+        cache.insert(c.clone());
+        assert!(cache.contains(&a.hash));
+        assert!(!cache.contains(&b.hash));
+        assert!(cache.contains(&c.hash));
+        assert_eq!(cache.len(), 2);
+    }
+
+    #[test]
+    fn test_flow_ttl_expiry() {
+        let mut bootstrap = Bootstrap::new();
+
+        let expiring = bootstrap.store_with_ttl(b"cached flow".to_vec(), 3);
+        let permanent = bootstrap.store(b"permanent flow".to_vec());
+
+        // Still alive after fewer than TTL advances
+        bootstrap.store(b"advance 2".to_vec());
+        bootstrap.store(b"advance 3".to_vec());
+        assert_eq!(bootstrap.purge_expired(), 0);
+        assert!(bootstrap.retrieve(&expiring).is_some());
+
+        // Fourth advance since the store pushes it past its TTL
+        bootstrap.store(b"advance 4".to_vec());
+        assert_eq!(bootstrap.purge_expired(), 1);
+
+        assert!(bootstrap.retrieve(&expiring).is_none());
+        assert!(bootstrap.retrieve(&permanent).is_some());
+        assert_eq!(bootstrap.total_divergence(), 4);
+    }
+
+    #[test]
+    fn test_ttl_store_keeps_permanent_content() {
+        let mut bootstrap = Bootstrap::new();
+        let permanent = bootstrap.store(b"permanent flow".to_vec());
+        assert_eq!(bootstrap.store_with_ttl(b"permanent flow".to_vec(), 0), permanent);
+
+        let expiring = bootstrap.store_with_ttl(b"expiring flow".to_vec(), 5);
+        // A shorter TTL does not cut the earlier, later expiry short
+        bootstrap.store_with_ttl(b"expiring flow".to_vec(), 0);
+
+        bootstrap.store(b"advance 1".to_vec());
+        bootstrap.store(b"advance 2".to_vec());
+        assert_eq!(bootstrap.purge_expired(), 0);
+        assert!(bootstrap.retrieve(&permanent).is_some());
+        assert!(bootstrap.retrieve(&expiring).is_some());
+
+        for i in 0..4 {
+            bootstrap.store(format!("advance {}", i + 3).into_bytes());
+        }
+        assert_eq!(bootstrap.purge_expired(), 1);
+        assert!(bootstrap.retrieve(&permanent).is_some());
+        assert!(bootstrap.retrieve(&expiring).is_none());
+    }
+
+    #[test]
+    fn test_gradient_since() {
+        let mut bootstrap = Bootstrap::new();
+        bootstrap.store(b"initial flow".to_vec());
+        let earlier = bootstrap.stats();
+
+        let gradient = bootstrap.gradient_since(&earlier);
+        assert_eq!(gradient.operations, 0);
+        assert_eq!(gradient.divergence_rate, 0.0);
+
+        for i in 0..4 {
+            bootstrap.store(format!("flow {}", i).into_bytes());
+        }
+        // Storing duplicate content advances the cycle without adding divergence
+        bootstrap.store(b"initial flow".to_vec());
+
+        let gradient = bootstrap.gradient_since(&earlier);
+        assert_eq!(gradient.operations, 5);
+        assert_eq!(gradient.divergence_delta, 4);
+        assert_eq!(gradient.curl_delta, 5);
+        assert!((gradient.divergence_rate - 0.8).abs() < 1e-12);
+        assert!((gradient.curl_rate - 1.0).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_hash_algorithm_tag() {
+        let field = *hash_flow(b"tagged flow").flow_field();
+        let flow = Hash::from_raw(field, HashAlgorithm::Flow);
+        let fnv = Hash::from_raw(field, HashAlgorithm::Fnv);
+
+        assert_eq!(flow.flow_field(), fnv.flow_field());
+        assert_eq!(flow.algorithm(), HashAlgorithm::Flow);
+        assert_ne!(flow, fnv);
+
+        // Same bytes under another algorithm must not alias in storage
+        let mut storage = storage_flow();
+        storage.store_flow(Artifact { hash: flow.clone(), content: b"tagged flow".to_vec() }).unwrap();
+        assert!(storage.retrieve_flow(&flow).is_some());
+        assert!(storage.retrieve_flow(&fnv).is_none());
+    }
+
+    #[test]
+    fn test_remove_and_compact() {
+        let mut bootstrap = Bootstrap::new();
+        let kept = bootstrap.store(b"kept flow".to_vec());
+        let removed = bootstrap.store(b"removed flow".to_vec());
+        bootstrap.store(b"other flow".to_vec());
+        assert_eq!(bootstrap.total_divergence(), 3);
+        assert!(bootstrap.retrieve(&removed).is_some());
+
+        let artifact = bootstrap.remove(&removed).unwrap();
+        assert_eq!(artifact.content_flow(), b"removed flow");
+        assert_eq!(bootstrap.total_divergence(), 2);
+        assert!(bootstrap.retrieve(&removed).is_none());
+        assert!(bootstrap.remove(&removed).is_none());
+        assert!(bootstrap.find_containing(b"removed").is_empty());
+
+        assert_eq!(bootstrap.compact(), 1);
+        assert_eq!(bootstrap.compact(), 0);
+        assert_eq!(bootstrap.total_divergence(), 2);
+        assert!(bootstrap.retrieve(&kept).is_some());
+
+        // Storing removed content again revives it
+        bootstrap.store(b"removed flow".to_vec());
+        assert!(bootstrap.retrieve(&removed).is_some());
+        assert_eq!(bootstrap.total_divergence(), 3);
+    }
+
+    #[test]
+    fn test_iter_hashes() {
+        let mut bootstrap = Bootstrap::new();
+        let stored: HashSet<Hash> = (0..5)
+            .map(|i| bootstrap.store(format!("payload {}", i).into_bytes()))
+            .collect();
+        bootstrap.store(b"payload 0".to_vec());
+
+        let walked: Vec<Hash> = bootstrap.iter_hashes().cloned().collect();
+        assert_eq!(walked.len(), 5);
+        assert_eq!(walked.iter().cloned().collect::<HashSet<_>>(), stored);
+        assert!(bootstrap.iter_hashes().eq(&walked));
+
+        let removed = walked[0].clone();
+        bootstrap.remove(&removed);
+        assert_eq!(bootstrap.iter_hashes().count(), 4);
+        assert!(bootstrap.iter_hashes().all(|hash| *hash != removed));
+
+        let storage_field = {
+            let mut storage = storage_flow();
+            storage.store_flow(artifact_flow(b"entry flow".to_vec())).unwrap();
+            storage
+        };
+        let entries: Vec<_> = storage_field.entries().collect();
+        assert_eq!(entries.len(), 1);
+        assert_eq!(*entries[0].0, entries[0].1.hash);
+        assert_eq!(entries[0].1.content_flow(), b"entry flow");
+        // Entries are lent from an in-memory backend, not copied out of it
+        assert!(matches!(&entries[0].1, Cow::Borrowed(artifact)
+            if std::ptr::eq(*artifact, storage_field.retrieve_ref_flow(entries[0].0).unwrap())));
+    }
+
+    #[test]
+    fn test_storage_entries_on_file_backend() {
+        let root = std::env::temp_dir().join(format!("stage0-file-entries-{}", std::process::id()));
+        let mut storage = Storage::with_backend(Box::new(FileBackend::new(&root).unwrap()));
+        let payloads: Vec<Vec<u8>> = (0..3).map(|i| format!("file entry {}", i).into_bytes()).collect();
+        for payload in &payloads {
+            storage.store_flow(artifact_flow(payload.clone())).unwrap();
+        }
+
+        let mut contents: Vec<Vec<u8>> = storage.entries()
+            .inspect(|(hash, artifact)| assert_eq!(**hash, artifact.hash))
+            .map(|(_, artifact)| artifact.into_owned().content)
+            .collect();
+        contents.sort();
+        assert_eq!(contents, payloads);
+
+        std::fs::remove_dir_all(&root).unwrap();
+    }
+
+    #[test]
+    fn test_storage_clone_is_independent() {
+        let kept = artifact_flow(b"kept".to_vec());
+        let mut storage = Storage::new_field();
+        storage.store_flow(kept.clone()).unwrap();
+
+        let mut copy = storage.clone();
+        copy.store_flow(artifact_flow(b"only in the copy".to_vec())).unwrap();
+        copy.remove_flow(&kept.hash);
+
+        assert!(storage.contains_flow(&kept.hash));
+        assert_eq!(storage.field_curl(), 1);
+        assert_eq!(copy.field_curl(), 1);
+    }
+
+    #[test]
+    fn test_digest_vectors() {
+        let hex = |hash: Hash| hash.flow_field().iter().map(|b| format!("{:02x}", b)).collect::<String>();
+
+        assert_eq!(
+            hex(hash_flow_with(b"abc", HashAlgorithm::Sha256)),
+            "ba7816bf8f01cfea414140de5dae2223b00361a396177a9cb410ff61f20015ad"
+        );
+        assert_eq!(
+            hex(hash_flow_with(b"", HashAlgorithm::Blake3)),
+            "af1349b9f5f9a1a6a0404dea36dcc9499bcb25c9adc112b7cc9a93cae41f3262"
+        );
+        assert_eq!(
+            hex(hash_flow_with(b"abc", HashAlgorithm::Blake3)),
+            "6437b3ac38465133ffb63b75273a8db548c558465d79db03fd359c6cd5bd9d85"
+        );
+        // Spans two chunks, so the root is a parent node
+        let long: Vec<u8> = (0..1025).map(|i| (i % 251) as u8).collect();
+        assert_eq!(
+            hex(hash_flow_with(&long, HashAlgorithm::Blake3)),
+            "d00278ae47eb27b34faecf67b4fe263f82d5412916c1ffd97c8cb7fb814b8444"
+        );
+        assert_eq!(hash_flow(b"abc"), hash_flow_with(b"abc", HashAlgorithm::Flow));
+    }
+
+    #[test]
+    fn test_hash_algorithm_selection() {
+        let content = b"pluggable flow".to_vec();
+        let algorithms = [HashAlgorithm::Flow, HashAlgorithm::Fnv, HashAlgorithm::Sha256, HashAlgorithm::Blake3];
+
+        let hashes: Vec<Hash> = algorithms.iter()
+            .map(|&algorithm| {
+                let mut bootstrap = Bootstrap::new().with_hash_algorithm(algorithm);
+                assert_eq!(bootstrap.hash_algorithm(), algorithm);
+                let hash = bootstrap.store(content.clone());
+                assert_eq!(hash.algorithm(), algorithm);
+                assert_eq!(bootstrap.retrieve(&hash).unwrap().content_flow(), content.as_slice());
+                assert_eq!(bootstrap.verify(&hash), Some(true));
+                hash
+            })
+            .collect();
+
+        let distinct: HashSet<&[u8; 32]> = hashes.iter().map(Hash::flow_field).collect();
+        assert_eq!(distinct.len(), algorithms.len());
+        assert_eq!(Bootstrap::new().hash_algorithm(), HashAlgorithm::Flow);
+        // A hash from another algorithm does not retrieve the same bytes
+        let mut sha = Bootstrap::new().with_hash_algorithm(HashAlgorithm::Sha256);
+        sha.store(content.clone());
+        assert!(sha.retrieve(&hashes[3]).is_none());
+    }
+
+    #[test]
+    fn test_hash_gradient() {
+        let hash = hash_flow(b"gradient flow");
+        assert_eq!(hash.gradient(&hash), 0);
+
+        let mut field = *hash.flow_field();
+        field[31] ^= 0b0000_0100;
+        let neighbour = Hash::from_raw(field, HashAlgorithm::Flow);
+        assert_eq!(hash.gradient(&neighbour), 1);
+        assert_eq!(neighbour.gradient(&hash), 1);
+
+        let mut bootstrap = Bootstrap::new();
+        let a = bootstrap.store(b"left flow".to_vec());
+        let b = bootstrap.store(b"right flow".to_vec());
+        assert_eq!(bootstrap.content_gradient(&a, &a), Some(0));
+        assert_eq!(bootstrap.content_gradient(&a, &b), Some(a.gradient(&b)));
+        assert_eq!(bootstrap.content_gradient(&a, &neighbour), None);
+    }
+
+    #[test]
+    fn test_find_containing() {
+        let mut bootstrap = Bootstrap::new();
+        let greeting = bootstrap.store(b"hello flow field".to_vec());
+        let farewell = bootstrap.store(b"goodbye potential".to_vec());
+        let echo = bootstrap.store(b"the flow returns".to_vec());
+
+        let found = bootstrap.find_containing(b"flow");
+
+        assert_eq!(found.len(), 2);
+        assert!(found.contains(&greeting));
+        assert!(found.contains(&echo));
+        assert!(!found.contains(&farewell));
+        assert!(bootstrap.find_containing(b"vortex").is_empty());
+    }
+}
@@ -0,0 +1,16 @@
+//! Observer - A potential flow for change notification
+//! This represents listeners resonating with every change to the flow field
+//! Observers are called synchronously, in the order they subscribed
+
+use crate::hash::Hash;
+use crate::artifact::Artifact;
+
+/// The StoreObserver potential flow
+/// Receives a callback whenever content enters or leaves the bootstrap flow
+pub trait StoreObserver: Send {
+    /// Called after content is stored under `hash`
+    fn on_store(&mut self, hash: &Hash, artifact: &Artifact);
+
+    /// Called after the content under `hash` is removed
+    fn on_remove(&mut self, hash: &Hash);
+}
@@ -2,39 +2,269 @@
 //! This represents the flow of artifacts through storage space
 //! Each storage operation is a potential in the storage field
 
-use std::collections::HashMap;
-use crate::hash::Hash;
+use std::borrow::Cow;
+use std::collections::{HashMap, HashSet};
+use std::fs;
+use std::path::PathBuf;
+use crate::hash::{Hash, HashAlgorithm};
 use crate::artifact::Artifact;
 
+/// The storage backend potential
+/// Where the artifacts of a storage field physically materialize
+/// Backends are `Send` so a bootstrap can move between threads
+pub trait StorageBackend: std::fmt::Debug + Send {
+    /// Gets the artifact stored under a hash potential
+    fn get(&self, hash: &Hash) -> Option<Artifact>;
+
+    /// Borrows the artifact stored under a hash potential without copying it
+    /// Backends that do not hold artifacts in memory return `None`
+    fn get_ref(&self, _hash: &Hash) -> Option<&Artifact> {
+        None
+    }
+
+    /// Checks whether an artifact is stored under a hash potential without reading it
+    fn contains(&self, hash: &Hash) -> bool {
+        self.get(hash).is_some()
+    }
+
+    /// Puts an artifact under its own hash potential, replacing any previous one
+    fn put(&mut self, artifact: Artifact) -> Result<(), StorageFlowError>;
+
+    /// Removes an artifact, returning it if present
+    fn remove(&mut self, hash: &Hash) -> Option<Artifact>;
+
+    /// Borrows every stored hash potential in no particular order
+    fn hashes(&self) -> Box<dyn Iterator<Item = &Hash> + '_>;
+
+    /// Counts the stored artifacts
+    fn len(&self) -> usize;
+
+    /// Checks whether no artifacts are stored
+    fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Clones the backend behind a box, so a `Storage` over it stays `Clone`
+    fn box_clone(&self) -> Box<dyn StorageBackend>;
+}
+
+impl Clone for Box<dyn StorageBackend> {
+    fn clone(&self) -> Self {
+        self.box_clone()
+    }
+}
+
+/// The in-memory storage backend
+#[derive(Debug, Clone, Default)]
+pub struct MemoryBackend {
+    field: HashMap<Hash, Artifact>,
+}
+
+impl StorageBackend for MemoryBackend {
+    fn get(&self, hash: &Hash) -> Option<Artifact> {
+        self.field.get(hash).cloned()
+    }
+
+    fn get_ref(&self, hash: &Hash) -> Option<&Artifact> {
+        self.field.get(hash)
+    }
+
+    fn contains(&self, hash: &Hash) -> bool {
+        self.field.contains_key(hash)
+    }
+
+    fn put(&mut self, artifact: Artifact) -> Result<(), StorageFlowError> {
+        self.field.insert(artifact.hash.clone(), artifact);
+        Ok(())
+    }
+
+    fn remove(&mut self, hash: &Hash) -> Option<Artifact> {
+        self.field.remove(hash)
+    }
+
+    fn hashes(&self) -> Box<dyn Iterator<Item = &Hash> + '_> {
+        Box::new(self.field.keys())
+    }
+
+    fn len(&self) -> usize {
+        self.field.len()
+    }
+
+    fn box_clone(&self) -> Box<dyn StorageBackend> {
+        Box::new(self.clone())
+    }
+}
+
+/// The file-directory storage backend
+/// Each artifact is one file in the root directory, named by the hex of its
+/// algorithm tag followed by its hash field
+/// The hashes are indexed in memory when the backend opens, so files added
+/// to the directory behind its back are not seen
+#[derive(Debug, Clone)]
+pub struct FileBackend {
+    root: PathBuf,
+    index: HashSet<Hash>,
+}
+
+impl FileBackend {
+    /// Opens a backend rooted at `root`, creating the directory if needed
+    /// Artifacts already in the directory are indexed, ignoring foreign files
+    pub fn new(root: impl Into<PathBuf>) -> Result<Self, StorageFlowError> {
+        let root = root.into();
+        fs::create_dir_all(&root)?;
+        let mut index = HashSet::new();
+        for entry in fs::read_dir(&root)? {
+            if let Some(hash) = entry?.file_name().to_str().and_then(Self::hash_of) {
+                index.insert(hash);
+            }
+        }
+        Ok(Self { root, index })
+    }
+
+    /// Returns the path of the file holding a hash potential
+    fn path_of(&self, hash: &Hash) -> PathBuf {
+        let mut name = format!("{:02x}", hash.algorithm().tag());
+        for byte in hash.flow_field() {
+            name.push_str(&format!("{:02x}", byte));
+        }
+        self.root.join(name)
+    }
+
+    /// Recovers a hash potential from a file name, ignoring foreign files
+    fn hash_of(name: &str) -> Option<Hash> {
+        if name.len() != 66 || !name.is_ascii() {
+            return None;
+        }
+        let byte = |i: usize| u8::from_str_radix(&name[2 * i..2 * i + 2], 16).ok();
+        let algorithm = HashAlgorithm::from_tag(byte(0)?)?;
+        let mut field = [0u8; 32];
+        for (i, slot) in field.iter_mut().enumerate() {
+            *slot = byte(i + 1)?;
+        }
+        Some(Hash::from_raw(field, algorithm))
+    }
+}
+
+impl StorageBackend for FileBackend {
+    fn get(&self, hash: &Hash) -> Option<Artifact> {
+        if !self.index.contains(hash) {
+            return None;
+        }
+        let content = fs::read(self.path_of(hash)).ok()?;
+        Some(Artifact { hash: hash.clone(), content })
+    }
+
+    fn contains(&self, hash: &Hash) -> bool {
+        self.index.contains(hash)
+    }
+
+    fn put(&mut self, artifact: Artifact) -> Result<(), StorageFlowError> {
+        fs::write(self.path_of(&artifact.hash), &artifact.content)?;
+        self.index.insert(artifact.hash);
+        Ok(())
+    }
+
+    fn remove(&mut self, hash: &Hash) -> Option<Artifact> {
+        let artifact = self.get(hash)?;
+        fs::remove_file(self.path_of(hash)).ok()?;
+        self.index.remove(hash);
+        Some(artifact)
+    }
+
+    fn hashes(&self) -> Box<dyn Iterator<Item = &Hash> + '_> {
+        Box::new(self.index.iter())
+    }
+
+    fn len(&self) -> usize {
+        self.index.len()
+    }
+
+    fn box_clone(&self) -> Box<dyn StorageBackend> {
+        Box::new(self.clone())
+    }
+}
+
 /// The Storage potential flow
 /// Represents a field of storage potentials where artifacts converge
 #[derive(Debug, Clone)]
 pub struct Storage {
-    field: HashMap<Hash, Artifact>,
+    backend: Box<dyn StorageBackend>,
 }
 
 impl Storage {
-    /// Creates a new storage potential field
+    /// Creates a new in-memory storage potential field
     pub fn new_field() -> Self {
-        Self {
-            field: HashMap::new(),
-        }
+        Self::with_backend(Box::new(MemoryBackend::default()))
+    }
+
+    /// Creates a storage potential field over the given backend
+    pub fn with_backend(backend: Box<dyn StorageBackend>) -> Self {
+        Self { backend }
     }
 
     /// Stores an artifact in the storage field
     pub fn store_flow(&mut self, artifact: Artifact) -> Result<(), StorageFlowError> {
-        self.field.insert(artifact.hash.clone(), artifact);
-        Ok(())
+        self.backend.put(artifact)
     }
 
     /// Retrieves an artifact from the storage field
     pub fn retrieve_flow(&self, hash: &Hash) -> Option<Artifact> {
-        self.field.get(hash).cloned()
+        self.backend.get(hash)
+    }
+
+    /// Borrows an artifact from the storage field, if the backend holds it in memory
+    pub fn retrieve_ref_flow(&self, hash: &Hash) -> Option<&Artifact> {
+        self.backend.get_ref(hash)
+    }
+
+    /// Checks whether an artifact is present in the storage field
+    pub fn contains_flow(&self, hash: &Hash) -> bool {
+        self.backend.contains(hash)
+    }
+
+    /// Removes an artifact from the storage field, returning it if present
+    pub fn remove_flow(&mut self, hash: &Hash) -> Option<Artifact> {
+        self.backend.remove(hash)
+    }
+
+    /// Walks every stored artifact with its hash potential, in no particular order
+    ///
+    /// Artifacts are borrowed from backends that hold them in memory and read
+    /// one at a time from the others, so every backend is walked in full
+    /// without collecting the field. The order is stable across calls while
+    /// the field is unmodified.
+    pub fn entries(&self) -> impl Iterator<Item = (&Hash, Cow<'_, Artifact>)> + '_ {
+        self.backend.hashes().filter_map(|hash| {
+            let artifact = match self.backend.get_ref(hash) {
+                Some(artifact) => Cow::Borrowed(artifact),
+                None => Cow::Owned(self.backend.get(hash)?),
+            };
+            Some((hash, artifact))
+        })
+    }
+
+    /// Borrows every stored hash potential without reading artifact contents
+    pub fn hashes_flow(&self) -> impl Iterator<Item = &Hash> + '_ {
+        self.backend.hashes()
+    }
+
+    /// Finds every artifact whose content contains `needle` as a byte substring
+    ///
+    /// This is a linear scan over all artifacts, costing O(total content bytes × needle length).
+    /// An empty needle matches every artifact. Results are in no particular order.
+    pub fn find_containing_flow(&self, needle: &[u8]) -> Vec<Hash> {
+        self.backend.hashes()
+            .filter(|hash| match self.backend.get_ref(hash) {
+                Some(artifact) => artifact.contains_flow(needle),
+                None => self.backend.get(hash).is_some_and(|artifact| artifact.contains_flow(needle)),
+            })
+            .cloned()
+            .collect()
     }
 
     /// Computes the curl of the storage field
     pub fn field_curl(&self) -> usize {
-        self.field.len()
+        self.backend.len()
     }
 }
 
@@ -45,6 +275,14 @@ pub enum StorageFlowError {
     FlowFailed,
     #[error("Hash potential not found in field")]
     NotFound,
+    #[error("Empty content cannot flow into the storage field")]
+    Empty,
+    #[error("Storage field is at capacity")]
+    CapacityExceeded,
+    #[error("Storage field byte budget exceeded")]
+    BudgetExceeded,
+    #[error("Storage flow I/O failed: {0}")]
+    Io(#[from] std::io::Error),
 }
 
 /// The storage flow operator
@@ -2,9 +2,11 @@
 //! This represents the unified flow field where all potentials converge
 //! The system is a higher-order potential flow in Navier-Stokes space
 
-use crate::hash::Hash;
+use std::io::{self, Read, Write};
+use crate::hash::{Hash, HashAlgorithm};
 use crate::artifact::Artifact;
 use crate::kernel::{Kernel, kernel_flow};
+use crate::storage::{Storage, StorageBackend, StorageFlowError};
 
 /// The System potential flow
 /// Represents the complete bootstrap system as a unified flow field
@@ -22,26 +24,184 @@ impl System {
         }
     }
 
+    /// Keeps the system's artifacts in the given backend
+    pub fn with_backend(mut self, backend: Box<dyn StorageBackend>) -> Self {
+        self.kernel = self.kernel.with_storage(Storage::with_backend(backend));
+        self
+    }
+
+    /// Makes the system cycle wrap after `cycle_length` steps
+    pub fn with_cycle_length(mut self, cycle_length: u64) -> Self {
+        self.kernel = self.kernel.with_cycle_length(cycle_length);
+        self
+    }
+
+    /// Hashes system content under `algorithm`
+    pub fn with_hash_algorithm(mut self, algorithm: HashAlgorithm) -> Self {
+        self.kernel = self.kernel.with_hash_algorithm(algorithm);
+        self
+    }
+
+    /// Holds at most `max_entries` artifacts in the system
+    pub fn with_max_entries(mut self, max_entries: usize) -> Self {
+        self.kernel = self.kernel.with_max_entries(max_entries);
+        self
+    }
+
+    /// Holds at most `max_bytes` bytes of live content in the system
+    pub fn with_max_bytes(mut self, max_bytes: usize) -> Self {
+        self.kernel = self.kernel.with_max_bytes(max_bytes);
+        self
+    }
+
+    /// Splits large system content into shared chunks
+    pub fn with_chunking(mut self) -> Self {
+        self.kernel = self.kernel.with_chunking();
+        self
+    }
+
     /// Stores content in the system flow field
     pub fn store_system_flow(&mut self, content: Vec<u8>) -> Hash {
         self.kernel.store_flow(content)
     }
 
+    /// Stores content in the system flow field, surfacing storage failures
+    pub fn try_store_system_flow(&mut self, content: Vec<u8>) -> Result<Hash, StorageFlowError> {
+        self.kernel.try_store_flow(content)
+    }
+
+    /// Stores a batch of content in the system flow field as a single cycle step
+    pub fn store_batch_system_flow(&mut self, items: Vec<Vec<u8>>) -> Vec<Hash> {
+        self.kernel.store_batch_flow(items)
+    }
+
+    /// Stores expiring content in the system flow field
+    pub fn store_system_flow_with_ttl(&mut self, content: Vec<u8>, ttl_cycles: u64) -> Hash {
+        self.kernel.store_flow_with_ttl(content, ttl_cycles)
+    }
+
+    /// Purges expired content from the system flow field
+    pub fn purge_expired_system_flow(&mut self) -> Vec<Hash> {
+        self.kernel.purge_expired_flow()
+    }
+
+    /// Removes content from the system flow field, returning it if present
+    pub fn remove_system_flow(&mut self, hash: &Hash) -> Option<Artifact> {
+        self.kernel.remove_flow(hash)
+    }
+
+    /// Erases removed content from the system flow field's storage
+    pub fn compact_system_flow(&mut self) -> usize {
+        self.kernel.compact_flow()
+    }
+
+    /// Restores an artifact in the system flow field exactly as given
+    pub fn restore_system_flow(&mut self, artifact: Artifact) -> Result<(), StorageFlowError> {
+        self.kernel.restore_flow(artifact)
+    }
+
     /// Retrieves content from the system flow field
     pub fn retrieve_system_flow(&self, hash: &Hash) -> Option<Artifact> {
         self.kernel.retrieve_flow(hash)
     }
 
+    /// Checks whether content exists in the system flow field
+    pub fn contains_system_flow(&self, hash: &Hash) -> bool {
+        self.kernel.contains_flow(hash)
+    }
+
+    /// Borrows content from the system flow field without copying it
+    pub fn retrieve_ref_system_flow(&self, hash: &Hash) -> Option<&Artifact> {
+        self.kernel.retrieve_ref_flow(hash)
+    }
+
+    /// Walks the hashes of all content in the system flow field
+    pub fn system_hashes_flow(&self) -> impl Iterator<Item = &Hash> + '_ {
+        self.kernel.hashes_flow()
+    }
+
+    /// Finds content in the system flow field containing the byte substring
+    pub fn find_containing_system_flow(&self, needle: &[u8]) -> Vec<Hash> {
+        self.kernel.find_containing_flow(needle)
+    }
+
     /// Gets the current system cycle potential
     pub fn system_cycle_potential(&self) -> u64 {
         self.kernel.cycle_potential()
     }
 
+    /// Gets the system cycle length
+    pub fn system_cycle_length(&self) -> u64 {
+        self.kernel.cycle_length()
+    }
+
+    /// Gets the system hash algorithm
+    pub fn system_hash_algorithm(&self) -> HashAlgorithm {
+        self.kernel.hash_algorithm()
+    }
+
+    /// Gets the system capacity in artifacts, if capped
+    pub fn system_max_entries(&self) -> Option<usize> {
+        self.kernel.max_entries()
+    }
+
+    /// Gets the system byte budget, if capped
+    pub fn system_max_bytes(&self) -> Option<usize> {
+        self.kernel.max_bytes()
+    }
+
+    /// Gets the bytes left in the system byte budget
+    pub fn system_remaining_bytes(&self) -> usize {
+        self.kernel.remaining_bytes()
+    }
+
+    /// Gets the total number of system operations
+    pub fn system_operations_potential(&self) -> u64 {
+        self.kernel.operations_potential()
+    }
+
     /// Computes the total system flow divergence
     pub fn total_system_divergence(&self) -> usize {
         self.kernel.system_divergence()
     }
 
+    /// Writes the system flow field to a binary flow
+    pub fn save_system_flow<W: Write>(&self, writer: W) -> io::Result<()> {
+        self.kernel.save_flow(writer)
+    }
+
+    /// Reads a system flow field written by `save_system_flow`
+    pub fn load_system_flow<R: Read>(reader: R) -> io::Result<Self> {
+        Ok(Self {
+            kernel: Kernel::load_flow(reader)?,
+        })
+    }
+
+    /// Computes the part of the system flow divergence held whole in storage
+    pub fn whole_system_divergence(&self) -> usize {
+        self.kernel.whole_divergence()
+    }
+
+    /// Computes the part of the system flow divergence held by the kernel cycle
+    pub fn expiring_system_divergence(&self) -> usize {
+        self.kernel.expiring_divergence()
+    }
+
+    /// Computes the part of the system flow divergence assembled from shared chunks
+    pub fn chunked_system_divergence(&self) -> usize {
+        self.kernel.chunked_divergence()
+    }
+
+    /// Counts the distinct chunks in the system flow field
+    pub fn system_chunk_count(&self) -> usize {
+        self.kernel.chunk_count()
+    }
+
+    /// Sums the bytes of the distinct chunks in the system flow field
+    pub fn system_chunk_bytes(&self) -> usize {
+        self.kernel.chunk_bytes()
+    }
+
     /// Computes the system flow curl (vorticity)
     pub fn system_curl(&self) -> u64 {
         self.kernel.cycle_potential()
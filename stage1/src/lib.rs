@@ -10,17 +10,62 @@
 //! - **Mirror Structures**: Symmetric data organization
 //! - **Dual Hashing**: Paired hash algorithms for verification
 //! - **Cyclic Duality**: 42-step cycle with dual phases
+//! - **N-ary Replication**: Quorum reads across any number of replicas
 
 pub mod dual;
 pub mod mirror;
+pub mod nary;
 pub mod paired;
 pub mod symmetry;
 
 pub use dual::{DualBootstrap, DualOperation, DualPhase};
 pub use mirror::{MirrorStore, MirrorArtifact};
+pub use nary::NaryBootstrap;
 pub use paired::{PairedHasher, PairedStorage};
 pub use symmetry::{SymmetryKernel, SymmetryCycle};
 
+use std::borrow::Cow;
+
+/// The result of repairing an artifact from its dual copy
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RepairOutcome {
+    /// Both copies already match their hash
+    Intact,
+    /// The primary copy was corrupt or missing and was restored from the dual
+    RepairedPrimary,
+    /// The dual copy was corrupt or missing and was restored from the primary
+    RepairedDual,
+    /// Neither copy matches its hash, or restoring failed
+    Unrecoverable,
+    /// Neither system stores the hash
+    Missing,
+}
+
+/// The system whose copy failed to round-trip in `dual_store_verified`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum DualMismatch {
+    /// The primary system did not give back the stored bytes
+    Primary(bootstrap::Hash),
+    /// The dual system did not give back the stored bytes
+    Dual(bootstrap::Hash),
+    /// Neither system gave back the stored bytes
+    Both(bootstrap::Hash, bootstrap::Hash),
+}
+
+impl std::fmt::Display for DualMismatch {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            DualMismatch::Primary(hash) => write!(f, "primary system diverged for {:?}", hash),
+            DualMismatch::Dual(hash) => write!(f, "dual system diverged for {:?}", hash),
+            DualMismatch::Both(primary, dual) => {
+                write!(f, "both systems diverged (primary {:?}, dual {:?})", primary, dual)
+            }
+        }
+    }
+}
+
+impl std::error::Error for DualMismatch {}
+
 /// The dual bootstrap system that operates in complementary pairs
 pub struct Stage1Bootstrap {
     primary: bootstrap::Bootstrap,
@@ -38,6 +83,18 @@ impl Stage1Bootstrap {
         }
     }
 
+    /// Creates a dual bootstrap system over the given primary and dual backends
+    pub fn with_backends(
+        primary: Box<dyn bootstrap::StorageBackend>,
+        dual: Box<dyn bootstrap::StorageBackend>,
+    ) -> Self {
+        Self {
+            primary: bootstrap::Bootstrap::new().with_backend(primary),
+            dual: bootstrap::Bootstrap::new().with_backend(dual),
+            phase: DualPhase::Primary,
+        }
+    }
+
     /// Performs a dual operation (stores in both systems)
     pub fn dual_store(&mut self, content: Vec<u8>) -> (bootstrap::Hash, bootstrap::Hash) {
         let primary_hash = self.primary.store(content.clone());
@@ -47,11 +104,65 @@ impl Stage1Bootstrap {
         (primary_hash, dual_hash)
     }
 
+    /// Performs a dual operation and checks both systems give back the original bytes
+    ///
+    /// The content stays stored, and the phase advances, even when a system
+    /// diverges; the error names the system whose copy did not round-trip.
+    pub fn dual_store_verified(&mut self, content: Vec<u8>) -> Result<(bootstrap::Hash, bootstrap::Hash), DualMismatch> {
+        let (primary_hash, dual_hash) = self.dual_store(content.clone());
+        let round_trips = |system: &bootstrap::Bootstrap, hash: &bootstrap::Hash| {
+            system.retrieve(hash).is_some_and(|artifact| artifact.content == content)
+        };
+
+        match (round_trips(&self.primary, &primary_hash), round_trips(&self.dual, &dual_hash)) {
+            (true, true) => Ok((primary_hash, dual_hash)),
+            (false, true) => Err(DualMismatch::Primary(primary_hash)),
+            (true, false) => Err(DualMismatch::Dual(dual_hash)),
+            (false, false) => Err(DualMismatch::Both(primary_hash, dual_hash)),
+        }
+    }
+
     /// Retrieves from the current phase's system
-    pub fn retrieve(&self, hash: &bootstrap::Hash) -> Option<&bootstrap::Artifact> {
+    ///
+    /// The artifact is borrowed when the backend can lend it and owned
+    /// otherwise, as with `FileBackend` or chunked content.
+    pub fn retrieve(&self, hash: &bootstrap::Hash) -> Option<Cow<'_, bootstrap::Artifact>> {
         match self.phase {
-            DualPhase::Primary => self.primary.retrieve(hash),
-            DualPhase::Dual => self.dual.retrieve(hash),
+            DualPhase::Primary => retrieve_from(&self.primary, hash),
+            DualPhase::Dual => retrieve_from(&self.dual, hash),
+        }
+    }
+
+    /// Retrieves from whichever system holds the hash, regardless of phase
+    ///
+    /// The primary system is consulted first, then the dual. Use `retrieve`
+    /// for strict duality semantics.
+    pub fn retrieve_any(&self, hash: &bootstrap::Hash) -> Option<Cow<'_, bootstrap::Artifact>> {
+        retrieve_from(&self.primary, hash).or_else(|| retrieve_from(&self.dual, hash))
+    }
+
+    /// Repairs an artifact whose content no longer matches its hash
+    ///
+    /// Whichever side still verifies is copied over the other side.
+    pub fn repair(&mut self, hash: &bootstrap::Hash) -> RepairOutcome {
+        let (source, target, outcome) = match (self.primary.verify(hash), self.dual.verify(hash)) {
+            (Some(true), Some(true)) => return RepairOutcome::Intact,
+            (Some(true), _) => (&self.primary, &mut self.dual, RepairOutcome::RepairedDual),
+            (_, Some(true)) => (&self.dual, &mut self.primary, RepairOutcome::RepairedPrimary),
+            (None, None) => return RepairOutcome::Missing,
+            _ => return RepairOutcome::Unrecoverable,
+        };
+
+        let healthy = match source.retrieve(hash) {
+            Some(artifact) => bootstrap::Artifact {
+                hash: hash.clone(),
+                content: artifact.content.clone(),
+            },
+            None => return RepairOutcome::Unrecoverable,
+        };
+        match target.restore(healthy) {
+            Ok(()) => outcome,
+            Err(_) => RepairOutcome::Unrecoverable,
         }
     }
 
@@ -74,6 +185,13 @@ impl Stage1Bootstrap {
     }
 }
 
+/// Borrows an artifact from a system, falling back to an owned copy when the backend cannot lend it
+fn retrieve_from<'a>(system: &'a bootstrap::Bootstrap, hash: &bootstrap::Hash) -> Option<Cow<'a, bootstrap::Artifact>> {
+    system.retrieve_ref(hash)
+        .map(Cow::Borrowed)
+        .or_else(|| system.retrieve(hash).map(Cow::Owned))
+}
+
 impl Default for Stage1Bootstrap {
     fn default() -> Self {
         Self::new()
@@ -98,14 +216,146 @@ mod tests {
         
         let (primary_hash, dual_hash) = stage1.dual_store(content.clone());
         
-        // Both hashes should be different (different systems)
-        assert_ne!(primary_hash, dual_hash);
+        // Both systems address content the same way, so the hashes agree
+        assert_eq!(primary_hash, dual_hash);
         
         // Both should be retrievable
         assert!(stage1.primary.retrieve(&primary_hash).is_some());
         assert!(stage1.dual.retrieve(&dual_hash).is_some());
     }
 
+    #[test]
+    fn test_retrieve_any_ignores_phase() {
+        let mut stage1 = Stage1Bootstrap::new();
+        let (primary_hash, dual_hash) = stage1.dual_store(b"dual content".to_vec());
+        let primary_only = stage1.primary.store(b"primary only".to_vec());
+        let dual_only = stage1.dual.store(b"dual only".to_vec());
+        assert_eq!(stage1.phase(), DualPhase::Dual);
+
+        assert!(stage1.retrieve(&primary_only).is_none());
+        assert_eq!(stage1.retrieve_any(&primary_only).unwrap().content, b"primary only".to_vec());
+        assert_eq!(stage1.retrieve_any(&dual_only).unwrap().content, b"dual only".to_vec());
+        assert!(stage1.retrieve_any(&primary_hash).is_some());
+        assert!(stage1.retrieve_any(&dual_hash).is_some());
+        assert!(stage1.retrieve_any(&bootstrap::hash_flow(b"never stored")).is_none());
+    }
+
+    #[test]
+    fn test_retrieve_falls_back_to_owned_copies() {
+        let root = std::env::temp_dir().join(format!("stage1-retrieve-owned-{}", std::process::id()));
+        let mut stage1 = Stage1Bootstrap::with_backends(
+            Box::new(bootstrap::FileBackend::new(root.join("primary")).unwrap()),
+            Box::new(bootstrap::MemoryBackend::default()),
+        );
+        let (hash, _) = stage1.dual_store(b"content on disk".to_vec());
+        let primary_only = stage1.primary.store(b"primary only".to_vec());
+
+        assert_eq!(stage1.phase(), DualPhase::Dual);
+        assert!(matches!(stage1.retrieve(&hash), Some(Cow::Borrowed(_))));
+        assert!(matches!(stage1.retrieve_any(&primary_only), Some(Cow::Owned(_))));
+        assert_eq!(stage1.retrieve_any(&primary_only).unwrap().content, b"primary only".to_vec());
+
+        stage1.dual_store(b"second content".to_vec());
+        assert_eq!(stage1.phase(), DualPhase::Primary);
+        assert_eq!(stage1.retrieve(&hash).unwrap().content, b"content on disk".to_vec());
+
+        std::fs::remove_dir_all(&root).unwrap();
+    }
+
+    /// A backend that flips the first bit of everything it reads back
+    #[derive(Debug, Clone, Default)]
+    struct BitRotBackend(bootstrap::MemoryBackend);
+
+    impl bootstrap::StorageBackend for BitRotBackend {
+        fn get(&self, hash: &bootstrap::Hash) -> Option<bootstrap::Artifact> {
+            let mut artifact = self.0.get(hash)?;
+            artifact.content[0] ^= 1;
+            Some(artifact)
+        }
+
+        fn put(&mut self, artifact: bootstrap::Artifact) -> Result<(), bootstrap::StorageFlowError> {
+            self.0.put(artifact)
+        }
+
+        fn remove(&mut self, hash: &bootstrap::Hash) -> Option<bootstrap::Artifact> {
+            self.0.remove(hash)
+        }
+
+        fn hashes(&self) -> Box<dyn Iterator<Item = &bootstrap::Hash> + '_> {
+            self.0.hashes()
+        }
+
+        fn len(&self) -> usize {
+            self.0.len()
+        }
+
+        fn box_clone(&self) -> Box<dyn bootstrap::StorageBackend> {
+            Box::new(self.clone())
+        }
+    }
+
+    #[test]
+    fn test_dual_store_verified() {
+        let mut stage1 = Stage1Bootstrap::new();
+        let (primary_hash, dual_hash) = stage1.dual_store_verified(b"verified content".to_vec()).unwrap();
+        assert!(stage1.primary.retrieve(&primary_hash).is_some());
+        assert!(stage1.dual.retrieve(&dual_hash).is_some());
+
+        let mut faulty = Stage1Bootstrap::with_backends(
+            Box::new(bootstrap::MemoryBackend::default()),
+            Box::new(BitRotBackend::default()),
+        );
+        let error = faulty.dual_store_verified(b"fragile content".to_vec()).unwrap_err();
+        assert!(matches!(error, DualMismatch::Dual(_)));
+        assert!(error.to_string().starts_with("dual system diverged"));
+
+        let mut faulty = Stage1Bootstrap::with_backends(
+            Box::new(BitRotBackend::default()),
+            Box::new(bootstrap::MemoryBackend::default()),
+        );
+        let error = faulty.dual_store_verified(b"fragile content".to_vec()).unwrap_err();
+        assert!(matches!(error, DualMismatch::Primary(_)));
+        assert!(error.to_string().starts_with("primary system diverged"));
+
+        let mut faulty = Stage1Bootstrap::with_backends(
+            Box::new(BitRotBackend::default()),
+            Box::new(BitRotBackend::default()),
+        );
+        let error = faulty.dual_store_verified(b"fragile content".to_vec()).unwrap_err();
+        assert!(matches!(error, DualMismatch::Both(_, _)));
+    }
+
+    #[test]
+    fn test_dual_store_verified_on_file_backends() {
+        let root = std::env::temp_dir().join(format!("stage1-dual-verified-{}", std::process::id()));
+        let mut stage1 = Stage1Bootstrap::with_backends(
+            Box::new(bootstrap::FileBackend::new(root.join("primary")).unwrap()),
+            Box::new(bootstrap::FileBackend::new(root.join("dual")).unwrap()),
+        );
+
+        // File backends cannot lend artifacts, so verification reads owned copies
+        let (primary_hash, dual_hash) = stage1.dual_store_verified(b"content on disk".to_vec()).unwrap();
+        assert!(stage1.primary.retrieve_ref(&primary_hash).is_none());
+        assert_eq!(stage1.dual.retrieve(&dual_hash).unwrap().content, b"content on disk".to_vec());
+
+        std::fs::remove_dir_all(&root).unwrap();
+    }
+
+    #[test]
+    fn test_repair_restores_corrupt_primary() {
+        let mut stage1 = Stage1Bootstrap::new();
+        let (hash, _) = stage1.dual_store(b"precious content".to_vec());
+
+        // Corrupt the primary copy in place
+        stage1.primary.restore(bootstrap::Artifact { hash: hash.clone(), content: b"bit rot".to_vec() }).unwrap();
+        assert_eq!(stage1.primary.verify(&hash), Some(false));
+
+        assert_eq!(stage1.repair(&hash), RepairOutcome::RepairedPrimary);
+        assert_eq!(stage1.primary.verify(&hash), Some(true));
+        assert_eq!(stage1.primary.retrieve(&hash).unwrap().content, b"precious content".to_vec());
+        assert_eq!(stage1.repair(&hash), RepairOutcome::Intact);
+    }
+
     #[test]
     fn test_phase_advancement() {
         let mut stage1 = Stage1Bootstrap::new();
@@ -1,6 +1,7 @@
 //! Mirror structures and symmetric data organization
 
-use bootstrap::{Artifact, Hash, Hasher};
+use bootstrap::{Artifact, Hash};
+use std::cell::Cell;
 use std::collections::HashMap;
 
 /// A mirror artifact that maintains symmetric relationships
@@ -57,21 +58,21 @@ impl MirrorArtifact {
 
     /// Creates a perfect mirror (identical content)
     pub fn perfect_mirror(original: Artifact) -> Self {
-        let mirror = Artifact::new(original.content.clone(), &bootstrap::SimpleHasher);
+        let mirror = Artifact::from_content_flow_with(original.content.clone(), original.hash.algorithm());
         Self::new(original, mirror, SymmetryType::Perfect)
     }
 
     /// Creates a byte-reversed mirror
     pub fn byte_reversed_mirror(original: Artifact) -> Self {
         let reversed_content: Vec<u8> = original.content.iter().rev().cloned().collect();
-        let mirror = Artifact::new(reversed_content, &bootstrap::SimpleHasher);
+        let mirror = Artifact::from_content_flow_with(reversed_content, original.hash.algorithm());
         Self::new(original, mirror, SymmetryType::ByteReversed)
     }
 
     /// Creates a bit-complement mirror
     pub fn bit_complement_mirror(original: Artifact) -> Self {
         let complemented_content: Vec<u8> = original.content.iter().map(|&b| !b).collect();
-        let mirror = Artifact::new(complemented_content, &bootstrap::SimpleHasher);
+        let mirror = Artifact::from_content_flow_with(complemented_content, original.hash.algorithm());
         Self::new(original, mirror, SymmetryType::BitComplement)
     }
 
@@ -112,6 +113,10 @@ pub struct MirrorStore {
     primary: HashMap<Hash, MirrorArtifact>,
     /// Mirror storage mapping (reverse lookup)
     mirror: HashMap<Hash, Hash>,
+    /// Secondary backing store duplicating every write to the primary mapping
+    secondary: HashMap<Hash, MirrorArtifact>,
+    /// Number of reads served by the secondary store
+    failover_reads: Cell<usize>,
     /// Symmetry statistics
     stats: MirrorStats,
 }
@@ -135,16 +140,19 @@ impl MirrorStore {
         Self {
             primary: HashMap::new(),
             mirror: HashMap::new(),
+            secondary: HashMap::new(),
+            failover_reads: Cell::new(0),
             stats: MirrorStats::default(),
         }
     }
 
-    /// Stores a mirror artifact
+    /// Stores a mirror artifact, duplicating it to the secondary store
     pub fn store_mirror(&mut self, mirror_artifact: MirrorArtifact) {
         let (original_hash, mirror_hash) = mirror_artifact.hashes();
         
-        // Store in primary mapping
+        // Store in primary mapping and its secondary copy
         self.primary.insert(original_hash.clone(), mirror_artifact.clone());
+        self.secondary.insert(original_hash.clone(), mirror_artifact.clone());
         
         // Store reverse mapping
         self.mirror.insert(mirror_hash.clone(), original_hash.clone());
@@ -168,6 +176,32 @@ impl MirrorStore {
         None
     }
 
+    /// Reads a mirror artifact, failing over to the secondary store
+    ///
+    /// The primary entry is served when present and intact, meaning both of its
+    /// artifacts still hash to their recorded hashes. Otherwise the secondary
+    /// copy is served if it is intact, and the read counts towards
+    /// `failover_reads`. Either hash of the pair can be used for the lookup.
+    pub fn read_failover(&self, hash: &Hash) -> Option<MirrorArtifact> {
+        let key = if self.primary.contains_key(hash) || self.secondary.contains_key(hash) {
+            hash
+        } else {
+            self.mirror.get(hash)?
+        };
+        
+        if let Some(entry) = self.primary.get(key).filter(|entry| is_intact(entry)) {
+            return Some(entry.clone());
+        }
+        let entry = self.secondary.get(key).filter(|entry| is_intact(entry))?;
+        self.failover_reads.set(self.failover_reads.get() + 1);
+        Some(entry.clone())
+    }
+
+    /// Gets the number of reads served by the secondary store
+    pub fn failover_reads(&self) -> usize {
+        self.failover_reads.get()
+    }
+
     /// Gets the mirror hash for a given hash
     pub fn get_mirror_hash(&self, hash: &Hash) -> Option<&Hash> {
         if let Some(mirror) = self.primary.get(hash) {
@@ -217,6 +251,11 @@ impl MirrorStore {
     }
 }
 
+/// Checks that both artifacts of a mirror still hash to their recorded hashes
+fn is_intact(entry: &MirrorArtifact) -> bool {
+    entry.original.is_intact() && entry.mirror.is_intact()
+}
+
 impl Default for MirrorStore {
     fn default() -> Self {
         Self::new()
@@ -229,7 +268,7 @@ mod tests {
 
     #[test]
     fn test_perfect_mirror_creation() {
-        let original = bootstrap::Artifact::new(b"test".to_vec(), &bootstrap::SimpleHasher);
+        let original = bootstrap::Artifact::from_content_flow(b"test".to_vec());
         let mirror_artifact = MirrorArtifact::perfect_mirror(original.clone());
         
         assert!(mirror_artifact.is_perfect_mirror());
@@ -239,7 +278,7 @@ mod tests {
 
     #[test]
     fn test_byte_reversed_mirror() {
-        let original = bootstrap::Artifact::new(b"hello".to_vec(), &bootstrap::SimpleHasher);
+        let original = bootstrap::Artifact::from_content_flow(b"hello".to_vec());
         let mirror_artifact = MirrorArtifact::byte_reversed_mirror(original.clone());
         
         assert!(!mirror_artifact.is_perfect_mirror());
@@ -250,8 +289,8 @@ mod tests {
     #[test]
     fn test_mirror_store() {
         let mut store = MirrorStore::new();
-        let original = bootstrap::Artifact::new(b"test".to_vec(), &bootstrap::SimpleHasher);
-        let mirror_artifact = MirrorArtifact::perfect_mirror(original);
+        let original = bootstrap::Artifact::from_content_flow(b"test".to_vec());
+        let mirror_artifact = MirrorArtifact::perfect_mirror(original.clone());
         
         store.store_mirror(mirror_artifact);
         
@@ -260,10 +299,37 @@ mod tests {
         assert!(store.has_mirror(&original.hash));
     }
 
+    #[test]
+    fn test_read_failover_serves_mirror_copy() {
+        let mut store = MirrorStore::new();
+        let original = bootstrap::Artifact::from_content_flow(b"replicated".to_vec());
+        let mirror_artifact = MirrorArtifact::byte_reversed_mirror(original.clone());
+        let mirror_hash = mirror_artifact.mirror().hash.clone();
+        store.store_mirror(mirror_artifact);
+
+        assert_eq!(store.read_failover(&original.hash).unwrap().original().content, b"replicated");
+        assert_eq!(store.failover_reads(), 0);
+
+        // Corrupt the primary entry in place
+        store.primary.get_mut(&original.hash).unwrap().original.content = b"bit rot".to_vec();
+        let served = store.read_failover(&original.hash).unwrap();
+        assert_eq!(served.original().content, b"replicated");
+        assert_eq!(store.failover_reads(), 1);
+
+        // A missing primary entry fails over too, including via the mirror hash
+        store.primary.remove(&original.hash);
+        assert_eq!(store.read_failover(&mirror_hash).unwrap().mirror().content, b"detacilper");
+        assert_eq!(store.failover_reads(), 2);
+
+        store.secondary.clear();
+        assert!(store.read_failover(&original.hash).is_none());
+        assert_eq!(store.failover_reads(), 2);
+    }
+
     #[test]
     fn test_mirror_lookup() {
         let mut store = MirrorStore::new();
-        let original = bootstrap::Artifact::new(b"test".to_vec(), &bootstrap::SimpleHasher);
+        let original = bootstrap::Artifact::from_content_flow(b"test".to_vec());
         let mirror_artifact = MirrorArtifact::perfect_mirror(original.clone());
         
         store.store_mirror(mirror_artifact.clone());
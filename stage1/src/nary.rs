@@ -0,0 +1,120 @@
+//! N-ary replication generalizing the primary/dual pair
+
+use bootstrap::{Bootstrap, Hash};
+
+/// A bootstrap system replicated across `n` independent instances
+pub struct NaryBootstrap {
+    /// The replica systems
+    replicas: Vec<Bootstrap>,
+    /// Index of the replica owning the current phase
+    phase: usize,
+}
+
+impl NaryBootstrap {
+    /// Creates a system with `n` replicas
+    ///
+    /// Panics if `n` is zero.
+    pub fn new(n: usize) -> Self {
+        assert!(n > 0, "an n-ary system needs at least one replica");
+        Self {
+            replicas: (0..n).map(|_| Bootstrap::new()).collect(),
+            phase: 0,
+        }
+    }
+
+    /// Stores the content in every replica, returning each replica's hash
+    pub fn nary_store(&mut self, content: Vec<u8>) -> Vec<Hash> {
+        let hashes = self.replicas
+            .iter_mut()
+            .map(|replica| replica.store(content.clone()))
+            .collect();
+
+        self.advance_phase();
+        hashes
+    }
+
+    /// Retrieves content that at least `k` replicas agree on
+    ///
+    /// Replicas missing the hash count against the quorum. When `k` is at most
+    /// half the replicas, two groups may both reach it and the first wins.
+    pub fn nary_retrieve_quorum(&self, hash: &Hash, k: usize) -> Option<Vec<u8>> {
+        let copies: Vec<Vec<u8>> = self.replicas
+            .iter()
+            .filter_map(|replica| replica.retrieve(hash))
+            .map(|artifact| artifact.content)
+            .collect();
+
+        copies
+            .iter()
+            .find(|candidate| copies.iter().filter(|copy| copy == candidate).count() >= k)
+            .cloned()
+    }
+
+    /// Retrieves from the current phase's replica
+    pub fn retrieve(&self, hash: &Hash) -> Option<Vec<u8>> {
+        self.replicas[self.phase].retrieve(hash).map(|artifact| artifact.content)
+    }
+
+    /// Gets the index of the replica owning the current phase
+    pub fn phase(&self) -> usize {
+        self.phase
+    }
+
+    /// Gets the number of replicas
+    pub fn replication_factor(&self) -> usize {
+        self.replicas.len()
+    }
+
+    /// Gets the combined cycle step (sum of all replicas)
+    pub fn combined_cycle(&self) -> u64 {
+        self.replicas.iter().map(|replica| replica.cycle_step()).sum()
+    }
+
+    /// Advances the phase round-robin to the next replica
+    fn advance_phase(&mut self) {
+        self.phase = (self.phase + 1) % self.replicas.len();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_phase_rotates_round_robin() {
+        let mut nary = NaryBootstrap::new(3);
+        assert_eq!(nary.replication_factor(), 3);
+        assert_eq!(nary.phase(), 0);
+
+        let phases: Vec<usize> = (0..4u8)
+            .map(|i| {
+                nary.nary_store(vec![i]);
+                nary.phase()
+            })
+            .collect();
+        assert_eq!(phases, vec![1, 2, 0, 1]);
+        assert_eq!(nary.combined_cycle(), 12);
+    }
+
+    #[test]
+    fn test_quorum_survives_one_corrupt_replica() {
+        let mut nary = NaryBootstrap::new(3);
+        let hashes = nary.nary_store(b"replicated content".to_vec());
+        assert_eq!(hashes.len(), 3);
+        let hash = hashes[0].clone();
+
+        // Corrupt the second replica in place
+        nary.replicas[1]
+            .restore(bootstrap::Artifact { hash: hash.clone(), content: b"bit rot".to_vec() })
+            .unwrap();
+
+        assert_eq!(nary.nary_retrieve_quorum(&hash, 2), Some(b"replicated content".to_vec()));
+        assert_eq!(nary.nary_retrieve_quorum(&hash, 3), None);
+
+        // With a second corruption no two replicas agree
+        nary.replicas[2]
+            .restore(bootstrap::Artifact { hash: hash.clone(), content: b"more rot".to_vec() })
+            .unwrap();
+        assert_eq!(nary.nary_retrieve_quorum(&hash, 2), None);
+    }
+}
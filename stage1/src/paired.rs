@@ -1,8 +1,26 @@
 //! Paired systems for dual verification and storage
 
-use bootstrap::{Hash, Hasher, Artifact, Store};
+use bootstrap::{Artifact, Hash, HashAlgorithm, StorageBackend};
 use std::collections::HashMap;
 
+/// A hash function one side of a `PairedHasher` computes digests with
+pub trait Hasher {
+    /// Computes the digest of the data
+    fn hash(&self, data: &[u8]) -> Hash;
+}
+
+/// Each stage0 hash algorithm is a hasher
+impl Hasher for HashAlgorithm {
+    fn hash(&self, data: &[u8]) -> Hash {
+        Hash::from_flow_with(data, *self)
+    }
+}
+
+/// Formats a hash's flow field as lowercase hex
+fn hex(hash: &Hash) -> String {
+    hash.flow_field().iter().map(|byte| format!("{:02x}", byte)).collect()
+}
+
 /// A paired hasher that uses two different hash algorithms
 pub struct PairedHasher {
     /// Primary hasher
@@ -24,6 +42,12 @@ impl PairedHasher {
         (primary_hash, secondary_hash)
     }
 
+    /// Computes both digests for the given data as a comparable pair
+    pub fn verify(&self, data: &[u8]) -> PairedHash {
+        let (primary, secondary) = self.dual_hash(data);
+        PairedHash { primary, secondary }
+    }
+
     /// Verifies that both hashes match for the same data
     pub fn verify_dual(&self, data: &[u8], primary_hash: &Hash, secondary_hash: &Hash) -> bool {
         let (computed_primary, computed_secondary) = self.dual_hash(data);
@@ -41,14 +65,66 @@ impl PairedHasher {
     }
 }
 
+/// Both digests of a piece of content
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PairedHash {
+    /// Digest from the primary hasher
+    pub primary: Hash,
+    /// Digest from the secondary hasher
+    pub secondary: Hash,
+}
+
+impl PairedHash {
+    /// Checks whether both hashers agree with an earlier record on content identity
+    ///
+    /// Each digest is compared with the same hasher's earlier digest. The
+    /// hashers agree when both match (the same content) or both differ
+    /// (different content); a match on one side only means that hasher
+    /// collided, or the content was tampered with in a way it cannot see.
+    pub fn agree(&self, earlier: &PairedHash) -> bool {
+        (self.primary == earlier.primary) == (self.secondary == earlier.secondary)
+    }
+}
+
+/// A collision caught by `PairedStorage::store_checked`
+#[derive(Debug, Clone, PartialEq)]
+pub enum PairedCollision {
+    /// The primary digest is already held by content with a different secondary digest
+    Primary(Hash),
+    /// The secondary digest is already held by content with a different primary digest
+    Secondary(Hash),
+    /// The underlying dual store rejected the artifact
+    Storage(&'static str),
+}
+
+impl std::fmt::Display for PairedCollision {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            PairedCollision::Primary(hash) => {
+                write!(f, "primary hash collision on {}", hex(hash))
+            }
+            PairedCollision::Secondary(hash) => {
+                write!(f, "secondary hash collision on {}", hex(hash))
+            }
+            PairedCollision::Storage(reason) => write!(f, "{}", reason),
+        }
+    }
+}
+
+impl std::error::Error for PairedCollision {}
+
 /// A paired storage system that maintains two storage backends
 pub struct PairedStorage {
     /// Primary storage
-    primary: Box<dyn Store>,
+    primary: Box<dyn StorageBackend>,
     /// Secondary storage
-    secondary: Box<dyn Store>,
+    secondary: Box<dyn StorageBackend>,
     /// Verification mode
     verification_mode: VerificationMode,
+    /// Secondary digest recorded for each primary digest by `store_checked`
+    primary_identities: HashMap<Hash, Hash>,
+    /// Primary digest recorded for each secondary digest by `store_checked`
+    secondary_identities: HashMap<Hash, Hash>,
 }
 
 /// Modes for storage verification
@@ -64,11 +140,13 @@ pub enum VerificationMode {
 
 impl PairedStorage {
     /// Creates a new paired storage system
-    pub fn new(primary: Box<dyn Store>, secondary: Box<dyn Store>) -> Self {
+    pub fn new(primary: Box<dyn StorageBackend>, secondary: Box<dyn StorageBackend>) -> Self {
         Self {
             primary,
             secondary,
             verification_mode: VerificationMode::Strict,
+            primary_identities: HashMap::new(),
+            secondary_identities: HashMap::new(),
         }
     }
 
@@ -79,45 +157,77 @@ impl PairedStorage {
 
     /// Stores an artifact in both storage systems
     pub fn dual_store(&mut self, artifact: Artifact) -> Result<(Hash, Hash), &'static str> {
-        let primary_hash = self.primary.store(artifact.clone());
-        let secondary_hash = self.secondary.store(artifact);
+        let hash = artifact.hash.clone();
+        let primary_stored = self.primary.put(artifact.clone()).is_ok();
+        let secondary_stored = self.secondary.put(artifact).is_ok();
 
         match self.verification_mode {
             VerificationMode::Strict => {
-                // Both must succeed (they should return the same hash)
-                if primary_hash == secondary_hash {
-                    Ok((primary_hash, secondary_hash))
+                // Both must succeed
+                if primary_stored && secondary_stored {
+                    Ok((hash.clone(), hash))
                 } else {
-                    Err("Storage verification failed - hashes don't match")
+                    Err("Storage verification failed - a storage rejected the artifact")
                 }
             }
             VerificationMode::Lenient => {
                 // Either can succeed
-                Ok((primary_hash, secondary_hash))
+                if primary_stored || secondary_stored {
+                    Ok((hash.clone(), hash))
+                } else {
+                    Err("Both storages rejected the artifact")
+                }
             }
             VerificationMode::PrimaryWithVerification => {
                 // Primary is authoritative, secondary is for verification
-                if primary_hash == secondary_hash {
-                    Ok((primary_hash, secondary_hash))
-                } else {
-                    Err("Secondary storage verification failed")
+                match (primary_stored, secondary_stored) {
+                    (true, true) => Ok((hash.clone(), hash)),
+                    (false, _) => Err("Primary storage rejected the artifact"),
+                    (true, false) => Err("Secondary storage verification failed"),
                 }
             }
         }
     }
 
+    /// Stores an artifact after checking both digests against previously checked content
+    ///
+    /// Two pieces of content may only share a primary digest if they also share
+    /// the secondary one, and vice versa. A pair that breaks this means one of
+    /// the hashers collided, so the artifact is rejected before it is stored.
+    pub fn store_checked(&mut self, hasher: &PairedHasher, artifact: Artifact) -> Result<PairedHash, PairedCollision> {
+        let paired = hasher.verify(&artifact.content);
+
+        if let Some(secondary) = self.primary_identities.get(&paired.primary) {
+            let earlier = PairedHash { primary: paired.primary.clone(), secondary: secondary.clone() };
+            if !paired.agree(&earlier) {
+                return Err(PairedCollision::Primary(paired.primary));
+            }
+        }
+        if let Some(primary) = self.secondary_identities.get(&paired.secondary) {
+            let earlier = PairedHash { primary: primary.clone(), secondary: paired.secondary.clone() };
+            if !paired.agree(&earlier) {
+                return Err(PairedCollision::Secondary(paired.secondary));
+            }
+        }
+
+        self.dual_store(artifact).map_err(PairedCollision::Storage)?;
+        self.primary_identities.insert(paired.primary.clone(), paired.secondary.clone());
+        self.secondary_identities.insert(paired.secondary.clone(), paired.primary.clone());
+        Ok(paired)
+    }
+
     /// Retrieves from the primary storage
-    pub fn get_primary(&self, hash: &Hash) -> Option<&Artifact> {
+    pub fn get_primary(&self, hash: &Hash) -> Option<Artifact> {
         self.primary.get(hash)
     }
 
     /// Retrieves from the secondary storage
-    pub fn get_secondary(&self, hash: &Hash) -> Option<&Artifact> {
+    pub fn get_secondary(&self, hash: &Hash) -> Option<Artifact> {
         self.secondary.get(hash)
     }
 
     /// Retrieves from both storages and verifies consistency
-    pub fn get_verified(&self, hash: &Hash) -> Result<Option<&Artifact>, &'static str> {
+    pub fn get_verified(&self, hash: &Hash) -> Result<Option<Artifact>, &'static str> {
         let primary_artifact = self.primary.get(hash);
         let secondary_artifact = self.secondary.get(hash);
 
@@ -232,13 +342,13 @@ impl PairedArtifact {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use bootstrap::{SimpleHasher, ChecksumHasher, MemoryStore};
+    use bootstrap::MemoryBackend;
 
     #[test]
     fn test_paired_hasher() {
         let paired = PairedHasher::new(
-            Box::new(SimpleHasher),
-            Box::new(ChecksumHasher),
+            Box::new(HashAlgorithm::Flow),
+            Box::new(HashAlgorithm::Fnv),
         );
 
         let data = b"test data";
@@ -251,14 +361,87 @@ mod tests {
         assert!(paired.verify_dual(data, &primary_hash, &secondary_hash));
     }
 
+    /// Weak hasher keyed on the first byte only
+    struct FirstByteHasher;
+
+    impl Hasher for FirstByteHasher {
+        fn hash(&self, data: &[u8]) -> Hash {
+            let mut field = [0u8; 32];
+            field[..1].copy_from_slice(&data[..1.min(data.len())]);
+            Hash::from_raw(field, HashAlgorithm::Fnv)
+        }
+    }
+
+    /// Weak hasher keyed on the length only
+    struct LengthHasher;
+
+    impl Hasher for LengthHasher {
+        fn hash(&self, data: &[u8]) -> Hash {
+            let mut field = [0u8; 32];
+            field[..8].copy_from_slice(&(data.len() as u64).to_be_bytes());
+            Hash::from_raw(field, HashAlgorithm::Fnv)
+        }
+    }
+
+    #[test]
+    fn test_paired_hash_agree() {
+        let hasher = PairedHasher::new(Box::new(HashAlgorithm::Flow), Box::new(HashAlgorithm::Fnv));
+        let recorded = hasher.verify(b"data");
+        assert_eq!(recorded.primary, HashAlgorithm::Flow.hash(b"data"));
+        assert_eq!(recorded.secondary, HashAlgorithm::Fnv.hash(b"data"));
+
+        // The same content matches on both sides, other content differs on both
+        assert!(hasher.verify(b"data").agree(&recorded));
+        assert!(hasher.verify(b"other data").agree(&recorded));
+
+        // Tampering that keeps the weak primary digest is caught by the secondary
+        let weak = PairedHasher::new(Box::new(FirstByteHasher), Box::new(HashAlgorithm::Flow));
+        let recorded = weak.verify(b"data");
+        assert!(weak.verify(b"data").agree(&recorded));
+        assert!(!weak.verify(b"dAta").agree(&recorded));
+    }
+
+    #[test]
+    fn test_store_checked_catches_collisions() {
+        let hasher = PairedHasher::new(Box::new(FirstByteHasher), Box::new(LengthHasher));
+        let mut paired = PairedStorage::new(
+            Box::new(MemoryBackend::default()),
+            Box::new(MemoryBackend::default()),
+        );
+
+        let first = Artifact::from_content_flow(b"ab".to_vec());
+        assert!(paired.store_checked(&hasher, first.clone()).is_ok());
+        // Storing the same content again is consistent
+        assert!(paired.store_checked(&hasher, first).is_ok());
+
+        // Same first byte, different length: the primary hasher collided
+        let crafted = Artifact::from_content_flow(b"abc".to_vec());
+        assert_eq!(
+            paired.store_checked(&hasher, crafted.clone()),
+            Err(PairedCollision::Primary(FirstByteHasher.hash(b"a")))
+        );
+        assert!(paired.get_primary(&crafted.hash).is_none());
+
+        // Same length, different first byte: the secondary hasher collided
+        let crafted = Artifact::from_content_flow(b"zz".to_vec());
+        assert_eq!(
+            paired.store_checked(&hasher, crafted),
+            Err(PairedCollision::Secondary(LengthHasher.hash(b"zz")))
+        );
+        assert_eq!(
+            PairedCollision::Secondary(LengthHasher.hash(b"zz")).to_string(),
+            format!("secondary hash collision on 0000000000000002{}", "0".repeat(48))
+        );
+    }
+
     #[test]
     fn test_paired_storage_strict() {
-        let paired = PairedStorage::new(
-            Box::new(MemoryStore::new()),
-            Box::new(MemoryStore::new()),
+        let mut paired = PairedStorage::new(
+            Box::new(MemoryBackend::default()),
+            Box::new(MemoryBackend::default()),
         );
 
-        let artifact = bootstrap::Artifact::new(b"test".to_vec(), &SimpleHasher);
+        let artifact = Artifact::from_content_flow(b"test".to_vec());
         
         // In strict mode, both storages should work identically
         let result = paired.dual_store(artifact.clone());
@@ -267,9 +450,9 @@ mod tests {
 
     #[test]
     fn test_paired_artifact() {
-        let artifact = bootstrap::Artifact::new(b"test".to_vec(), &SimpleHasher);
-        let primary_hash = SimpleHasher.hash(b"test");
-        let secondary_hash = ChecksumHasher.hash(b"test");
+        let artifact = Artifact::from_content_flow(b"test".to_vec());
+        let primary_hash = HashAlgorithm::Flow.hash(b"test");
+        let secondary_hash = HashAlgorithm::Fnv.hash(b"test");
 
         let paired = PairedArtifact::new(artifact.clone(), primary_hash.clone(), secondary_hash.clone());
 